@@ -1,3 +1,6 @@
 fn main() {
     cargo_emit::rustc_link_lib!("dl");
+
+    // The x11 crate does not link libXext for the MIT-SHM bindings
+    cargo_emit::rustc_link_lib!("Xext");
 }
@@ -0,0 +1,349 @@
+use crate::egl_sys;
+use crate::glx::rank_config;
+use crate::{EGLError, XVisualInfo, EGL};
+
+bitflags::bitflags! {
+    /// The kinds of surfaces an EGL framebuffer configuration can render to.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct EGLSurfaceTypes: i32 {
+        const PBUFFER = egl_sys::EGL_PBUFFER_BIT;
+        const PIXMAP = egl_sys::EGL_PIXMAP_BIT;
+        const WINDOW = egl_sys::EGL_WINDOW_BIT;
+    }
+}
+
+bitflags::bitflags! {
+    /// The client APIs an EGL framebuffer configuration supports.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct EGLRenderableTypes: i32 {
+        const OPENGL_ES = egl_sys::EGL_OPENGL_ES_BIT;
+        const OPENVG = egl_sys::EGL_OPENVG_BIT;
+        const OPENGL_ES2 = egl_sys::EGL_OPENGL_ES2_BIT;
+        const OPENGL = egl_sys::EGL_OPENGL_BIT;
+        const OPENGL_ES3 = egl_sys::EGL_OPENGL_ES3_BIT;
+    }
+}
+
+/// Caveat attached to an EGL framebuffer configuration by the implementation.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum EGLConfigCaveat {
+    /// The configuration has no caveats
+    None,
+
+    /// Rendering to the configuration may be slow, usually due to a software fallback
+    Slow,
+
+    /// The configuration does not pass the conformance tests
+    NonConformant,
+}
+
+impl EGLConfigCaveat {
+    /// Converts the native caveat value, treating unknown values as no caveat.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value of the `EGL_CONFIG_CAVEAT` attribute
+    pub fn from_native(value: i32) -> Self {
+        match value {
+            egl_sys::EGL_SLOW_CONFIG => Self::Slow,
+            egl_sys::EGL_NON_CONFORMANT_CONFIG => Self::NonConformant,
+            _ => Self::None,
+        }
+    }
+}
+
+/// Snapshot of the attributes of an EGL framebuffer configuration.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EGLConfigInfo {
+    /// The id of the configuration
+    pub id: i32,
+
+    /// The id of the associated X visual, 0 if there is none
+    pub native_visual_id: u64,
+
+    /// Total amount of bits in the color buffer
+    pub buffer_size: u32,
+
+    /// Amount of bits in the red channel
+    pub red_size: u32,
+
+    /// Amount of bits in the green channel
+    pub green_size: u32,
+
+    /// Amount of bits in the blue channel
+    pub blue_size: u32,
+
+    /// Amount of bits in the alpha channel
+    pub alpha_size: u32,
+
+    /// Amount of bits in the depth buffer
+    pub depth_size: u32,
+
+    /// Amount of bits in the stencil buffer
+    pub stencil_size: u32,
+
+    /// Amount of multisample buffers
+    pub sample_buffers: u32,
+
+    /// Amount of samples per pixel
+    pub samples: u32,
+
+    /// The kinds of surfaces the configuration can render to
+    pub surface_types: EGLSurfaceTypes,
+
+    /// The client APIs the configuration supports
+    pub renderable_types: EGLRenderableTypes,
+
+    /// The client APIs for which contexts using the configuration are conformant
+    pub conformant: EGLRenderableTypes,
+
+    /// Caveat attached by the implementation
+    pub caveat: EGLConfigCaveat,
+
+    /// Minimum supported swap interval
+    pub min_swap_interval: i32,
+
+    /// Maximum supported swap interval
+    pub max_swap_interval: i32,
+}
+
+/// Criteria used to choose EGL framebuffer configurations.
+///
+/// Sizes are minimum requirements, type masks have to be fully supported. Criteria which are not
+/// set are left to the EGL defaults.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EGLConfigCriteria {
+    red_size: Option<u32>,
+    green_size: Option<u32>,
+    blue_size: Option<u32>,
+    alpha_size: Option<u32>,
+    depth_size: Option<u32>,
+    stencil_size: Option<u32>,
+    sample_buffers: Option<u32>,
+    samples: Option<u32>,
+    surface_types: Option<EGLSurfaceTypes>,
+    renderable_types: Option<EGLRenderableTypes>,
+    argb_visual: bool,
+}
+
+impl EGLConfigCriteria {
+    /// Creates a new set of criteria without any set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the minimum size of the red, green, blue and alpha channels.
+    ///
+    /// # Arguments
+    ///
+    /// * `red` - The minimum amount of red bits
+    /// * `green` - The minimum amount of green bits
+    /// * `blue` - The minimum amount of blue bits
+    /// * `alpha` - The minimum amount of alpha bits
+    pub fn rgba_sizes(&mut self, red: u32, green: u32, blue: u32, alpha: u32) -> &mut Self {
+        self.red_size = Some(red);
+        self.green_size = Some(green);
+        self.blue_size = Some(blue);
+        self.alpha_size = Some(alpha);
+        self
+    }
+
+    /// Sets the minimum size of the depth buffer.
+    pub fn depth_size(&mut self, size: u32) -> &mut Self {
+        self.depth_size = Some(size);
+        self
+    }
+
+    /// Sets the minimum size of the stencil buffer.
+    pub fn stencil_size(&mut self, size: u32) -> &mut Self {
+        self.stencil_size = Some(size);
+        self
+    }
+
+    /// Sets the minimum amount of multisample buffers.
+    pub fn sample_buffers(&mut self, buffers: u32) -> &mut Self {
+        self.sample_buffers = Some(buffers);
+        self
+    }
+
+    /// Sets the minimum amount of samples per pixel.
+    ///
+    /// Configurations with a sample count closer to the requested one are ranked first.
+    pub fn samples(&mut self, samples: u32) -> &mut Self {
+        self.samples = Some(samples);
+        self
+    }
+
+    /// Sets the kinds of surfaces the configuration must be able to render to.
+    pub fn surface_types(&mut self, types: EGLSurfaceTypes) -> &mut Self {
+        self.surface_types = Some(types);
+        self
+    }
+
+    /// Sets the client APIs the configuration must support.
+    pub fn renderable_types(&mut self, types: EGLRenderableTypes) -> &mut Self {
+        self.renderable_types = Some(types);
+        self
+    }
+
+    /// Sets whether the configuration must have a native visual with an alpha channel.
+    ///
+    /// Such visuals are required for windows which are blended by a compositing manager.
+    pub fn argb_visual(&mut self, required: bool) -> &mut Self {
+        self.argb_visual = required;
+        self
+    }
+
+    /// Determines whether a visual with an alpha channel is required.
+    pub fn requires_argb_visual(&self) -> bool {
+        self.argb_visual
+    }
+
+    /// Turns the criteria into an `EGL_NONE` terminated EGL attribute list.
+    pub fn into_native(self) -> Vec<i32> {
+        let mut attributes = Vec::new();
+
+        let sizes = [
+            (egl_sys::EGL_RED_SIZE, self.red_size),
+            (egl_sys::EGL_GREEN_SIZE, self.green_size),
+            (egl_sys::EGL_BLUE_SIZE, self.blue_size),
+            (egl_sys::EGL_ALPHA_SIZE, self.alpha_size),
+            (egl_sys::EGL_DEPTH_SIZE, self.depth_size),
+            (egl_sys::EGL_STENCIL_SIZE, self.stencil_size),
+            (egl_sys::EGL_SAMPLE_BUFFERS, self.sample_buffers),
+            (egl_sys::EGL_SAMPLES, self.samples),
+        ];
+
+        for (attribute, size) in sizes {
+            if let Some(size) = size {
+                attributes.extend([attribute, size as i32]);
+            }
+        }
+
+        if let Some(types) = self.surface_types {
+            attributes.extend([egl_sys::EGL_SURFACE_TYPE, types.bits()]);
+        }
+
+        if let Some(types) = self.renderable_types {
+            attributes.extend([egl_sys::EGL_RENDERABLE_TYPE, types.bits()]);
+        }
+
+        attributes.push(egl_sys::EGL_NONE);
+        attributes
+    }
+
+    /// Computes the ranking key of a matching configuration, lower keys are better.
+    pub(crate) fn rank(&self, info: &EGLConfigInfo) -> (bool, u32, u32) {
+        rank_config(
+            [
+                (info.red_size, self.red_size),
+                (info.green_size, self.green_size),
+                (info.blue_size, self.blue_size),
+                (info.alpha_size, self.alpha_size),
+                (info.depth_size, self.depth_size),
+                (info.stencil_size, self.stencil_size),
+            ],
+            (info.samples, self.samples),
+            info.caveat != EGLConfigCaveat::None,
+        )
+    }
+}
+
+/// EGL framebuffer configuration.
+///
+/// Configurations stay valid for as long as the EGL display is initialized.
+#[derive(Debug, Clone)]
+pub struct EGLConfig<'a> {
+    handle: egl_sys::EGLConfig,
+    egl: &'a EGL<'a>,
+}
+
+impl<'a> EGLConfig<'a> {
+    /// Wraps a native EGL configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The underlying native configuration
+    /// * `egl` - The EGL display the configuration belongs to
+    ///
+    /// # Safety
+    ///
+    /// It is up to the caller to ensure that all parameters are valid.
+    pub unsafe fn new(handle: egl_sys::EGLConfig, egl: &'a EGL<'a>) -> Self {
+        Self { handle, egl }
+    }
+
+    /// Retrieves the underlying native configuration.
+    pub fn handle(&self) -> egl_sys::EGLConfig {
+        self.handle
+    }
+
+    /// Queries an attribute of the configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `attrib` - The attribute to query
+    pub fn get_attribute(&self, attrib: i32) -> Result<i32, EGLError> {
+        let mut value = 0;
+        let ok = unsafe {
+            egl_sys::eglGetConfigAttrib(self.egl.handle(), self.handle, attrib, &mut value)
+        };
+
+        if ok != egl_sys::EGL_FALSE {
+            Ok(value)
+        } else {
+            match EGLError::last("eglGetConfigAttrib") {
+                EGLError::CallFailed(_, egl_sys::EGL_BAD_ATTRIBUTE) => {
+                    Err(EGLError::BadAttribute(attrib))
+                }
+                err => Err(err),
+            }
+        }
+    }
+
+    /// Attempts to retrieve the X11 visual information for the native visual of the
+    /// configuration.
+    pub fn get_visual(&self) -> Option<XVisualInfo<'a>> {
+        let id = self.get_attribute(egl_sys::EGL_NATIVE_VISUAL_ID).ok()?;
+
+        if id == 0 {
+            None
+        } else {
+            self.egl.screen().find_visual(id as u32 as u64)
+        }
+    }
+
+    /// Determines whether the native visual of the configuration has an alpha channel.
+    pub fn has_argb_visual(&self) -> bool {
+        self.get_visual()
+            .is_some_and(|info| info.is_argb(self.egl.display()))
+    }
+
+    /// Queries all attributes of the configuration at once.
+    pub fn info(&self) -> Result<EGLConfigInfo, EGLError> {
+        let get = |attrib| self.get_attribute(attrib);
+        let get_size = |attrib| get(attrib).map(|v| v.max(0) as u32);
+
+        Ok(EGLConfigInfo {
+            id: get(egl_sys::EGL_CONFIG_ID)?,
+            native_visual_id: get(egl_sys::EGL_NATIVE_VISUAL_ID)? as u32 as u64,
+            buffer_size: get_size(egl_sys::EGL_BUFFER_SIZE)?,
+            red_size: get_size(egl_sys::EGL_RED_SIZE)?,
+            green_size: get_size(egl_sys::EGL_GREEN_SIZE)?,
+            blue_size: get_size(egl_sys::EGL_BLUE_SIZE)?,
+            alpha_size: get_size(egl_sys::EGL_ALPHA_SIZE)?,
+            depth_size: get_size(egl_sys::EGL_DEPTH_SIZE)?,
+            stencil_size: get_size(egl_sys::EGL_STENCIL_SIZE)?,
+            sample_buffers: get_size(egl_sys::EGL_SAMPLE_BUFFERS)?,
+            samples: get_size(egl_sys::EGL_SAMPLES)?,
+            surface_types: EGLSurfaceTypes::from_bits_truncate(get(egl_sys::EGL_SURFACE_TYPE)?),
+            renderable_types: EGLRenderableTypes::from_bits_truncate(get(
+                egl_sys::EGL_RENDERABLE_TYPE,
+            )?),
+            conformant: EGLRenderableTypes::from_bits_truncate(get(egl_sys::EGL_CONFORMANT)?),
+            caveat: EGLConfigCaveat::from_native(get(egl_sys::EGL_CONFIG_CAVEAT)?),
+            min_swap_interval: get(egl_sys::EGL_MIN_SWAP_INTERVAL)?,
+            max_swap_interval: get(egl_sys::EGL_MAX_SWAP_INTERVAL)?,
+        })
+    }
+}
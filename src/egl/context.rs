@@ -0,0 +1,266 @@
+use crate::egl_sys;
+use crate::{EGLError, EGLSurface, EGL};
+
+/// The client API of an EGL context.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(u32)]
+pub enum EGLApi {
+    /// Desktop OpenGL
+    OpenGL = egl_sys::EGL_OPENGL_API,
+
+    /// OpenGL ES
+    OpenGLES = egl_sys::EGL_OPENGL_ES_API,
+}
+
+/// The OpenGL profile of an EGL context.
+///
+/// Profiles only apply to desktop OpenGL contexts.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(i32)]
+pub enum EGLContextProfile {
+    /// Core profile without deprecated functionality
+    Core = egl_sys::EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT,
+
+    /// Compatibility profile including deprecated functionality
+    Compatibility = egl_sys::EGL_CONTEXT_OPENGL_COMPATIBILITY_PROFILE_BIT,
+}
+
+/// Strategy used to report graphics resets to robust EGL contexts.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(i32)]
+pub enum EGLResetNotification {
+    /// Resets are not reported
+    NoNotification = egl_sys::EGL_NO_RESET_NOTIFICATION,
+
+    /// The context is lost on reset and the reset is reported
+    LoseContextOnReset = egl_sys::EGL_LOSE_CONTEXT_ON_RESET,
+}
+
+/// Attributes used to create an EGL context.
+///
+/// Attributes which are not set are left to the implementation defaults.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EGLContextAttributes {
+    version: Option<(i32, i32)>,
+    profile: Option<EGLContextProfile>,
+    debug: bool,
+    forward_compatible: bool,
+    robust_access: bool,
+    reset_notification: Option<EGLResetNotification>,
+}
+
+impl EGLContextAttributes {
+    /// Creates a new set of context attributes without any set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the requested version of the client API.
+    ///
+    /// # Arguments
+    ///
+    /// * `major` - The major version to request
+    /// * `minor` - The minor version to request
+    pub fn version(&mut self, major: i32, minor: i32) -> &mut Self {
+        self.version = Some((major, minor));
+        self
+    }
+
+    /// Sets the requested OpenGL profile.
+    pub fn profile(&mut self, profile: EGLContextProfile) -> &mut Self {
+        self.profile = Some(profile);
+        self
+    }
+
+    /// Sets whether a debug context should be created.
+    pub fn debug(&mut self, debug: bool) -> &mut Self {
+        self.debug = debug;
+        self
+    }
+
+    /// Sets whether deprecated functionality should be removed from the context.
+    pub fn forward_compatible(&mut self, forward_compatible: bool) -> &mut Self {
+        self.forward_compatible = forward_compatible;
+        self
+    }
+
+    /// Sets whether the context should provide robust buffer access.
+    pub fn robust_access(&mut self, robust_access: bool) -> &mut Self {
+        self.robust_access = robust_access;
+        self
+    }
+
+    /// Sets the strategy used to report graphics resets.
+    pub fn reset_notification(&mut self, strategy: EGLResetNotification) -> &mut Self {
+        self.reset_notification = Some(strategy);
+        self
+    }
+
+    /// Retrieves the requested version, if any.
+    pub fn requested_version(&self) -> Option<(i32, i32)> {
+        self.version
+    }
+
+    /// Turns the attributes into an `EGL_NONE` terminated EGL attribute list.
+    pub fn into_native(self) -> Vec<i32> {
+        let mut attributes = Vec::new();
+
+        if let Some((major, minor)) = self.version {
+            attributes.extend([
+                egl_sys::EGL_CONTEXT_MAJOR_VERSION,
+                major,
+                egl_sys::EGL_CONTEXT_MINOR_VERSION,
+                minor,
+            ]);
+        }
+
+        if let Some(profile) = self.profile {
+            attributes.extend([egl_sys::EGL_CONTEXT_OPENGL_PROFILE_MASK, profile as i32]);
+        }
+
+        let flags = [
+            (egl_sys::EGL_CONTEXT_OPENGL_DEBUG, self.debug),
+            (
+                egl_sys::EGL_CONTEXT_OPENGL_FORWARD_COMPATIBLE,
+                self.forward_compatible,
+            ),
+            (
+                egl_sys::EGL_CONTEXT_OPENGL_ROBUST_ACCESS,
+                self.robust_access,
+            ),
+        ];
+
+        for (attribute, enabled) in flags {
+            if enabled {
+                attributes.extend([attribute, egl_sys::EGL_TRUE as i32]);
+            }
+        }
+
+        if let Some(strategy) = self.reset_notification {
+            attributes.extend([
+                egl_sys::EGL_CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY,
+                strategy as i32,
+            ]);
+        }
+
+        attributes.push(egl_sys::EGL_NONE);
+        attributes
+    }
+}
+
+/// An EGL rendering context.
+///
+/// Note that this struct does not guarantee that the context is the current context!
+#[derive(Debug)]
+pub struct EGLContext<'a> {
+    handle: egl_sys::EGLContext,
+    api: EGLApi,
+    egl: &'a EGL<'a>,
+}
+
+impl<'a> EGLContext<'a> {
+    /// Wraps a native EGL context.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The underlying native EGL context
+    /// * `api` - The client API the context was created for
+    /// * `egl` - The EGL display the context was created on
+    ///
+    /// # Safety
+    ///
+    /// It is up to the caller to ensure that `handle` is a valid EGL context.
+    pub unsafe fn new(handle: egl_sys::EGLContext, api: EGLApi, egl: &'a EGL<'a>) -> Self {
+        Self { handle, api, egl }
+    }
+
+    /// Retrieves the underlying native EGL context.
+    pub fn handle(&self) -> egl_sys::EGLContext {
+        self.handle
+    }
+
+    /// Retrieves the client API the context was created for.
+    pub fn api(&self) -> EGLApi {
+        self.api
+    }
+
+    /// Determines whether this context is current on the calling thread.
+    ///
+    /// Only contexts of the currently bound client API are considered.
+    pub fn is_current(&self) -> bool {
+        unsafe { egl_sys::eglGetCurrentContext() == self.handle }
+    }
+
+    /// Makes the context current using the given surface for drawing and reading.
+    ///
+    /// # Arguments
+    ///
+    /// * `surface` - The surface to render to
+    pub fn make_current(&self, surface: &EGLSurface) -> Result<(), EGLError> {
+        self.make_context_current(surface, surface)
+    }
+
+    /// Makes the context current using separate surfaces for drawing and reading.
+    ///
+    /// # Arguments
+    ///
+    /// * `draw` - The surface to render to
+    /// * `read` - The surface to read pixels from
+    pub fn make_context_current(
+        &self,
+        draw: &EGLSurface,
+        read: &EGLSurface,
+    ) -> Result<(), EGLError> {
+        self.make_current_raw(draw.handle(), read.handle())
+    }
+
+    /// Makes the context current without any surface.
+    ///
+    /// Requires `EGL_KHR_surfaceless_context`, rendering has to happen to framebuffer objects.
+    pub fn make_current_surfaceless(&self) -> Result<(), EGLError> {
+        self.make_current_raw(egl_sys::EGL_NO_SURFACE, egl_sys::EGL_NO_SURFACE)
+    }
+
+    /// Removes the current context from the calling thread.
+    pub fn make_non_current(&self) {
+        unsafe {
+            egl_sys::eglMakeCurrent(
+                self.egl.handle(),
+                egl_sys::EGL_NO_SURFACE,
+                egl_sys::EGL_NO_SURFACE,
+                egl_sys::EGL_NO_CONTEXT,
+            )
+        };
+    }
+
+    fn make_current_raw(
+        &self,
+        draw: egl_sys::EGLSurface,
+        read: egl_sys::EGLSurface,
+    ) -> Result<(), EGLError> {
+        // The current context is tracked per client API
+        if unsafe { egl_sys::eglBindAPI(self.api as _) } == egl_sys::EGL_FALSE {
+            return Err(EGLError::last("eglBindAPI"));
+        }
+
+        let ok = unsafe { egl_sys::eglMakeCurrent(self.egl.handle(), draw, read, self.handle) };
+
+        if ok == egl_sys::EGL_FALSE {
+            Err(EGLError::last("eglMakeCurrent"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<'a> Drop for EGLContext<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            if self.is_current() {
+                self.make_non_current();
+            }
+
+            egl_sys::eglDestroyContext(self.egl.handle(), self.handle);
+        }
+    }
+}
@@ -0,0 +1,516 @@
+//! This module provides an EGL rendering backend for X11 as an alternative to GLX.
+
+mod config;
+mod context;
+mod platform;
+mod surface;
+
+pub use config::*;
+pub use context::*;
+pub use surface::*;
+
+use std::ffi::{CStr, CString};
+use std::sync::{Mutex, PoisonError};
+
+use crate::egl_sys;
+use crate::{XDisplay, XPixmap, XScreen, XWindow};
+use platform::PlatformFunctions;
+use thiserror::Error;
+
+/// The name of the EGL client extension providing X11 displays.
+const PLATFORM_X11_EXTENSION: &str = "EGL_KHR_platform_x11";
+
+/// The name of the older EGL client extension providing X11 displays, on top of
+/// `EGL_EXT_platform_base`.
+const PLATFORM_X11_EXT_EXTENSION: &str = "EGL_EXT_platform_x11";
+
+/// Reference counts of the initialized EGL displays, keyed by their handle.
+///
+/// EGL returns the same display for the same X11 display and screen and `eglTerminate` is not
+/// reference counted, so a display may only be terminated once its last interface is dropped.
+static DISPLAY_REFERENCES: Mutex<Vec<(usize, usize)>> = Mutex::new(Vec::new());
+
+/// Main interface for talking to EGL.
+///
+/// The EGL display is initialized on creation and terminated when the last interface for the same
+/// screen is dropped, all objects created through it are bound to its lifetime.
+#[derive(Debug)]
+pub struct EGL<'a> {
+    handle: egl_sys::EGLDisplay,
+    version: (i32, i32),
+    screen: XScreen<'a>,
+    platform: PlatformFunctions,
+}
+
+impl<'a> EGL<'a> {
+    /// Creates the EGL interface for a screen of an X11 display.
+    ///
+    /// # Arguments
+    ///
+    /// * `screen` - The screen to render on
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wrap11::{XDisplay, EGL};
+    ///
+    /// let display = XDisplay::open(None).expect("failed to open the display");
+    /// let screen = display.default_screen();
+    ///
+    /// let egl = EGL::create(&screen).expect("EGL is not available");
+    /// let (major, minor) = egl.get_version();
+    ///
+    /// println!("EGL {}.{}", major, minor);
+    /// ```
+    pub fn create(screen: &XScreen<'a>) -> Result<Self, EGLError> {
+        let client_extensions =
+            unsafe { egl_sys::eglQueryString(egl_sys::EGL_NO_DISPLAY, egl_sys::EGL_EXTENSIONS) };
+
+        // Client extensions are only available with EGL 1.5 or EGL_EXT_client_extensions
+        let client_extensions = if client_extensions.is_null() {
+            String::new()
+        } else {
+            unsafe { CStr::from_ptr(client_extensions) }
+                .to_string_lossy()
+                .into_owned()
+        };
+        let client_extensions = client_extensions.split(' ').collect::<Vec<_>>();
+
+        let platform_supported = client_extensions.iter().any(|extension| {
+            *extension == PLATFORM_X11_EXTENSION || *extension == PLATFORM_X11_EXT_EXTENSION
+        });
+
+        if !platform_supported {
+            return Err(EGLError::MissingExtension(PLATFORM_X11_EXTENSION));
+        }
+
+        let platform = PlatformFunctions::load(&client_extensions)?;
+
+        let attributes = [
+            egl_sys::EGL_PLATFORM_X11_SCREEN_KHR,
+            screen.number() as egl_sys::EGLAttrib,
+            egl_sys::EGL_NONE as egl_sys::EGLAttrib,
+        ];
+
+        let handle = unsafe {
+            platform.get_display(
+                egl_sys::EGL_PLATFORM_X11_KHR,
+                screen.display().handle() as _,
+                &attributes,
+            )?
+        };
+
+        let mut major = 0;
+        let mut minor = 0;
+
+        // Held while initializing so the display can't be terminated concurrently
+        let mut references = DISPLAY_REFERENCES
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        if unsafe { egl_sys::eglInitialize(handle, &mut major, &mut minor) } == egl_sys::EGL_FALSE {
+            return Err(EGLError::last("eglInitialize"));
+        }
+
+        match references
+            .iter_mut()
+            .find(|(display, _)| *display == handle as usize)
+        {
+            Some((_, count)) => *count += 1,
+            None => references.push((handle as usize, 1)),
+        }
+
+        Ok(Self {
+            handle,
+            version: (major, minor),
+            screen: unsafe { XScreen::new(screen.handle(), screen.display()) },
+            platform,
+        })
+    }
+
+    /// Retrieves the underlying native EGL display.
+    pub fn handle(&self) -> egl_sys::EGLDisplay {
+        self.handle
+    }
+
+    /// Retrieves the X11 display EGL is running on.
+    pub fn display(&self) -> &'a XDisplay {
+        self.screen.display()
+    }
+
+    /// Retrieves the X11 screen EGL is rendering on.
+    pub fn screen(&self) -> &XScreen<'a> {
+        &self.screen
+    }
+
+    /// Retrieves the version of EGL the display has been initialized with.
+    pub fn get_version(&self) -> (i32, i32) {
+        self.version
+    }
+
+    /// Retrieves the vendor of the EGL implementation.
+    pub fn vendor(&self) -> Option<String> {
+        self.query_string(egl_sys::EGL_VENDOR)
+    }
+
+    /// Retrieves the client APIs supported by the display, for example `OpenGL` or `OpenGL_ES`.
+    pub fn client_apis(&self) -> Vec<String> {
+        self.query_string(egl_sys::EGL_CLIENT_APIS)
+            .map(|apis| apis.split_whitespace().map(String::from).collect())
+            .unwrap_or_default()
+    }
+
+    /// Queries all available EGL display extensions.
+    pub fn query_extensions(&self) -> Vec<String> {
+        self.query_string(egl_sys::EGL_EXTENSIONS)
+            .map(|extensions| extensions.split_whitespace().map(String::from).collect())
+            .unwrap_or_default()
+    }
+
+    /// Looks up an OpenGL, OpenGL ES or EGL function.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the function to look up
+    ///
+    /// # Warning
+    ///
+    /// Like GLX, EGL may return pointers for functions which are not supported by the current
+    /// context.
+    pub fn lookup_function(&self, name: impl AsRef<str>) -> Option<unsafe extern "C" fn()> {
+        let name = CString::new(name.as_ref()).ok()?;
+
+        unsafe { egl_sys::eglGetProcAddress(name.as_ptr()) }
+    }
+
+    /// Chooses all framebuffer configurations matching the given criteria.
+    ///
+    /// The returned configurations are ranked with the best match first, see
+    /// [`GLX::choose_framebuffer_configs`](crate::GLX::choose_framebuffer_configs) for the
+    /// ranking rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `criteria` - The criteria the configurations have to match
+    pub fn choose_configs(&self, criteria: EGLConfigCriteria) -> Result<Vec<EGLConfig>, EGLError> {
+        let native = criteria.into_native();
+        let mut count = 0;
+
+        let ok = unsafe {
+            egl_sys::eglChooseConfig(
+                self.handle,
+                native.as_ptr(),
+                std::ptr::null_mut(),
+                0,
+                &mut count,
+            )
+        };
+
+        if ok == egl_sys::EGL_FALSE {
+            return Err(EGLError::last("eglChooseConfig"));
+        }
+
+        let mut handles = vec![std::ptr::null_mut(); count.max(0) as usize];
+
+        let ok = unsafe {
+            egl_sys::eglChooseConfig(
+                self.handle,
+                native.as_ptr(),
+                handles.as_mut_ptr(),
+                count,
+                &mut count,
+            )
+        };
+
+        if ok == egl_sys::EGL_FALSE {
+            return Err(EGLError::last("eglChooseConfig"));
+        }
+
+        handles.truncate(count.max(0) as usize);
+
+        let mut ranked = Vec::with_capacity(handles.len());
+
+        for handle in handles {
+            let config = unsafe { EGLConfig::new(handle, self) };
+
+            if criteria.requires_argb_visual() && !config.has_argb_visual() {
+                continue;
+            }
+
+            let Ok(info) = config.info() else {
+                continue;
+            };
+
+            ranked.push((criteria.rank(&info), config));
+        }
+
+        if ranked.is_empty() {
+            return Err(EGLError::NoConfigFound);
+        }
+
+        ranked.sort_by_key(|(rank, _)| *rank);
+
+        Ok(ranked.into_iter().map(|(_, config)| config).collect())
+    }
+
+    /// Creates an EGL rendering context.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The framebuffer configuration to use for rendering
+    /// * `api` - The client API of the context
+    /// * `attributes` - The attributes of the context
+    /// * `share` - An existing context to share objects with
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wrap11::{EGLApi, EGLConfigCriteria, EGLContextAttributes, EGLRenderableTypes};
+    /// use wrap11::{XDisplay, EGL};
+    ///
+    /// let display = XDisplay::open(None).expect("failed to open the display");
+    /// let screen = display.default_screen();
+    /// let egl = EGL::create(&screen).expect("EGL is not available");
+    ///
+    /// let mut criteria = EGLConfigCriteria::new();
+    /// criteria.renderable_types(EGLRenderableTypes::OPENGL_ES3);
+    ///
+    /// let configs = egl.choose_configs(criteria).unwrap();
+    ///
+    /// let mut attributes = EGLContextAttributes::new();
+    /// attributes.version(3, 0);
+    ///
+    /// let context = egl
+    ///     .create_context(&configs[0], EGLApi::OpenGLES, attributes, None)
+    ///     .expect("OpenGL ES 3.0 is not available");
+    /// ```
+    pub fn create_context(
+        &self,
+        config: &EGLConfig,
+        api: EGLApi,
+        attributes: EGLContextAttributes,
+        share: Option<&EGLContext>,
+    ) -> Result<EGLContext, EGLError> {
+        if unsafe { egl_sys::eglBindAPI(api as _) } == egl_sys::EGL_FALSE {
+            return Err(EGLError::last("eglBindAPI"));
+        }
+
+        let native = attributes.into_native();
+        let share = share.map_or(egl_sys::EGL_NO_CONTEXT, |share| share.handle());
+
+        let context = unsafe {
+            egl_sys::eglCreateContext(self.handle, config.handle(), share, native.as_ptr())
+        };
+
+        if context == egl_sys::EGL_NO_CONTEXT {
+            return match (
+                EGLError::last("eglCreateContext"),
+                attributes.requested_version(),
+            ) {
+                (EGLError::CallFailed(_, egl_sys::EGL_BAD_MATCH), Some((major, minor))) => {
+                    Err(EGLError::VersionUnavailable(major, minor))
+                }
+                (err, _) => Err(err),
+            };
+        }
+
+        Ok(unsafe { EGLContext::new(context, api, self) })
+    }
+
+    /// Creates a surface rendering to an X11 window.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The framebuffer configuration to use, it has to support
+    ///   [`EGLSurfaceTypes::WINDOW`] and match the visual of the window
+    /// * `window` - The window to render to, it stays borrowed for as long as the surface exists
+    pub fn create_window_surface<'w>(
+        &self,
+        config: &EGLConfig,
+        window: &'w XWindow,
+    ) -> Result<EGLSurface<'w, '_>, EGLError> {
+        let mut window_handle = window.handle();
+
+        let (surface, function) = unsafe {
+            self.platform.create_window_surface(
+                self.handle,
+                config.handle(),
+                &mut window_handle as *mut _ as _,
+                &[egl_sys::EGL_NONE as egl_sys::EGLAttrib],
+            )
+        };
+
+        self.wrap_surface(surface, function)
+    }
+
+    /// Creates a surface rendering to an X11 pixmap.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The framebuffer configuration to use, it has to support
+    ///   [`EGLSurfaceTypes::PIXMAP`] and match the depth of the pixmap
+    /// * `pixmap` - The pixmap to render to, it stays borrowed for as long as the surface exists
+    pub fn create_pixmap_surface<'w>(
+        &self,
+        config: &EGLConfig,
+        pixmap: &'w XPixmap,
+    ) -> Result<EGLSurface<'w, '_>, EGLError> {
+        let mut pixmap_handle = pixmap.handle();
+
+        let (surface, function) = unsafe {
+            self.platform.create_pixmap_surface(
+                self.handle,
+                config.handle(),
+                &mut pixmap_handle as *mut _ as _,
+                &[egl_sys::EGL_NONE as egl_sys::EGLAttrib],
+            )
+        };
+
+        self.wrap_surface(surface, function)
+    }
+
+    /// Creates an offscreen pbuffer surface.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The framebuffer configuration to use, it has to support
+    ///   [`EGLSurfaceTypes::PBUFFER`]
+    /// * `width` - The width of the pbuffer
+    /// * `height` - The height of the pbuffer
+    /// * `largest` - Whether to create the largest available pbuffer if the requested size is
+    ///   not available
+    pub fn create_pbuffer_surface(
+        &self,
+        config: &EGLConfig,
+        width: u32,
+        height: u32,
+        largest: bool,
+    ) -> Result<EGLSurface<'_, '_>, EGLError> {
+        let attributes = [
+            egl_sys::EGL_WIDTH,
+            width as i32,
+            egl_sys::EGL_HEIGHT,
+            height as i32,
+            egl_sys::EGL_LARGEST_PBUFFER,
+            largest as i32,
+            egl_sys::EGL_NONE,
+        ];
+
+        let surface = unsafe {
+            egl_sys::eglCreatePbufferSurface(self.handle, config.handle(), attributes.as_ptr())
+        };
+
+        self.wrap_surface(surface, "eglCreatePbufferSurface")
+    }
+
+    /// Sets the swap interval of the surface bound to the current context.
+    ///
+    /// The interval is clamped by the implementation to the minimum and maximum swap interval
+    /// of the config of the surface.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - The minimum amount of vertical retraces between two buffer swaps
+    pub fn set_swap_interval(&self, interval: i32) -> Result<(), EGLError> {
+        if unsafe { egl_sys::eglSwapInterval(self.handle, interval) } == egl_sys::EGL_FALSE {
+            Err(EGLError::last("eglSwapInterval"))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn wrap_surface<'w>(
+        &self,
+        surface: egl_sys::EGLSurface,
+        function: &'static str,
+    ) -> Result<EGLSurface<'w, '_>, EGLError> {
+        if surface == egl_sys::EGL_NO_SURFACE {
+            Err(EGLError::last(function))
+        } else {
+            Ok(unsafe { EGLSurface::new(surface, self) })
+        }
+    }
+
+    fn query_string(&self, name: i32) -> Option<String> {
+        let value = unsafe { egl_sys::eglQueryString(self.handle, name) };
+
+        if value.is_null() {
+            None
+        } else {
+            Some(
+                unsafe { CStr::from_ptr(value) }
+                    .to_string_lossy()
+                    .into_owned(),
+            )
+        }
+    }
+}
+
+impl<'a> Drop for EGL<'a> {
+    fn drop(&mut self) {
+        let mut references = DISPLAY_REFERENCES
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        let Some(index) = references
+            .iter()
+            .position(|(display, _)| *display == self.handle as usize)
+        else {
+            return;
+        };
+
+        references[index].1 -= 1;
+
+        // Other interfaces for the same display are still alive
+        if references[index].1 > 0 {
+            return;
+        }
+
+        references.swap_remove(index);
+
+        unsafe {
+            egl_sys::eglMakeCurrent(
+                self.handle,
+                egl_sys::EGL_NO_SURFACE,
+                egl_sys::EGL_NO_SURFACE,
+                egl_sys::EGL_NO_CONTEXT,
+            );
+            egl_sys::eglTerminate(self.handle);
+        }
+    }
+}
+
+/// Possible errors that might occur while working with EGL.
+#[derive(Debug, Error)]
+pub enum EGLError {
+    /// An EGL extension required for the requested operation is not available.
+    #[error("the EGL extension {0} is not available")]
+    MissingExtension(&'static str),
+
+    /// No framebuffer configuration matches the requested criteria.
+    #[error("no framebuffer configuration could be found for the requested attributes")]
+    NoConfigFound,
+
+    /// The implementation could not create a context of the requested version.
+    #[error("a context of version {0}.{1} with the requested attributes is not available")]
+    VersionUnavailable(i32, i32),
+
+    /// An attempt was made to request an invalid attribute.
+    #[error("0x{0:X} is not a valid EGL attribute")]
+    BadAttribute(i32),
+
+    /// An EGL call failed.
+    #[error("{0} failed with EGL error 0x{1:X}")]
+    CallFailed(&'static str, i32),
+}
+
+impl EGLError {
+    /// Creates an error from the last EGL error of the calling thread.
+    ///
+    /// # Arguments
+    ///
+    /// * `function` - The name of the EGL function which failed
+    pub fn last(function: &'static str) -> Self {
+        Self::CallFailed(function, unsafe { egl_sys::eglGetError() })
+    }
+}
@@ -0,0 +1,257 @@
+use std::ffi::{c_void, CString};
+
+use super::EGLError;
+use crate::egl_sys;
+
+/// Type alias for the [`eglGetPlatformDisplay`] C function.
+type EGLGetPlatformDisplayFn = unsafe extern "C" fn(
+    egl_sys::EGLenum,
+    *mut c_void,
+    *const egl_sys::EGLAttrib,
+) -> egl_sys::EGLDisplay;
+
+/// Type alias for the [`eglCreatePlatformWindowSurface`] and [`eglCreatePlatformPixmapSurface`]
+/// C functions.
+type EGLCreatePlatformSurfaceFn = unsafe extern "C" fn(
+    egl_sys::EGLDisplay,
+    egl_sys::EGLConfig,
+    *mut c_void,
+    *const egl_sys::EGLAttrib,
+) -> egl_sys::EGLSurface;
+
+/// Type alias for the [`eglGetPlatformDisplayEXT`] C function.
+type EGLGetPlatformDisplayEXTFn = unsafe extern "C" fn(
+    egl_sys::EGLenum,
+    *mut c_void,
+    *const egl_sys::EGLint,
+) -> egl_sys::EGLDisplay;
+
+/// Type alias for the [`eglCreatePlatformWindowSurfaceEXT`] and
+/// [`eglCreatePlatformPixmapSurfaceEXT`] C functions.
+type EGLCreatePlatformSurfaceEXTFn = unsafe extern "C" fn(
+    egl_sys::EGLDisplay,
+    egl_sys::EGLConfig,
+    *mut c_void,
+    *const egl_sys::EGLint,
+) -> egl_sys::EGLSurface;
+
+/// The name of the EGL client extension providing the `…EXT` platform functions.
+const PLATFORM_BASE_EXTENSION: &str = "EGL_EXT_platform_base";
+
+/// The platform functions used to create X11 displays and surfaces.
+///
+/// The core functions were only added with EGL 1.5, so they are looked up at runtime instead of
+/// being linked, falling back to the `EGL_EXT_platform_base` variants on EGL 1.4.
+#[derive(Debug, Copy, Clone)]
+pub(super) enum PlatformFunctions {
+    Core {
+        get_display: EGLGetPlatformDisplayFn,
+        create_window_surface: EGLCreatePlatformSurfaceFn,
+        create_pixmap_surface: EGLCreatePlatformSurfaceFn,
+    },
+    Ext {
+        get_display: EGLGetPlatformDisplayEXTFn,
+        create_window_surface: EGLCreatePlatformSurfaceEXTFn,
+        create_pixmap_surface: EGLCreatePlatformSurfaceEXTFn,
+    },
+}
+
+impl PlatformFunctions {
+    /// Loads the platform functions, preferring the EGL 1.5 ones.
+    ///
+    /// # Arguments
+    ///
+    /// * `client_extensions` - The client extensions supported by the implementation
+    pub fn load(client_extensions: &[&str]) -> Result<Self, EGLError> {
+        let lookup = |name: &str| {
+            let name = CString::new(name).unwrap();
+
+            unsafe { egl_sys::eglGetProcAddress(name.as_ptr()) }
+        };
+
+        if let (Some(get_display), Some(create_window_surface), Some(create_pixmap_surface)) = (
+            lookup("eglGetPlatformDisplay"),
+            lookup("eglCreatePlatformWindowSurface"),
+            lookup("eglCreatePlatformPixmapSurface"),
+        ) {
+            return Ok(unsafe {
+                Self::Core {
+                    get_display: std::mem::transmute::<
+                        unsafe extern "C" fn(),
+                        EGLGetPlatformDisplayFn,
+                    >(get_display),
+                    create_window_surface: std::mem::transmute::<
+                        unsafe extern "C" fn(),
+                        EGLCreatePlatformSurfaceFn,
+                    >(create_window_surface),
+                    create_pixmap_surface: std::mem::transmute::<
+                        unsafe extern "C" fn(),
+                        EGLCreatePlatformSurfaceFn,
+                    >(create_pixmap_surface),
+                }
+            });
+        }
+
+        let missing = || EGLError::MissingExtension(PLATFORM_BASE_EXTENSION);
+
+        if !client_extensions.contains(&PLATFORM_BASE_EXTENSION) {
+            return Err(missing());
+        }
+
+        let get_display = lookup("eglGetPlatformDisplayEXT").ok_or_else(missing)?;
+        let create_window_surface =
+            lookup("eglCreatePlatformWindowSurfaceEXT").ok_or_else(missing)?;
+        let create_pixmap_surface =
+            lookup("eglCreatePlatformPixmapSurfaceEXT").ok_or_else(missing)?;
+
+        Ok(unsafe {
+            Self::Ext {
+                get_display: std::mem::transmute::<
+                    unsafe extern "C" fn(),
+                    EGLGetPlatformDisplayEXTFn,
+                >(get_display),
+                create_window_surface: std::mem::transmute::<
+                    unsafe extern "C" fn(),
+                    EGLCreatePlatformSurfaceEXTFn,
+                >(create_window_surface),
+                create_pixmap_surface: std::mem::transmute::<
+                    unsafe extern "C" fn(),
+                    EGLCreatePlatformSurfaceEXTFn,
+                >(create_pixmap_surface),
+            }
+        })
+    }
+
+    /// Retrieves an EGL display for a native display.
+    ///
+    /// # Arguments
+    ///
+    /// * `platform` - The platform of the native display
+    /// * `native_display` - The native display
+    /// * `attributes` - The display attributes, terminated by `EGL_NONE`
+    ///
+    /// # Safety
+    ///
+    /// The native display has to be a valid display of the given platform.
+    pub unsafe fn get_display(
+        &self,
+        platform: egl_sys::EGLenum,
+        native_display: *mut c_void,
+        attributes: &[egl_sys::EGLAttrib],
+    ) -> Result<egl_sys::EGLDisplay, EGLError> {
+        let (display, function) = match self {
+            Self::Core { get_display, .. } => (
+                get_display(platform, native_display, attributes.as_ptr()),
+                "eglGetPlatformDisplay",
+            ),
+            Self::Ext { get_display, .. } => (
+                get_display(
+                    platform,
+                    native_display,
+                    Self::to_ext_attributes(attributes).as_ptr(),
+                ),
+                "eglGetPlatformDisplayEXT",
+            ),
+        };
+
+        if display == egl_sys::EGL_NO_DISPLAY {
+            Err(EGLError::last(function))
+        } else {
+            Ok(display)
+        }
+    }
+
+    /// Creates a surface rendering to a native window.
+    ///
+    /// # Arguments
+    ///
+    /// * `display` - The EGL display to create the surface on
+    /// * `config` - The framebuffer configuration to use
+    /// * `native_window` - A pointer to the native window
+    /// * `attributes` - The surface attributes, terminated by `EGL_NONE`
+    ///
+    /// # Safety
+    ///
+    /// The display and config have to be valid and the native window has to point to a window
+    /// of the platform of the display.
+    pub unsafe fn create_window_surface(
+        &self,
+        display: egl_sys::EGLDisplay,
+        config: egl_sys::EGLConfig,
+        native_window: *mut c_void,
+        attributes: &[egl_sys::EGLAttrib],
+    ) -> (egl_sys::EGLSurface, &'static str) {
+        match self {
+            Self::Core {
+                create_window_surface,
+                ..
+            } => (
+                create_window_surface(display, config, native_window, attributes.as_ptr()),
+                "eglCreatePlatformWindowSurface",
+            ),
+            Self::Ext {
+                create_window_surface,
+                ..
+            } => (
+                create_window_surface(
+                    display,
+                    config,
+                    native_window,
+                    Self::to_ext_attributes(attributes).as_ptr(),
+                ),
+                "eglCreatePlatformWindowSurfaceEXT",
+            ),
+        }
+    }
+
+    /// Creates a surface rendering to a native pixmap.
+    ///
+    /// # Arguments
+    ///
+    /// * `display` - The EGL display to create the surface on
+    /// * `config` - The framebuffer configuration to use
+    /// * `native_pixmap` - A pointer to the native pixmap
+    /// * `attributes` - The surface attributes, terminated by `EGL_NONE`
+    ///
+    /// # Safety
+    ///
+    /// The display and config have to be valid and the native pixmap has to point to a pixmap
+    /// of the platform of the display.
+    pub unsafe fn create_pixmap_surface(
+        &self,
+        display: egl_sys::EGLDisplay,
+        config: egl_sys::EGLConfig,
+        native_pixmap: *mut c_void,
+        attributes: &[egl_sys::EGLAttrib],
+    ) -> (egl_sys::EGLSurface, &'static str) {
+        match self {
+            Self::Core {
+                create_pixmap_surface,
+                ..
+            } => (
+                create_pixmap_surface(display, config, native_pixmap, attributes.as_ptr()),
+                "eglCreatePlatformPixmapSurface",
+            ),
+            Self::Ext {
+                create_pixmap_surface,
+                ..
+            } => (
+                create_pixmap_surface(
+                    display,
+                    config,
+                    native_pixmap,
+                    Self::to_ext_attributes(attributes).as_ptr(),
+                ),
+                "eglCreatePlatformPixmapSurfaceEXT",
+            ),
+        }
+    }
+
+    /// The `…EXT` functions take `EGLint` attributes instead of `EGLAttrib`.
+    fn to_ext_attributes(attributes: &[egl_sys::EGLAttrib]) -> Vec<egl_sys::EGLint> {
+        attributes
+            .iter()
+            .map(|&attribute| attribute as egl_sys::EGLint)
+            .collect()
+    }
+}
@@ -0,0 +1,88 @@
+use std::marker::PhantomData;
+
+use crate::egl_sys;
+use crate::{EGLError, EGL};
+
+/// An EGL surface which can be rendered to.
+///
+/// Depending on how it was created the surface renders to an X11 window, an X11 pixmap or an
+/// offscreen pbuffer. The X11 drawable backing the surface stays borrowed for as long as the
+/// surface exists.
+#[derive(Debug)]
+pub struct EGLSurface<'w, 'a> {
+    handle: egl_sys::EGLSurface,
+    egl: &'a EGL<'a>,
+    _backing: PhantomData<&'w ()>,
+}
+
+impl<'w, 'a> EGLSurface<'w, 'a> {
+    /// Wraps a native EGL surface.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The underlying native EGL surface
+    /// * `egl` - The EGL display the surface was created on
+    ///
+    /// # Safety
+    ///
+    /// It is up to the caller to ensure that `handle` is a valid EGL surface and that the X11
+    /// drawable backing it, if any, outlives `'w`.
+    pub unsafe fn new(handle: egl_sys::EGLSurface, egl: &'a EGL<'a>) -> Self {
+        Self {
+            handle,
+            egl,
+            _backing: PhantomData,
+        }
+    }
+
+    /// Retrieves the underlying native EGL surface.
+    pub fn handle(&self) -> egl_sys::EGLSurface {
+        self.handle
+    }
+
+    /// Retrieves the current width of the surface.
+    pub fn width(&self) -> u32 {
+        self.query(egl_sys::EGL_WIDTH).unwrap_or(0).max(0) as u32
+    }
+
+    /// Retrieves the current height of the surface.
+    pub fn height(&self) -> u32 {
+        self.query(egl_sys::EGL_HEIGHT).unwrap_or(0).max(0) as u32
+    }
+
+    /// Queries an attribute of the surface.
+    ///
+    /// # Arguments
+    ///
+    /// * `attribute` - The attribute to query
+    pub fn query(&self, attribute: i32) -> Result<i32, EGLError> {
+        let mut value = 0;
+        let ok = unsafe {
+            egl_sys::eglQuerySurface(self.egl.handle(), self.handle, attribute, &mut value)
+        };
+
+        if ok == egl_sys::EGL_FALSE {
+            Err(EGLError::last("eglQuerySurface"))
+        } else {
+            Ok(value)
+        }
+    }
+
+    /// Swaps the front/back buffers of the surface.
+    ///
+    /// A context has to be current on the surface in order for the call to succeed!
+    pub fn swap_buffers(&self) -> Result<(), EGLError> {
+        if unsafe { egl_sys::eglSwapBuffers(self.egl.handle(), self.handle) } == egl_sys::EGL_FALSE
+        {
+            Err(EGLError::last("eglSwapBuffers"))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<'w, 'a> Drop for EGLSurface<'w, 'a> {
+    fn drop(&mut self) {
+        unsafe { egl_sys::eglDestroySurface(self.egl.handle(), self.handle) };
+    }
+}
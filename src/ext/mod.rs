@@ -1 +1,2 @@
 pub mod edid;
+pub mod xcursor;
@@ -0,0 +1,252 @@
+//! Parser for the Xcursor file format as used by cursor themes.
+//!
+//! An Xcursor file starts with a header followed by a table of contents, each entry pointing
+//! to a chunk in the file. Only image chunks are of interest here, comment chunks are skipped.
+//! All values are stored as little-endian 32 bit integers.
+
+/// The magic bytes at the start of every Xcursor file ("Xcur").
+const XCURSOR_MAGIC: u32 = 0x72756358;
+
+/// The chunk type of image chunks.
+const XCURSOR_IMAGE_TYPE: u32 = 0xFFFD0002;
+
+/// The maximum width and height of a single cursor image.
+const XCURSOR_IMAGE_MAX_SIZE: u32 = 0x7FFF;
+
+/// The type of parsing results.
+pub type Result<T> = core::result::Result<T, &'static str>;
+
+/// A single image contained in an Xcursor file.
+#[derive(Debug, Clone)]
+pub struct XcursorImage {
+    /// The nominal size the image has been designed for.
+    pub nominal_size: u32,
+    /// The width of the image in pixels.
+    pub width: u32,
+    /// The height of the image in pixels.
+    pub height: u32,
+    /// The x coordinate of the hotspot.
+    pub xhot: u32,
+    /// The y coordinate of the hotspot.
+    pub yhot: u32,
+    /// The delay in milliseconds before the next frame of an animation is shown.
+    pub delay: u32,
+    /// The pixels of the image in premultiplied ARGB format, row by row.
+    pub pixels: Vec<u32>,
+}
+
+/// Reads little-endian values from a byte buffer.
+struct Reader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn read_u32_at(&self, offset: usize) -> Result<u32> {
+        let bytes = offset
+            .checked_add(4)
+            .and_then(|end| self.data.get(offset..end))
+            .ok_or("Unexpectedly out of data!")?;
+
+        Ok(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+}
+
+/// Parses all images contained in an Xcursor file.
+///
+/// # Arguments
+///
+/// * `data` - The contents of the Xcursor file
+pub fn parse(data: &[u8]) -> Result<Vec<XcursorImage>> {
+    let r = Reader { data };
+
+    if r.read_u32_at(0)? != XCURSOR_MAGIC {
+        return Err("Not an Xcursor file!");
+    }
+
+    let header_size = r.read_u32_at(4)? as usize;
+    let toc_count = r.read_u32_at(12)? as usize;
+
+    let mut images = Vec::new();
+
+    for i in 0..toc_count {
+        let entry = header_size + i * 12;
+
+        let chunk_type = r.read_u32_at(entry)?;
+        let position = r.read_u32_at(entry + 8)? as usize;
+
+        if chunk_type != XCURSOR_IMAGE_TYPE {
+            continue;
+        }
+
+        images.push(parse_image(&r, position)?);
+    }
+
+    Ok(images)
+}
+
+/// Parses a single image chunk.
+fn parse_image(r: &Reader, position: usize) -> Result<XcursorImage> {
+    let chunk_header_size = r.read_u32_at(position)? as usize;
+
+    if r.read_u32_at(position + 4)? != XCURSOR_IMAGE_TYPE {
+        return Err("Table of contents does not match chunk type!");
+    }
+
+    let nominal_size = r.read_u32_at(position + 8)?;
+    let width = r.read_u32_at(position + 16)?;
+    let height = r.read_u32_at(position + 20)?;
+    let xhot = r.read_u32_at(position + 24)?;
+    let yhot = r.read_u32_at(position + 28)?;
+    let delay = r.read_u32_at(position + 32)?;
+
+    if width > XCURSOR_IMAGE_MAX_SIZE || height > XCURSOR_IMAGE_MAX_SIZE {
+        return Err("Image is too large!");
+    }
+
+    if xhot > width || yhot > height {
+        return Err("Hotspot lies outside of the image!");
+    }
+
+    let pixel_start = position + chunk_header_size;
+    let pixels = (0..(width * height) as usize)
+        .map(|i| r.read_u32_at(pixel_start + i * 4))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(XcursorImage {
+        nominal_size,
+        width,
+        height,
+        xhot,
+        yhot,
+        delay,
+        pixels,
+    })
+}
+
+/// Selects the images best matching a requested size.
+///
+/// All images with the nominal size closest to the requested one are returned in file order,
+/// which for animated cursors is the order of the frames.
+///
+/// # Arguments
+///
+/// * `images` - The images to choose from
+/// * `size` - The requested nominal size
+pub fn select_size(images: Vec<XcursorImage>, size: u32) -> Vec<XcursorImage> {
+    let best = images
+        .iter()
+        .map(|image| image.nominal_size)
+        .min_by_key(|&nominal| nominal.abs_diff(size));
+
+    match best {
+        Some(best) => images
+            .into_iter()
+            .filter(|image| image.nominal_size == best)
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A cursor with a comment chunk, two 2x2 frames of nominal size 24 and one 3x3 image of
+    /// nominal size 32.
+    const SAMPLE: &[u8] = include_bytes!("testdata/sample.xcursor");
+
+    /// Offset of the position field of the second table of contents entry.
+    const SECOND_TOC_POSITION: usize = 16 + 12 + 8;
+
+    /// Offset of the first image chunk.
+    const FIRST_IMAGE: usize = 102;
+
+    fn patched(offset: usize, value: u32) -> Vec<u8> {
+        let mut data = SAMPLE.to_vec();
+        data[offset..offset + 4].copy_from_slice(&value.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn parses_sample() {
+        let images = parse(SAMPLE).unwrap();
+
+        assert_eq!(images.len(), 3);
+
+        let sizes = images.iter().map(|i| i.nominal_size).collect::<Vec<_>>();
+        assert_eq!(sizes, [24, 24, 32]);
+
+        let first = &images[0];
+        assert_eq!((first.width, first.height), (2, 2));
+        assert_eq!((first.xhot, first.yhot), (1, 1));
+        assert_eq!(first.delay, 50);
+        assert_eq!(first.pixels.len(), 4);
+        assert_eq!(first.pixels[0], 0xFF111111);
+
+        let last = &images[2];
+        assert_eq!((last.width, last.height), (3, 3));
+        assert_eq!(last.pixels.len(), 9);
+    }
+
+    #[test]
+    fn rejects_invalid_magic() {
+        assert!(parse(&patched(0, 0x12345678)).is_err());
+        assert!(parse(b"").is_err());
+    }
+
+    #[test]
+    fn rejects_truncated_header() {
+        assert!(parse(&SAMPLE[..8]).is_err());
+        assert!(parse(&SAMPLE[..30]).is_err());
+    }
+
+    #[test]
+    fn rejects_truncated_pixels() {
+        assert!(parse(&SAMPLE[..SAMPLE.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn rejects_chunk_offset_past_end() {
+        assert!(parse(&patched(SECOND_TOC_POSITION, SAMPLE.len() as u32)).is_err());
+        assert!(parse(&patched(SECOND_TOC_POSITION, u32::MAX)).is_err());
+    }
+
+    #[test]
+    fn rejects_chunk_type_mismatch() {
+        // Point the image entry at the comment chunk
+        assert!(parse(&patched(SECOND_TOC_POSITION, 64)).is_err());
+    }
+
+    #[test]
+    fn rejects_table_of_contents_past_end() {
+        assert!(parse(&patched(12, u32::MAX)).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_image_dimensions() {
+        assert!(parse(&patched(FIRST_IMAGE + 16, XCURSOR_IMAGE_MAX_SIZE + 1)).is_err());
+        assert!(parse(&patched(FIRST_IMAGE + 24, 3)).is_err());
+    }
+
+    #[test]
+    fn selects_closest_size() {
+        let images = parse(SAMPLE).unwrap();
+
+        let exact = select_size(images.clone(), 24);
+        assert_eq!(exact.len(), 2);
+        assert_eq!(exact[0].pixels[0], 0xFF111111);
+        assert_eq!(exact[1].pixels[0], 0xFF222222);
+
+        let larger = select_size(images.clone(), 48);
+        assert_eq!(larger.len(), 1);
+        assert_eq!(larger[0].nominal_size, 32);
+
+        let smaller = select_size(images, 16);
+        assert!(smaller.iter().all(|image| image.nominal_size == 24));
+    }
+
+    #[test]
+    fn selects_nothing_from_no_images() {
+        assert!(select_size(Vec::new(), 24).is_empty());
+    }
+}
@@ -1,6 +1,267 @@
-use crate::glx_sys;
-use crate::XDisplay;
-use crate::XDrawable;
+use super::gl::{self, GLDebugCallback, KHR_DEBUG_EXTENSION};
+use crate::{glx_arb_sys, glx_ext_sys, glx_sys};
+use crate::{GLDebugMessage, GLInfo, GLXDrawable, GLXError, XDisplay};
+use x11::glx::GLX_BAD_ATTRIBUTE;
+
+/// Information about a renderer as reported by `GLX_MESA_query_renderer`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GLXRendererInfo {
+    /// The PCI vendor id of the device, 0 if unknown
+    pub vendor_id: u32,
+
+    /// The PCI device id of the device, 0 if unknown
+    pub device_id: u32,
+
+    /// Name of the vendor
+    pub vendor: Option<String>,
+
+    /// Name of the device
+    pub device: Option<String>,
+
+    /// Major, minor and patch version of the driver
+    pub version: (u32, u32, u32),
+
+    /// Whether the renderer is hardware accelerated
+    pub accelerated: bool,
+
+    /// Amount of video memory in megabytes
+    pub video_memory: u32,
+
+    /// Whether the device shares its memory with the CPU
+    pub unified_memory: bool,
+}
+
+/// The OpenGL profile of a context.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum GLXContextProfile {
+    /// Core profile without deprecated functionality
+    Core,
+
+    /// Compatibility profile including deprecated functionality
+    Compatibility,
+
+    /// OpenGL ES profile
+    Es,
+}
+
+/// Strategy used to report graphics resets to robust contexts.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(i32)]
+pub enum GLXResetNotification {
+    /// Resets are not reported
+    NoNotification = glx_ext_sys::GLX_NO_RESET_NOTIFICATION_ARB,
+
+    /// The context is lost on reset and the reset is reported
+    LoseContextOnReset = glx_ext_sys::GLX_LOSE_CONTEXT_ON_RESET_ARB,
+}
+
+/// Behavior when a context is released from being current.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(i32)]
+pub enum GLXReleaseBehavior {
+    /// Nothing happens, pending commands are not flushed
+    None = glx_ext_sys::GLX_CONTEXT_RELEASE_BEHAVIOR_NONE_ARB,
+
+    /// Pending commands are flushed
+    Flush = glx_ext_sys::GLX_CONTEXT_RELEASE_BEHAVIOR_FLUSH_ARB,
+}
+
+/// Attributes used to create a GLX context through `GLX_ARB_create_context`.
+///
+/// Attributes which are not set are left to the implementation defaults.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ContextAttributes {
+    version: Option<(i32, i32)>,
+    profile: Option<GLXContextProfile>,
+    debug: bool,
+    forward_compatible: bool,
+    robust_access: bool,
+    reset_notification: Option<GLXResetNotification>,
+    no_error: bool,
+    release_behavior: Option<GLXReleaseBehavior>,
+}
+
+impl ContextAttributes {
+    /// Creates a new set of context attributes without any set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the requested OpenGL version.
+    ///
+    /// The implementation may return a context of a newer version which is backwards
+    /// compatible with the requested one.
+    ///
+    /// # Arguments
+    ///
+    /// * `major` - The major version to request
+    /// * `minor` - The minor version to request
+    pub fn version(&mut self, major: i32, minor: i32) -> &mut Self {
+        self.version = Some((major, minor));
+        self
+    }
+
+    /// Sets the requested OpenGL profile.
+    pub fn profile(&mut self, profile: GLXContextProfile) -> &mut Self {
+        self.profile = Some(profile);
+        self
+    }
+
+    /// Sets whether a debug context should be created.
+    pub fn debug(&mut self, debug: bool) -> &mut Self {
+        self.debug = debug;
+        self
+    }
+
+    /// Sets whether deprecated functionality should be removed from the context.
+    pub fn forward_compatible(&mut self, forward_compatible: bool) -> &mut Self {
+        self.forward_compatible = forward_compatible;
+        self
+    }
+
+    /// Sets whether the context should provide robust buffer access.
+    pub fn robust_access(&mut self, robust_access: bool) -> &mut Self {
+        self.robust_access = robust_access;
+        self
+    }
+
+    /// Sets the strategy used to report graphics resets.
+    pub fn reset_notification(&mut self, strategy: GLXResetNotification) -> &mut Self {
+        self.reset_notification = Some(strategy);
+        self
+    }
+
+    /// Sets whether the context should not generate OpenGL errors.
+    pub fn no_error(&mut self, no_error: bool) -> &mut Self {
+        self.no_error = no_error;
+        self
+    }
+
+    /// Sets the behavior when the context is released from being current.
+    pub fn release_behavior(&mut self, behavior: GLXReleaseBehavior) -> &mut Self {
+        self.release_behavior = Some(behavior);
+        self
+    }
+
+    /// Retrieves the requested OpenGL version, if any.
+    pub fn requested_version(&self) -> Option<(i32, i32)> {
+        self.version
+    }
+
+    /// Determines whether no attributes have been set at all.
+    pub fn is_default(&self) -> bool {
+        self.version.is_none()
+            && self.profile.is_none()
+            && !self.debug
+            && !self.forward_compatible
+            && !self.robust_access
+            && self.reset_notification.is_none()
+            && !self.no_error
+            && self.release_behavior.is_none()
+    }
+
+    /// Finds the first GLX extension required by these attributes which is not available.
+    ///
+    /// # Arguments
+    ///
+    /// * `extensions` - The GLX extensions supported by the screen
+    pub(crate) fn missing_extension(&self, extensions: &[&str]) -> Option<&'static str> {
+        let mut required = vec![super::ARB_CREATE_CONTEXT_EXTENSION];
+
+        match self.profile {
+            // The ES profile extension is a superset of the ES2 profile extension
+            Some(GLXContextProfile::Es)
+                if !extensions.contains(&"GLX_EXT_create_context_es_profile") =>
+            {
+                required.push("GLX_EXT_create_context_es2_profile")
+            }
+            Some(GLXContextProfile::Es) => {}
+            Some(_) => required.push("GLX_ARB_create_context_profile"),
+            None => {}
+        }
+
+        if self.robust_access || self.reset_notification.is_some() {
+            required.push("GLX_ARB_create_context_robustness");
+        }
+
+        if self.no_error {
+            required.push("GLX_ARB_create_context_no_error");
+        }
+
+        if self.release_behavior.is_some() {
+            required.push("GLX_ARB_context_flush_control");
+        }
+
+        required
+            .into_iter()
+            .find(|extension| !extensions.contains(extension))
+    }
+
+    /// Turns the attributes into a zero terminated GLX attribute list.
+    pub fn into_native(self) -> Vec<i32> {
+        let mut attributes = Vec::new();
+
+        if let Some((major, minor)) = self.version {
+            attributes.extend([
+                glx_arb_sys::GLX_CONTEXT_MAJOR_VERSION_ARB,
+                major,
+                glx_arb_sys::GLX_CONTEXT_MINOR_VERSION_ARB,
+                minor,
+            ]);
+        }
+
+        if let Some(profile) = self.profile {
+            let mask = match profile {
+                GLXContextProfile::Core => glx_arb_sys::GLX_CONTEXT_CORE_PROFILE_BIT_ARB,
+                GLXContextProfile::Compatibility => {
+                    glx_arb_sys::GLX_CONTEXT_COMPATIBILITY_PROFILE_BIT_ARB
+                }
+                GLXContextProfile::Es => glx_ext_sys::GLX_CONTEXT_ES2_PROFILE_BIT_EXT,
+            };
+
+            attributes.extend([glx_arb_sys::GLX_CONTEXT_PROFILE_MASK_ARB, mask]);
+        }
+
+        let mut flags = 0;
+
+        if self.debug {
+            flags |= glx_arb_sys::GLX_CONTEXT_DEBUG_BIT_ARB;
+        }
+
+        if self.forward_compatible {
+            flags |= glx_arb_sys::GLX_CONTEXT_FORWARD_COMPATIBLE_BIT_ARB;
+        }
+
+        if self.robust_access {
+            flags |= glx_ext_sys::GLX_CONTEXT_ROBUST_ACCESS_BIT_ARB;
+        }
+
+        if flags != 0 {
+            attributes.extend([glx_arb_sys::GLX_CONTEXT_FLAGS_ARB, flags]);
+        }
+
+        if let Some(strategy) = self.reset_notification {
+            attributes.extend([
+                glx_ext_sys::GLX_CONTEXT_RESET_NOTIFICATION_STRATEGY_ARB,
+                strategy as i32,
+            ]);
+        }
+
+        if self.no_error {
+            attributes.extend([glx_ext_sys::GLX_CONTEXT_OPENGL_NO_ERROR_ARB, 1]);
+        }
+
+        if let Some(behavior) = self.release_behavior {
+            attributes.extend([
+                glx_ext_sys::GLX_CONTEXT_RELEASE_BEHAVIOR_ARB,
+                behavior as i32,
+            ]);
+        }
+
+        attributes.push(0);
+        attributes
+    }
+}
 
 /// A GLX OpenGL context bound to a specific display.
 ///
@@ -8,6 +269,7 @@ use crate::XDrawable;
 #[derive(Debug)]
 pub struct GLXContext<'a> {
     handle: glx_sys::GLXContext,
+    debug_callback: Option<Box<GLDebugCallback>>,
     display: &'a XDisplay,
 }
 
@@ -23,7 +285,16 @@ impl<'a> GLXContext<'a> {
     ///
     /// It is up to the caller to ensure that `handle` is a valid GLX context.
     pub unsafe fn new(handle: glx_sys::GLXContext, display: &'a XDisplay) -> Self {
-        Self { handle, display }
+        Self {
+            handle,
+            debug_callback: None,
+            display,
+        }
+    }
+
+    /// Retrieves the underlying native GLX context.
+    pub fn handle(&self) -> glx_sys::GLXContext {
+        self.handle
     }
 
     /// Determines whether this context uses direct rendering.
@@ -31,24 +302,160 @@ impl<'a> GLXContext<'a> {
         (unsafe { glx_sys::glXIsDirect(self.display.handle(), self.handle) }) > 0
     }
 
+    /// Determines whether this context is current on the calling thread.
+    pub fn is_current(&self) -> bool {
+        unsafe { glx_sys::glXGetCurrentContext() == self.handle }
+    }
+
+    /// Queries an attribute of the context.
+    ///
+    /// # Arguments
+    ///
+    /// * `attribute` - The attribute to query, for example `GLX_FBCONFIG_ID`
+    pub fn query(&self, attribute: i32) -> Result<i32, GLXError> {
+        let mut value = 0;
+        let ok = unsafe {
+            glx_sys::glXQueryContext(self.display.handle(), self.handle, attribute, &mut value)
+        };
+
+        match ok {
+            0 => Ok(value),
+            GLX_BAD_ATTRIBUTE => Err(GLXError::BadAttribute(attribute)),
+            v => Err(GLXError::GenericError(v)),
+        }
+    }
+
+    /// Retrieves the XID of the framebuffer configuration the context was created with.
+    pub fn fb_config_id(&self) -> Result<i32, GLXError> {
+        self.query(glx_sys::GLX_FBCONFIG_ID)
+    }
+
+    /// Retrieves the number of the screen the context was created on.
+    pub fn screen_number(&self) -> Result<i32, GLXError> {
+        self.query(glx_sys::GLX_SCREEN)
+    }
+
+    /// Retrieves the vendor, renderer and version strings of the OpenGL implementation.
+    ///
+    /// Returns [`None`] if the context is not current on the calling thread.
+    pub fn gl_info(&self) -> Option<GLInfo> {
+        if !self.is_current() {
+            return None;
+        }
+
+        unsafe { gl::query_info() }
+    }
+
+    /// Retrieves the OpenGL extensions supported by the context.
+    ///
+    /// The context has to be current on the calling thread.
+    pub fn gl_extensions(&self) -> Result<Vec<String>, GLXError> {
+        if !self.is_current() {
+            return Err(GLXError::ContextNotCurrent);
+        }
+
+        Ok(unsafe { gl::query_extensions() })
+    }
+
+    /// Routes OpenGL debug messages of the context into a callback.
+    ///
+    /// Requires `GL_KHR_debug` and the context being current on the calling thread. Messages
+    /// are generated synchronously, so the callback is invoked on the thread issuing the
+    /// OpenGL call causing the message. Most implementations only generate a meaningful amount
+    /// of messages for debug contexts, see [`ContextAttributes::debug`]. Installing a new
+    /// callback replaces the previous one.
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - The callback to invoke for every debug message
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wrap11::{ContextAttributes, FBConfigCriteria, XDisplay, GLX};
+    ///
+    /// let display = XDisplay::open(None).expect("Failed to open display");
+    /// let screen = display.default_screen();
+    /// let glx = GLX::create(&display).unwrap();
+    ///
+    /// let configs = glx
+    ///     .choose_framebuffer_configs(&screen, FBConfigCriteria::new())
+    ///     .unwrap();
+    ///
+    /// let mut attributes = ContextAttributes::new();
+    /// attributes.debug(true);
+    ///
+    /// let mut context = glx
+    ///     .create_context_with_attributes(&screen, &configs[0], attributes, None)
+    ///     .unwrap();
+    ///
+    /// let window = screen.create_simple_window(0, 0, 640, 480, 0, 0, 0);
+    /// context.make_current(&window);
+    ///
+    /// context
+    ///     .set_debug_callback(|message| eprintln!("OpenGL: {}", message.message))
+    ///     .expect("Debug output is not supported");
+    /// ```
+    pub fn set_debug_callback<F>(&mut self, callback: F) -> Result<(), GLXError>
+    where
+        F: Fn(&GLDebugMessage) + 'static,
+    {
+        let extensions = self.gl_extensions()?;
+
+        if !extensions.iter().any(|e| e == KHR_DEBUG_EXTENSION) {
+            return Err(GLXError::MissingExtension(KHR_DEBUG_EXTENSION));
+        }
+
+        let callback = unsafe { gl::install_debug_callback(Box::new(callback)) }
+            .ok_or(GLXError::MissingExtension(KHR_DEBUG_EXTENSION))?;
+
+        // Replacing the old callback only after the new one has been installed
+        self.debug_callback = Some(callback);
+
+        Ok(())
+    }
+
     /// Makes the context current using the given drawable.
     ///
     /// # Arguments
     ///
-    /// * `drawable` - An X11 drawable such as a window or a pixmap
+    /// * `drawable` - A GLX drawable such as a window, a GLX pixmap or a pbuffer
     pub fn make_current<D>(&self, drawable: &D)
     where
-        D: XDrawable<'a>,
+        D: GLXDrawable<'a>,
     {
         unsafe {
             glx_sys::glXMakeCurrent(
                 self.display.handle(),
-                drawable.drawable_handle(),
+                drawable.glx_drawable_handle(),
                 self.handle,
             )
         };
     }
 
+    /// Makes the context current using separate drawables for drawing and reading.
+    ///
+    /// Returns whether the context could be made current.
+    ///
+    /// # Arguments
+    ///
+    /// * `draw` - The drawable to render to
+    /// * `read` - The drawable to read pixels from, for example using `glReadPixels`
+    pub fn make_context_current<D, R>(&self, draw: &D, read: &R) -> bool
+    where
+        D: GLXDrawable<'a>,
+        R: GLXDrawable<'a>,
+    {
+        (unsafe {
+            glx_sys::glXMakeContextCurrent(
+                self.display.handle(),
+                draw.glx_drawable_handle(),
+                read.glx_drawable_handle(),
+                self.handle,
+            )
+        }) != 0
+    }
+
     /// Swaps the front/back buffers of the given drawable.
     ///
     /// The context has to be the current context and be active on the given drawable in order for
@@ -56,12 +463,12 @@ impl<'a> GLXContext<'a> {
     ///
     /// # Arguments
     ///
-    /// * `drawable` - An X11 drawable such as a window or a pixmap
+    /// * `drawable` - A GLX drawable such as a window, a GLX pixmap or a pbuffer
     pub fn swap_buffers<D>(&self, drawable: &D)
     where
-        D: XDrawable<'a>,
+        D: GLXDrawable<'a>,
     {
-        unsafe { glx_sys::glXSwapBuffers(self.display.handle(), drawable.drawable_handle()) }
+        unsafe { glx_sys::glXSwapBuffers(self.display.handle(), drawable.glx_drawable_handle()) }
     }
 
     /// Removes the current context from the display.
@@ -78,3 +485,117 @@ impl<'a> Drop for GLXContext<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CREATE_CONTEXT: &str = "GLX_ARB_create_context";
+
+    #[test]
+    fn create_context_is_always_required() {
+        let attributes = ContextAttributes::new();
+
+        assert_eq!(attributes.missing_extension(&[]), Some(CREATE_CONTEXT));
+        assert_eq!(attributes.missing_extension(&[CREATE_CONTEXT]), None);
+    }
+
+    #[test]
+    fn es_profile_falls_back_to_es2_extension() {
+        let mut attributes = ContextAttributes::new();
+        attributes.profile(GLXContextProfile::Es);
+
+        assert_eq!(
+            attributes.missing_extension(&[CREATE_CONTEXT]),
+            Some("GLX_EXT_create_context_es2_profile")
+        );
+        assert_eq!(
+            attributes.missing_extension(&[CREATE_CONTEXT, "GLX_EXT_create_context_es_profile"]),
+            None
+        );
+        assert_eq!(
+            attributes.missing_extension(&[CREATE_CONTEXT, "GLX_EXT_create_context_es2_profile"]),
+            None
+        );
+    }
+
+    #[test]
+    fn desktop_profiles_require_profile_extension() {
+        let mut attributes = ContextAttributes::new();
+        attributes.profile(GLXContextProfile::Core);
+
+        assert_eq!(
+            attributes.missing_extension(&[CREATE_CONTEXT, "GLX_EXT_create_context_es_profile"]),
+            Some("GLX_ARB_create_context_profile")
+        );
+        assert_eq!(
+            attributes.missing_extension(&[CREATE_CONTEXT, "GLX_ARB_create_context_profile"]),
+            None
+        );
+    }
+
+    #[test]
+    fn reports_first_missing_extension() {
+        let mut attributes = ContextAttributes::new();
+        attributes
+            .reset_notification(GLXResetNotification::LoseContextOnReset)
+            .no_error(true)
+            .release_behavior(GLXReleaseBehavior::None);
+
+        let mut extensions = vec![CREATE_CONTEXT];
+        assert_eq!(
+            attributes.missing_extension(&extensions),
+            Some("GLX_ARB_create_context_robustness")
+        );
+
+        extensions.push("GLX_ARB_create_context_robustness");
+        assert_eq!(
+            attributes.missing_extension(&extensions),
+            Some("GLX_ARB_create_context_no_error")
+        );
+
+        extensions.push("GLX_ARB_create_context_no_error");
+        assert_eq!(
+            attributes.missing_extension(&extensions),
+            Some("GLX_ARB_context_flush_control")
+        );
+
+        extensions.push("GLX_ARB_context_flush_control");
+        assert_eq!(attributes.missing_extension(&extensions), None);
+    }
+
+    #[test]
+    fn default_attributes_are_only_terminated() {
+        let attributes = ContextAttributes::new();
+
+        assert!(attributes.is_default());
+        assert_eq!(attributes.into_native(), vec![0]);
+    }
+
+    #[test]
+    fn attributes_are_converted_to_attribute_pairs() {
+        let mut attributes = ContextAttributes::new();
+        attributes
+            .version(3, 2)
+            .profile(GLXContextProfile::Es)
+            .debug(true)
+            .robust_access(true);
+
+        assert!(!attributes.is_default());
+        assert_eq!(
+            attributes.into_native(),
+            vec![
+                glx_arb_sys::GLX_CONTEXT_MAJOR_VERSION_ARB,
+                3,
+                glx_arb_sys::GLX_CONTEXT_MINOR_VERSION_ARB,
+                2,
+                glx_arb_sys::GLX_CONTEXT_PROFILE_MASK_ARB,
+                glx_ext_sys::GLX_CONTEXT_ES2_PROFILE_BIT_EXT,
+                glx_arb_sys::GLX_CONTEXT_FLAGS_ARB,
+                glx_arb_sys::GLX_CONTEXT_DEBUG_BIT_ARB
+                    | glx_ext_sys::GLX_CONTEXT_ROBUST_ACCESS_BIT_ARB,
+                0,
+            ]
+        );
+    }
+}
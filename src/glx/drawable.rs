@@ -0,0 +1,36 @@
+use crate::glx_sys;
+use crate::{GLXPbuffer, GLXPixmap, GLXWindow, XWindow};
+
+/// Represents a drawable which can be rendered to using GLX.
+///
+/// GLX windows, pixmaps and pbuffers are GLX resources rather than X11 drawables, core X11
+/// requests on them fail. Such requests have to be sent to the backing X11 drawable instead,
+/// which is why this trait is separate from [`XDrawable`](crate::XDrawable).
+pub trait GLXDrawable<'a> {
+    /// Retrieves the underlying native GLX drawable representation.
+    fn glx_drawable_handle(&self) -> glx_sys::GLXDrawable;
+}
+
+impl<'a> GLXDrawable<'a> for XWindow<'a> {
+    fn glx_drawable_handle(&self) -> glx_sys::GLXDrawable {
+        self.handle()
+    }
+}
+
+impl<'w, 'a> GLXDrawable<'a> for GLXWindow<'w, 'a> {
+    fn glx_drawable_handle(&self) -> glx_sys::GLXDrawable {
+        self.handle()
+    }
+}
+
+impl<'a> GLXDrawable<'a> for GLXPixmap<'a> {
+    fn glx_drawable_handle(&self) -> glx_sys::GLXDrawable {
+        self.handle()
+    }
+}
+
+impl<'a> GLXDrawable<'a> for GLXPbuffer<'a> {
+    fn glx_drawable_handle(&self) -> glx_sys::GLXDrawable {
+        self.handle()
+    }
+}
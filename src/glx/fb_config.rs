@@ -1,8 +1,451 @@
-use crate::glx_sys;
+use crate::{glx_ext_sys, glx_sys};
 use crate::{xlib_sys, GLXError, XDisplay};
 use crate::{XVisual, XVisualInfo};
 use x11::glx::{glXGetFBConfigAttrib, glXGetVisualFromFBConfig, GLX_BAD_ATTRIBUTE};
 
+bitflags::bitflags! {
+    /// The kinds of drawables a framebuffer configuration can render to.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct GLXDrawableTypes: i32 {
+        const WINDOW = glx_sys::GLX_WINDOW_BIT;
+        const PIXMAP = glx_sys::GLX_PIXMAP_BIT;
+        const PBUFFER = glx_sys::GLX_PBUFFER_BIT;
+    }
+}
+
+bitflags::bitflags! {
+    /// The kinds of contexts a framebuffer configuration can be rendered to by.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct GLXRenderTypes: i32 {
+        const RGBA = glx_sys::GLX_RGBA_BIT;
+        const COLOR_INDEX = glx_sys::GLX_COLOR_INDEX_BIT;
+    }
+}
+
+bitflags::bitflags! {
+    /// The texture targets a framebuffer configuration can be bound to using
+    /// `GLX_EXT_texture_from_pixmap`.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct GLXTextureTargets: i32 {
+        const TEXTURE_1D = glx_ext_sys::GLX_TEXTURE_1D_BIT_EXT;
+        const TEXTURE_2D = glx_ext_sys::GLX_TEXTURE_2D_BIT_EXT;
+        const RECTANGLE = glx_ext_sys::GLX_TEXTURE_RECTANGLE_BIT_EXT;
+    }
+}
+
+/// Caveat attached to a framebuffer configuration by the implementation.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum GLXConfigCaveat {
+    /// The configuration has no caveats
+    None,
+
+    /// Rendering to the configuration may be slow, usually due to a software fallback
+    Slow,
+
+    /// The configuration does not pass the OpenGL conformance tests
+    NonConformant,
+}
+
+impl GLXConfigCaveat {
+    /// Converts the native caveat value, treating unknown values as no caveat.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value of the `GLX_CONFIG_CAVEAT` attribute
+    pub fn from_native(value: i32) -> Self {
+        match value {
+            glx_sys::GLX_SLOW_CONFIG => Self::Slow,
+            glx_sys::GLX_NON_CONFORMANT_CONFIG => Self::NonConformant,
+            _ => Self::None,
+        }
+    }
+}
+
+/// Kind of transparency a framebuffer configuration supports for overlay planes.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum GLXTransparentType {
+    /// The configuration is opaque
+    None,
+
+    /// A specific RGB value is treated as transparent
+    Rgb,
+
+    /// A specific color index is treated as transparent
+    Index,
+}
+
+impl GLXTransparentType {
+    /// Converts the native transparency type, treating unknown values as opaque.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value of the `GLX_TRANSPARENT_TYPE` attribute
+    pub fn from_native(value: i32) -> Self {
+        match value {
+            glx_sys::GLX_TRANSPARENT_RGB => Self::Rgb,
+            glx_sys::GLX_TRANSPARENT_INDEX => Self::Index,
+            _ => Self::None,
+        }
+    }
+}
+
+/// Snapshot of the attributes of a framebuffer configuration.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GLXFBConfigInfo {
+    /// The XID of the configuration
+    pub id: i32,
+
+    /// The id of the associated X visual, 0 if there is none
+    pub visual_id: u64,
+
+    /// Total amount of bits in the color buffer
+    pub buffer_size: u32,
+
+    /// Amount of bits in the red channel
+    pub red_size: u32,
+
+    /// Amount of bits in the green channel
+    pub green_size: u32,
+
+    /// Amount of bits in the blue channel
+    pub blue_size: u32,
+
+    /// Amount of bits in the alpha channel
+    pub alpha_size: u32,
+
+    /// Amount of bits in the depth buffer
+    pub depth_size: u32,
+
+    /// Amount of bits in the stencil buffer
+    pub stencil_size: u32,
+
+    /// Amount of auxiliary color buffers
+    pub aux_buffers: u32,
+
+    /// Whether the configuration has front and back buffers
+    pub double_buffer: bool,
+
+    /// Whether the configuration has left and right buffers
+    pub stereo: bool,
+
+    /// Amount of multisample buffers
+    pub sample_buffers: u32,
+
+    /// Amount of samples per pixel
+    pub samples: u32,
+
+    /// Whether the configuration supports sRGB rendering
+    pub srgb_capable: bool,
+
+    /// The kinds of drawables the configuration can render to
+    pub drawable_types: GLXDrawableTypes,
+
+    /// The kinds of contexts the configuration can be rendered to by
+    pub render_types: GLXRenderTypes,
+
+    /// Whether the configuration has an associated X visual
+    pub x_renderable: bool,
+
+    /// Caveat attached by the implementation
+    pub caveat: GLXConfigCaveat,
+
+    /// Kind of transparency supported
+    pub transparent_type: GLXTransparentType,
+
+    /// Whether pixmaps of the configuration can be bound to RGB textures
+    pub bind_to_texture_rgb: bool,
+
+    /// Whether pixmaps of the configuration can be bound to RGBA textures
+    pub bind_to_texture_rgba: bool,
+
+    /// Whether pixmaps of the configuration can be bound to mipmapped textures
+    pub bind_to_mipmap_texture: bool,
+
+    /// The texture targets pixmaps of the configuration can be bound to
+    pub bind_to_texture_targets: GLXTextureTargets,
+
+    /// Whether bound textures have their origin at the top left instead of the bottom left
+    pub y_inverted: bool,
+
+    /// Maximum width of a pbuffer created with the configuration
+    pub max_pbuffer_width: u32,
+
+    /// Maximum height of a pbuffer created with the configuration
+    pub max_pbuffer_height: u32,
+
+    /// Maximum amount of pixels of a pbuffer created with the configuration
+    pub max_pbuffer_pixels: u32,
+}
+
+/// Criteria used to choose framebuffer configurations.
+///
+/// Sizes are minimum requirements, boolean criteria are exact requirements. Criteria which are
+/// not set are left to the GLX defaults.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FBConfigCriteria {
+    red_size: Option<u32>,
+    green_size: Option<u32>,
+    blue_size: Option<u32>,
+    alpha_size: Option<u32>,
+    depth_size: Option<u32>,
+    stencil_size: Option<u32>,
+    double_buffer: Option<bool>,
+    sample_buffers: Option<u32>,
+    samples: Option<u32>,
+    srgb_capable: Option<bool>,
+    drawable_types: Option<GLXDrawableTypes>,
+    render_types: Option<GLXRenderTypes>,
+    bind_to_texture_rgb: Option<bool>,
+    bind_to_texture_rgba: Option<bool>,
+    bind_to_mipmap_texture: Option<bool>,
+    bind_to_texture_targets: Option<GLXTextureTargets>,
+    argb_visual: bool,
+}
+
+impl FBConfigCriteria {
+    /// Creates a new set of criteria without any set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the minimum size of the red, green, blue and alpha channels.
+    ///
+    /// # Arguments
+    ///
+    /// * `red` - The minimum amount of red bits
+    /// * `green` - The minimum amount of green bits
+    /// * `blue` - The minimum amount of blue bits
+    /// * `alpha` - The minimum amount of alpha bits
+    pub fn rgba_sizes(&mut self, red: u32, green: u32, blue: u32, alpha: u32) -> &mut Self {
+        self.red_size = Some(red);
+        self.green_size = Some(green);
+        self.blue_size = Some(blue);
+        self.alpha_size = Some(alpha);
+        self
+    }
+
+    /// Sets the minimum size of the depth buffer.
+    pub fn depth_size(&mut self, size: u32) -> &mut Self {
+        self.depth_size = Some(size);
+        self
+    }
+
+    /// Sets the minimum size of the stencil buffer.
+    pub fn stencil_size(&mut self, size: u32) -> &mut Self {
+        self.stencil_size = Some(size);
+        self
+    }
+
+    /// Sets whether the configuration must or must not be double buffered.
+    pub fn double_buffer(&mut self, double_buffer: bool) -> &mut Self {
+        self.double_buffer = Some(double_buffer);
+        self
+    }
+
+    /// Sets the minimum amount of multisample buffers.
+    pub fn sample_buffers(&mut self, buffers: u32) -> &mut Self {
+        self.sample_buffers = Some(buffers);
+        self
+    }
+
+    /// Sets the minimum amount of samples per pixel.
+    ///
+    /// Configurations with a sample count closer to the requested one are ranked first.
+    pub fn samples(&mut self, samples: u32) -> &mut Self {
+        self.samples = Some(samples);
+        self
+    }
+
+    /// Sets whether the configuration must support sRGB rendering.
+    ///
+    /// Requires `GLX_ARB_framebuffer_sRGB` or `GLX_EXT_framebuffer_sRGB`.
+    pub fn srgb_capable(&mut self, capable: bool) -> &mut Self {
+        self.srgb_capable = Some(capable);
+        self
+    }
+
+    /// Sets the kinds of drawables the configuration must be able to render to.
+    pub fn drawable_types(&mut self, types: GLXDrawableTypes) -> &mut Self {
+        self.drawable_types = Some(types);
+        self
+    }
+
+    /// Sets the kinds of contexts the configuration must support.
+    pub fn render_types(&mut self, types: GLXRenderTypes) -> &mut Self {
+        self.render_types = Some(types);
+        self
+    }
+
+    /// Sets whether pixmaps of the configuration must be bindable to RGB textures.
+    ///
+    /// Requires `GLX_EXT_texture_from_pixmap`.
+    pub fn bind_to_texture_rgb(&mut self, bindable: bool) -> &mut Self {
+        self.bind_to_texture_rgb = Some(bindable);
+        self
+    }
+
+    /// Sets whether pixmaps of the configuration must be bindable to RGBA textures.
+    ///
+    /// Requires `GLX_EXT_texture_from_pixmap`.
+    pub fn bind_to_texture_rgba(&mut self, bindable: bool) -> &mut Self {
+        self.bind_to_texture_rgba = Some(bindable);
+        self
+    }
+
+    /// Sets whether pixmaps of the configuration must be bindable to mipmapped textures.
+    ///
+    /// Requires `GLX_EXT_texture_from_pixmap`.
+    pub fn bind_to_mipmap_texture(&mut self, bindable: bool) -> &mut Self {
+        self.bind_to_mipmap_texture = Some(bindable);
+        self
+    }
+
+    /// Sets the texture targets pixmaps of the configuration must be bindable to.
+    ///
+    /// Requires `GLX_EXT_texture_from_pixmap`.
+    pub fn bind_to_texture_targets(&mut self, targets: GLXTextureTargets) -> &mut Self {
+        self.bind_to_texture_targets = Some(targets);
+        self
+    }
+
+    /// Sets whether the configuration must have a visual with an alpha channel.
+    ///
+    /// Such visuals are required for windows which are blended by a compositing manager, for
+    /// example transparent overlays. Usually this is combined with a requested alpha size.
+    pub fn argb_visual(&mut self, required: bool) -> &mut Self {
+        self.argb_visual = required;
+        self
+    }
+
+    /// Determines whether a visual with an alpha channel is required.
+    pub fn requires_argb_visual(&self) -> bool {
+        self.argb_visual
+    }
+
+    /// Turns the criteria into a zero terminated GLX attribute list.
+    pub fn into_native(self) -> Vec<i32> {
+        let mut attributes = Vec::new();
+
+        let sizes = [
+            (glx_sys::GLX_RED_SIZE, self.red_size),
+            (glx_sys::GLX_GREEN_SIZE, self.green_size),
+            (glx_sys::GLX_BLUE_SIZE, self.blue_size),
+            (glx_sys::GLX_ALPHA_SIZE, self.alpha_size),
+            (glx_sys::GLX_DEPTH_SIZE, self.depth_size),
+            (glx_sys::GLX_STENCIL_SIZE, self.stencil_size),
+            (glx_sys::GLX_SAMPLE_BUFFERS, self.sample_buffers),
+            (glx_sys::GLX_SAMPLES, self.samples),
+        ];
+
+        for (attribute, size) in sizes {
+            if let Some(size) = size {
+                attributes.extend([attribute, size as i32]);
+            }
+        }
+
+        if let Some(double_buffer) = self.double_buffer {
+            attributes.extend([glx_sys::GLX_DOUBLEBUFFER, double_buffer as i32]);
+        }
+
+        if let Some(capable) = self.srgb_capable {
+            attributes.extend([
+                glx_ext_sys::GLX_FRAMEBUFFER_SRGB_CAPABLE_ARB,
+                capable as i32,
+            ]);
+        }
+
+        let texture_bindings = [
+            (
+                glx_ext_sys::GLX_BIND_TO_TEXTURE_RGB_EXT,
+                self.bind_to_texture_rgb,
+            ),
+            (
+                glx_ext_sys::GLX_BIND_TO_TEXTURE_RGBA_EXT,
+                self.bind_to_texture_rgba,
+            ),
+            (
+                glx_ext_sys::GLX_BIND_TO_MIPMAP_TEXTURE_EXT,
+                self.bind_to_mipmap_texture,
+            ),
+        ];
+
+        for (attribute, bindable) in texture_bindings {
+            if let Some(bindable) = bindable {
+                attributes.extend([attribute, bindable as i32]);
+            }
+        }
+
+        if let Some(targets) = self.bind_to_texture_targets {
+            attributes.extend([glx_ext_sys::GLX_BIND_TO_TEXTURE_TARGETS_EXT, targets.bits()]);
+        }
+
+        if let Some(types) = self.drawable_types {
+            attributes.extend([glx_sys::GLX_DRAWABLE_TYPE, types.bits()]);
+        }
+
+        if let Some(types) = self.render_types {
+            attributes.extend([glx_sys::GLX_RENDER_TYPE, types.bits()]);
+        }
+
+        if self.argb_visual {
+            attributes.extend([
+                glx_sys::GLX_X_RENDERABLE,
+                1,
+                glx_sys::GLX_X_VISUAL_TYPE,
+                glx_sys::GLX_TRUE_COLOR,
+            ]);
+        }
+
+        attributes.push(0);
+        attributes
+    }
+
+    /// Computes the ranking key of a matching configuration, lower keys are better.
+    ///
+    /// Configurations without caveats come first, followed by the closest sample count and
+    /// the least amount of unrequested color, depth and stencil bits.
+    pub(crate) fn rank(&self, info: &GLXFBConfigInfo) -> (bool, u32, u32) {
+        rank_config(
+            [
+                (info.red_size, self.red_size),
+                (info.green_size, self.green_size),
+                (info.blue_size, self.blue_size),
+                (info.alpha_size, self.alpha_size),
+                (info.depth_size, self.depth_size),
+                (info.stencil_size, self.stencil_size),
+            ],
+            (info.samples, self.samples),
+            info.caveat != GLXConfigCaveat::None,
+        )
+    }
+}
+
+/// Computes the ranking key of a framebuffer configuration, lower keys are better.
+///
+/// This is shared by GLX and EGL. Configurations without caveats come first, followed by the
+/// closest sample count and the least amount of unrequested color, depth and stencil bits.
+///
+/// # Arguments
+///
+/// * `sizes` - The actual and requested sizes of the color, depth and stencil buffers
+/// * `samples` - The actual and requested amount of samples per pixel
+/// * `caveat` - Whether the configuration has a caveat
+pub(crate) fn rank_config(
+    sizes: [(u32, Option<u32>); 6],
+    samples: (u32, Option<u32>),
+    caveat: bool,
+) -> (bool, u32, u32) {
+    let (actual_samples, requested_samples) = samples;
+    let samples = actual_samples.abs_diff(requested_samples.unwrap_or(0));
+
+    let excess = sizes
+        .into_iter()
+        .map(|(actual, requested)| actual.saturating_sub(requested.unwrap_or(actual)))
+        .sum();
+
+    (caveat, samples, excess)
+}
+
 /// Wrapped array of GLX framebuffer configurations.
 #[derive(Debug)]
 pub struct GLXFBConfigArray<'a> {
@@ -159,10 +602,70 @@ impl<'a> GLXFBConfig<'a> {
                 owned_handle
             };
 
-            Some(unsafe { XVisualInfo::new(owned_handle, XVisual::new((*handle).visual)) })
+            Some(unsafe { XVisualInfo::new(owned_handle, XVisual::new(owned_handle.visual)) })
         }
     }
 
+    /// Determines whether the visual of the configuration has an alpha channel.
+    ///
+    /// This checks the XRender picture format of the visual, since the depth of the visual
+    /// alone does not guarantee that the additional bits are used for alpha.
+    pub fn has_argb_visual(&self) -> bool {
+        self.get_visual()
+            .is_some_and(|info| info.is_argb(self.display))
+    }
+
+    /// Queries all attributes of the configuration at once.
+    ///
+    /// Attributes introduced by extensions the implementation does not support are reported as
+    /// disabled.
+    pub fn info(&self) -> Result<GLXFBConfigInfo, GLXError> {
+        let get = |attrib| self.get_attribute(attrib);
+        let get_size = |attrib| get(attrib).map(|v| v.max(0) as u32);
+        let get_bool = |attrib| get(attrib).map(|v| v != 0);
+
+        // Extension attributes are reported as bad attributes if the extension is missing
+        let get_optional = |attrib| match get(attrib) {
+            Err(GLXError::BadAttribute(_)) => Ok(0),
+            v => v,
+        };
+
+        let srgb_capable = get_optional(glx_ext_sys::GLX_FRAMEBUFFER_SRGB_CAPABLE_ARB)? != 0;
+
+        Ok(GLXFBConfigInfo {
+            id: get(glx_sys::GLX_FBCONFIG_ID)?,
+            visual_id: get(glx_sys::GLX_VISUAL_ID)? as u32 as u64,
+            buffer_size: get_size(glx_sys::GLX_BUFFER_SIZE)?,
+            red_size: get_size(glx_sys::GLX_RED_SIZE)?,
+            green_size: get_size(glx_sys::GLX_GREEN_SIZE)?,
+            blue_size: get_size(glx_sys::GLX_BLUE_SIZE)?,
+            alpha_size: get_size(glx_sys::GLX_ALPHA_SIZE)?,
+            depth_size: get_size(glx_sys::GLX_DEPTH_SIZE)?,
+            stencil_size: get_size(glx_sys::GLX_STENCIL_SIZE)?,
+            aux_buffers: get_size(glx_sys::GLX_AUX_BUFFERS)?,
+            double_buffer: get_bool(glx_sys::GLX_DOUBLEBUFFER)?,
+            stereo: get_bool(glx_sys::GLX_STEREO)?,
+            sample_buffers: get_size(glx_sys::GLX_SAMPLE_BUFFERS)?,
+            samples: get_size(glx_sys::GLX_SAMPLES)?,
+            srgb_capable,
+            drawable_types: GLXDrawableTypes::from_bits_truncate(get(glx_sys::GLX_DRAWABLE_TYPE)?),
+            render_types: GLXRenderTypes::from_bits_truncate(get(glx_sys::GLX_RENDER_TYPE)?),
+            x_renderable: get_bool(glx_sys::GLX_X_RENDERABLE)?,
+            caveat: GLXConfigCaveat::from_native(get(glx_sys::GLX_CONFIG_CAVEAT)?),
+            transparent_type: GLXTransparentType::from_native(get(glx_sys::GLX_TRANSPARENT_TYPE)?),
+            bind_to_texture_rgb: get_optional(glx_ext_sys::GLX_BIND_TO_TEXTURE_RGB_EXT)? != 0,
+            bind_to_texture_rgba: get_optional(glx_ext_sys::GLX_BIND_TO_TEXTURE_RGBA_EXT)? != 0,
+            bind_to_mipmap_texture: get_optional(glx_ext_sys::GLX_BIND_TO_MIPMAP_TEXTURE_EXT)? != 0,
+            bind_to_texture_targets: GLXTextureTargets::from_bits_truncate(get_optional(
+                glx_ext_sys::GLX_BIND_TO_TEXTURE_TARGETS_EXT,
+            )?),
+            y_inverted: get_optional(glx_ext_sys::GLX_Y_INVERTED_EXT)? != 0,
+            max_pbuffer_width: get_size(glx_sys::GLX_MAX_PBUFFER_WIDTH)?,
+            max_pbuffer_height: get_size(glx_sys::GLX_MAX_PBUFFER_HEIGHT)?,
+            max_pbuffer_pixels: get_size(glx_sys::GLX_MAX_PBUFFER_PIXELS)?,
+        })
+    }
+
     /// Queries an attribute of the configuration.
     ///
     /// # Arguments
@@ -203,3 +706,139 @@ impl<'a> GLXFBConfig<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(color: u32, depth: u32, samples: u32, caveat: GLXConfigCaveat) -> GLXFBConfigInfo {
+        GLXFBConfigInfo {
+            id: 0,
+            visual_id: 0,
+            buffer_size: color * 4,
+            red_size: color,
+            green_size: color,
+            blue_size: color,
+            alpha_size: color,
+            depth_size: depth,
+            stencil_size: 0,
+            aux_buffers: 0,
+            double_buffer: true,
+            stereo: false,
+            sample_buffers: (samples > 0) as u32,
+            samples,
+            srgb_capable: false,
+            drawable_types: GLXDrawableTypes::WINDOW,
+            render_types: GLXRenderTypes::RGBA,
+            x_renderable: true,
+            caveat,
+            transparent_type: GLXTransparentType::None,
+            bind_to_texture_rgb: false,
+            bind_to_texture_rgba: false,
+            bind_to_mipmap_texture: false,
+            bind_to_texture_targets: GLXTextureTargets::empty(),
+            y_inverted: false,
+            max_pbuffer_width: 0,
+            max_pbuffer_height: 0,
+            max_pbuffer_pixels: 0,
+        }
+    }
+
+    fn criteria() -> FBConfigCriteria {
+        let mut criteria = FBConfigCriteria::new();
+        criteria.rgba_sizes(8, 8, 8, 8).depth_size(24).samples(4);
+        criteria
+    }
+
+    #[test]
+    fn caveats_rank_before_samples() {
+        let criteria = criteria();
+        let slow = criteria.rank(&info(8, 24, 4, GLXConfigCaveat::Slow));
+        let far = criteria.rank(&info(8, 24, 16, GLXConfigCaveat::None));
+
+        assert!(far < slow);
+    }
+
+    #[test]
+    fn sample_distance_ranks_before_excess_bits() {
+        let criteria = criteria();
+        let exact = criteria.rank(&info(10, 32, 4, GLXConfigCaveat::None));
+        let fewer = criteria.rank(&info(8, 24, 2, GLXConfigCaveat::None));
+
+        assert_eq!(exact, (false, 0, 16));
+        assert_eq!(fewer, (false, 2, 0));
+        assert!(exact < fewer);
+    }
+
+    #[test]
+    fn excess_bits_rank_last() {
+        let criteria = criteria();
+        let exact = criteria.rank(&info(8, 24, 4, GLXConfigCaveat::None));
+        let deeper = criteria.rank(&info(8, 32, 4, GLXConfigCaveat::None));
+
+        assert_eq!(exact, (false, 0, 0));
+        assert_eq!(deeper, (false, 0, 8));
+    }
+
+    #[test]
+    fn unrequested_sizes_are_not_excess() {
+        let info = info(8, 24, 0, GLXConfigCaveat::NonConformant);
+
+        assert_eq!(FBConfigCriteria::new().rank(&info), (true, 0, 0));
+    }
+
+    #[test]
+    fn ranking_sorts_configs() {
+        let criteria = criteria();
+        let mut configs = [
+            info(8, 24, 4, GLXConfigCaveat::Slow),
+            info(8, 32, 4, GLXConfigCaveat::None),
+            info(8, 24, 8, GLXConfigCaveat::None),
+            info(8, 24, 4, GLXConfigCaveat::None),
+        ];
+
+        configs.sort_by_key(|info| criteria.rank(info));
+
+        assert_eq!(
+            configs.map(|info| (info.caveat, info.samples, info.depth_size)),
+            [
+                (GLXConfigCaveat::None, 4, 24),
+                (GLXConfigCaveat::None, 4, 32),
+                (GLXConfigCaveat::None, 8, 24),
+                (GLXConfigCaveat::Slow, 4, 24),
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_criteria_are_only_terminated() {
+        assert_eq!(FBConfigCriteria::new().into_native(), vec![0]);
+    }
+
+    #[test]
+    fn criteria_are_converted_to_attribute_pairs() {
+        let mut criteria = FBConfigCriteria::new();
+        criteria
+            .depth_size(24)
+            .double_buffer(true)
+            .drawable_types(GLXDrawableTypes::WINDOW | GLXDrawableTypes::PBUFFER)
+            .argb_visual(true);
+
+        assert_eq!(
+            criteria.into_native(),
+            vec![
+                glx_sys::GLX_DEPTH_SIZE,
+                24,
+                glx_sys::GLX_DOUBLEBUFFER,
+                1,
+                glx_sys::GLX_DRAWABLE_TYPE,
+                glx_sys::GLX_WINDOW_BIT | glx_sys::GLX_PBUFFER_BIT,
+                glx_sys::GLX_X_RENDERABLE,
+                1,
+                glx_sys::GLX_X_VISUAL_TYPE,
+                glx_sys::GLX_TRUE_COLOR,
+                0,
+            ]
+        );
+    }
+}
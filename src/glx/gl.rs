@@ -0,0 +1,350 @@
+use std::ffi::{c_char, c_void, CStr};
+use std::fmt::{Debug, Formatter};
+
+/// Type alias for the [`glGetString`] C function.
+type GLGetStringFn = unsafe extern "C" fn(u32) -> *const u8;
+
+/// Type alias for the [`glGetStringi`] C function.
+type GLGetStringiFn = unsafe extern "C" fn(u32, u32) -> *const u8;
+
+/// Type alias for the [`glGetIntegerv`] C function.
+type GLGetIntegervFn = unsafe extern "C" fn(u32, *mut i32);
+
+/// Type alias for the [`glEnable`] C function.
+type GLEnableFn = unsafe extern "C" fn(u32);
+
+/// Type alias for the [`glDebugMessageCallback`] C function.
+type GLDebugMessageCallbackFn = unsafe extern "C" fn(GLDebugProc, *const c_void);
+
+/// Type alias for the callback passed to [`glDebugMessageCallback`].
+type GLDebugProc = unsafe extern "C" fn(u32, u32, u32, u32, i32, *const c_char, *const c_void);
+
+const GL_VENDOR: u32 = 0x1F00;
+const GL_RENDERER: u32 = 0x1F01;
+const GL_VERSION: u32 = 0x1F02;
+const GL_EXTENSIONS: u32 = 0x1F03;
+const GL_SHADING_LANGUAGE_VERSION: u32 = 0x8B8C;
+const GL_NUM_EXTENSIONS: u32 = 0x821D;
+const GL_DEBUG_OUTPUT: u32 = 0x92E0;
+const GL_DEBUG_OUTPUT_SYNCHRONOUS: u32 = 0x8242;
+
+/// The name of the OpenGL extension providing debug output.
+pub(crate) const KHR_DEBUG_EXTENSION: &str = "GL_KHR_debug";
+
+/// Identification strings of the OpenGL implementation.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GLInfo {
+    /// The company responsible for the implementation
+    pub vendor: String,
+
+    /// The name of the renderer, usually the GPU and driver
+    pub renderer: String,
+
+    /// The OpenGL version string
+    pub version: String,
+
+    /// The GLSL version string, [`None`] if shaders are not supported
+    pub shading_language_version: Option<String>,
+}
+
+/// The source of an OpenGL debug message.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum GLDebugSource {
+    /// Calls to the OpenGL API
+    Api,
+
+    /// Calls to the window system API, for example GLX
+    WindowSystem,
+
+    /// The shader compiler
+    ShaderCompiler,
+
+    /// A third party application associated with OpenGL, for example a debugger
+    ThirdParty,
+
+    /// The application itself, see `glDebugMessageInsert`
+    Application,
+
+    /// Any other source
+    Other,
+
+    /// A source not known to this library
+    Unknown(u32),
+}
+
+impl GLDebugSource {
+    /// Converts the native debug message source.
+    pub fn from_native(value: u32) -> Self {
+        match value {
+            0x8246 => Self::Api,
+            0x8247 => Self::WindowSystem,
+            0x8248 => Self::ShaderCompiler,
+            0x8249 => Self::ThirdParty,
+            0x824A => Self::Application,
+            0x824B => Self::Other,
+            v => Self::Unknown(v),
+        }
+    }
+}
+
+/// The type of an OpenGL debug message.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum GLDebugType {
+    /// An error, usually an OpenGL error
+    Error,
+
+    /// Use of deprecated functionality
+    DeprecatedBehavior,
+
+    /// Use of functionality with undefined behavior
+    UndefinedBehavior,
+
+    /// Use of functionality which is not portable
+    Portability,
+
+    /// Use of functionality which may perform badly
+    Performance,
+
+    /// A command stream annotation
+    Marker,
+
+    /// A debug group has been pushed
+    PushGroup,
+
+    /// A debug group has been popped
+    PopGroup,
+
+    /// Any other type
+    Other,
+
+    /// A type not known to this library
+    Unknown(u32),
+}
+
+impl GLDebugType {
+    /// Converts the native debug message type.
+    pub fn from_native(value: u32) -> Self {
+        match value {
+            0x824C => Self::Error,
+            0x824D => Self::DeprecatedBehavior,
+            0x824E => Self::UndefinedBehavior,
+            0x824F => Self::Portability,
+            0x8250 => Self::Performance,
+            0x8268 => Self::Marker,
+            0x8269 => Self::PushGroup,
+            0x826A => Self::PopGroup,
+            0x8251 => Self::Other,
+            v => Self::Unknown(v),
+        }
+    }
+}
+
+/// The severity of an OpenGL debug message.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum GLDebugSeverity {
+    /// Errors or undefined behavior
+    High,
+
+    /// Major performance warnings or use of deprecated functionality
+    Medium,
+
+    /// Redundant state changes or minor performance warnings
+    Low,
+
+    /// Anything which is not an error or performance issue
+    Notification,
+
+    /// A severity not known to this library
+    Unknown(u32),
+}
+
+impl GLDebugSeverity {
+    /// Converts the native debug message severity.
+    pub fn from_native(value: u32) -> Self {
+        match value {
+            0x9146 => Self::High,
+            0x9147 => Self::Medium,
+            0x9148 => Self::Low,
+            0x826B => Self::Notification,
+            v => Self::Unknown(v),
+        }
+    }
+}
+
+/// A message generated by the OpenGL debug output.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct GLDebugMessage {
+    /// The component which generated the message
+    pub source: GLDebugSource,
+
+    /// The type of the message
+    pub kind: GLDebugType,
+
+    /// The implementation specific id of the message
+    pub id: u32,
+
+    /// The severity of the message
+    pub severity: GLDebugSeverity,
+
+    /// The message text
+    pub message: String,
+}
+
+/// Rust callback receiving OpenGL debug messages.
+pub(crate) struct GLDebugCallback(Box<dyn Fn(&GLDebugMessage)>);
+
+impl Debug for GLDebugCallback {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("GLDebugCallback").finish_non_exhaustive()
+    }
+}
+
+/// Trampoline invoked by OpenGL which forwards messages to the [`GLDebugCallback`].
+unsafe extern "C" fn debug_trampoline(
+    source: u32,
+    kind: u32,
+    id: u32,
+    severity: u32,
+    length: i32,
+    message: *const c_char,
+    user_param: *const c_void,
+) {
+    let callback = &*(user_param as *const GLDebugCallback);
+
+    let message = if length < 0 {
+        CStr::from_ptr(message).to_string_lossy().into_owned()
+    } else {
+        String::from_utf8_lossy(std::slice::from_raw_parts(
+            message as *const u8,
+            length as _,
+        ))
+        .into_owned()
+    };
+
+    let message = GLDebugMessage {
+        source: GLDebugSource::from_native(source),
+        kind: GLDebugType::from_native(kind),
+        id,
+        severity: GLDebugSeverity::from_native(severity),
+        message,
+    };
+
+    // Unwinding into the OpenGL implementation is undefined behavior
+    let _ = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| (callback.0)(&message)));
+}
+
+/// Retrieves an OpenGL string, the context has to be current.
+unsafe fn get_string(get_string: GLGetStringFn, name: u32) -> Option<String> {
+    let value = get_string(name);
+
+    if value.is_null() {
+        None
+    } else {
+        Some(
+            CStr::from_ptr(value as *const c_char)
+                .to_string_lossy()
+                .into_owned(),
+        )
+    }
+}
+
+/// Queries the identification strings of the current OpenGL context.
+///
+/// # Safety
+///
+/// A context has to be current on the calling thread.
+pub(crate) unsafe fn query_info() -> Option<GLInfo> {
+    let get_string_fn = std::mem::transmute::<unsafe extern "C" fn(), GLGetStringFn>(
+        super::get_proc_address("glGetString")?,
+    );
+
+    Some(GLInfo {
+        vendor: get_string(get_string_fn, GL_VENDOR)?,
+        renderer: get_string(get_string_fn, GL_RENDERER)?,
+        version: get_string(get_string_fn, GL_VERSION)?,
+        shading_language_version: get_string(get_string_fn, GL_SHADING_LANGUAGE_VERSION),
+    })
+}
+
+/// Queries the OpenGL extensions supported by the current context.
+///
+/// Core profile contexts only support querying extensions one by one, legacy contexts only
+/// support querying them as a single string, both are attempted.
+///
+/// # Safety
+///
+/// A context has to be current on the calling thread.
+pub(crate) unsafe fn query_extensions() -> Vec<String> {
+    type RawFn = unsafe extern "C" fn();
+
+    let get_integer = super::get_proc_address("glGetIntegerv")
+        .map(|f| std::mem::transmute::<RawFn, GLGetIntegervFn>(f));
+    let get_string_i = super::get_proc_address("glGetStringi")
+        .map(|f| std::mem::transmute::<RawFn, GLGetStringiFn>(f));
+
+    if let (Some(get_integer), Some(get_string_i)) = (get_integer, get_string_i) {
+        let mut count = 0;
+        get_integer(GL_NUM_EXTENSIONS, &mut count);
+
+        let extensions: Vec<String> = (0..count.max(0) as u32)
+            .filter_map(|i| {
+                let value = get_string_i(GL_EXTENSIONS, i);
+
+                (!value.is_null()).then(|| {
+                    CStr::from_ptr(value as *const c_char)
+                        .to_string_lossy()
+                        .into_owned()
+                })
+            })
+            .collect();
+
+        if !extensions.is_empty() {
+            return extensions;
+        }
+    }
+
+    super::get_proc_address("glGetString")
+        .and_then(|f| {
+            get_string(
+                std::mem::transmute::<RawFn, GLGetStringFn>(f),
+                GL_EXTENSIONS,
+            )
+        })
+        .map(|all| all.split(' ').map(String::from).collect())
+        .unwrap_or_default()
+}
+
+/// Installs a debug callback on the current OpenGL context and enables debug output.
+///
+/// Returns the installed callback, which has to be kept alive for as long as the context
+/// exists or until another callback has been installed.
+///
+/// # Safety
+///
+/// A context supporting `GL_KHR_debug` has to be current on the calling thread.
+pub(crate) unsafe fn install_debug_callback(
+    callback: Box<dyn Fn(&GLDebugMessage)>,
+) -> Option<Box<GLDebugCallback>> {
+    type RawFn = unsafe extern "C" fn();
+
+    // OpenGL ES contexts only provide the suffixed function
+    let debug_message_callback = super::get_proc_address("glDebugMessageCallback")
+        .or_else(|| super::get_proc_address("glDebugMessageCallbackKHR"))?;
+    let debug_message_callback =
+        std::mem::transmute::<RawFn, GLDebugMessageCallbackFn>(debug_message_callback);
+
+    let enable = std::mem::transmute::<RawFn, GLEnableFn>(super::get_proc_address("glEnable")?);
+
+    let callback = Box::new(GLDebugCallback(callback));
+
+    debug_message_callback(
+        debug_trampoline,
+        callback.as_ref() as *const GLDebugCallback as *const c_void,
+    );
+
+    // Synchronous output makes sure the callback is invoked on the thread causing the message
+    enable(GL_DEBUG_OUTPUT);
+    enable(GL_DEBUG_OUTPUT_SYNCHRONOUS);
+
+    Some(callback)
+}
@@ -1,21 +1,31 @@
 //! This module provides all sort of functionality to interact with GLX.
 
 mod context;
+mod drawable;
 mod fb_config;
+mod gl;
+mod pbuffer;
 mod pixmap;
+mod swap;
+mod window;
 
 pub use context::*;
+pub use drawable::*;
+pub use gl::*;
+pub use pbuffer::*;
 pub use pixmap::*;
+use pixmap::{TextureFromPixmapFns, TEXTURE_FROM_PIXMAP_EXTENSION};
+pub use swap::*;
+pub use window::*;
 
 pub use fb_config::*;
 use std::ffi::{CStr, CString};
 
-use crate::glx_arb_sys;
-use crate::glx_sys;
 use crate::xlib_sys;
+use crate::{glx_ext_sys, glx_sys};
 
 use crate::{XDisplay, XScreen, XVisual};
-use crate::{XDrawable, XPixmap};
+use crate::{XDrawable, XPixmap, XWindow};
 use thiserror::Error;
 
 /// Type alias for the [`glXCreateContextAttribsARB`] C function.
@@ -30,6 +40,27 @@ type GLXCreateContextAttribsARBFn = unsafe extern "C" fn(
 /// The name of the GLX extension providing ARB context creation.
 const ARB_CREATE_CONTEXT_EXTENSION: &str = "GLX_ARB_create_context";
 
+/// Type alias for the [`glXQueryCurrentRendererIntegerMESA`] C function.
+type GLXQueryCurrentRendererIntegerMESAFn = unsafe extern "C" fn(i32, *mut u32) -> xlib_sys::Bool;
+
+/// Type alias for the [`glXQueryCurrentRendererStringMESA`] C function.
+type GLXQueryCurrentRendererStringMESAFn = unsafe extern "C" fn(i32) -> *const std::ffi::c_char;
+
+/// The name of the GLX extension providing renderer information.
+const QUERY_RENDERER_EXTENSION: &str = "GLX_MESA_query_renderer";
+
+/// Looks up an OpenGL or GLX function, see [`GLX::lookup_function`].
+pub(crate) fn get_proc_address(name: &str) -> Option<unsafe extern "C" fn()> {
+    let name = match CString::new(name) {
+        Ok(v) => v,
+        Err(_) => return None,
+    };
+
+    let name = name.as_bytes_with_nul().as_ptr();
+
+    unsafe { glx_sys::glXGetProcAddressARB(name).or_else(|| glx_sys::glXGetProcAddress(name)) }
+}
+
 /// Main interface for talking to GLX.
 ///
 /// This interface is only valid as long as the display is held open. However, the functions
@@ -117,14 +148,107 @@ impl<'a> GLX<'a> {
     /// ```
     ///
     pub fn lookup_function(&self, name: impl AsRef<str>) -> Option<unsafe extern "C" fn()> {
-        let name = match CString::new(name.as_ref()) {
-            Ok(v) => v,
-            Err(_) => return None,
+        get_proc_address(name.as_ref())
+    }
+
+    /// Retrieves the handle of the context current on the calling thread.
+    pub fn current_context_handle(&self) -> Option<glx_sys::GLXContext> {
+        let context = unsafe { glx_sys::glXGetCurrentContext() };
+
+        (!context.is_null()).then_some(context)
+    }
+
+    /// Retrieves the drawable the current context renders to on the calling thread.
+    pub fn current_drawable_handle(&self) -> Option<xlib_sys::Drawable> {
+        let drawable = unsafe { glx_sys::glXGetCurrentDrawable() };
+
+        (drawable != 0).then_some(drawable)
+    }
+
+    /// Retrieves the drawable the current context reads from on the calling thread.
+    pub fn current_read_drawable_handle(&self) -> Option<xlib_sys::Drawable> {
+        let drawable = unsafe { glx_sys::glXGetCurrentReadDrawable() };
+
+        (drawable != 0).then_some(drawable)
+    }
+
+    /// Determines whether the context current on the calling thread belongs to this display.
+    pub fn is_current_display(&self) -> bool {
+        unsafe { glx_sys::glXGetCurrentDisplay() == self.display.handle() }
+    }
+
+    /// Queries information about the renderer of the current context.
+    ///
+    /// Requires `GLX_MESA_query_renderer` and a context being current on the calling thread.
+    ///
+    /// # Arguments
+    ///
+    /// * `screen` - The screen the current context was created on
+    pub fn query_current_renderer(&self, screen: &XScreen) -> Result<GLXRendererInfo, GLXError> {
+        if !self
+            .query_extensions(screen)
+            .contains(&QUERY_RENDERER_EXTENSION)
+        {
+            return Err(GLXError::MissingExtension(QUERY_RENDERER_EXTENSION));
+        }
+
+        if self.current_context_handle().is_none() {
+            return Err(GLXError::ContextNotCurrent);
+        }
+
+        let missing = || GLXError::MissingExtension(QUERY_RENDERER_EXTENSION);
+
+        let query_integer = self
+            .lookup_function("glXQueryCurrentRendererIntegerMESA")
+            .ok_or_else(missing)?;
+        let query_integer = unsafe {
+            std::mem::transmute::<unsafe extern "C" fn(), GLXQueryCurrentRendererIntegerMESAFn>(
+                query_integer,
+            )
         };
 
-        let name = name.as_bytes_with_nul().as_ptr();
+        let query_string = self
+            .lookup_function("glXQueryCurrentRendererStringMESA")
+            .ok_or_else(missing)?;
+        let query_string = unsafe {
+            std::mem::transmute::<unsafe extern "C" fn(), GLXQueryCurrentRendererStringMESAFn>(
+                query_string,
+            )
+        };
 
-        unsafe { glx_sys::glXGetProcAddressARB(name).or_else(|| glx_sys::glXGetProcAddress(name)) }
+        let integer = |attribute| {
+            // The version attribute returns 3 values, all others only one
+            let mut values = [0u32; 3];
+
+            match unsafe { query_integer(attribute, values.as_mut_ptr()) } {
+                0 => Err(GLXError::BadAttribute(attribute)),
+                _ => Ok(values),
+            }
+        };
+
+        let string = |attribute| {
+            let value = unsafe { query_string(attribute) };
+
+            (!value.is_null()).then(|| {
+                unsafe { CStr::from_ptr(value) }
+                    .to_string_lossy()
+                    .into_owned()
+            })
+        };
+
+        let [major, minor, patch] = integer(glx_ext_sys::GLX_RENDERER_VERSION_MESA)?;
+
+        Ok(GLXRendererInfo {
+            vendor_id: integer(glx_ext_sys::GLX_RENDERER_VENDOR_ID_MESA)?[0],
+            device_id: integer(glx_ext_sys::GLX_RENDERER_DEVICE_ID_MESA)?[0],
+            vendor: string(glx_ext_sys::GLX_RENDERER_VENDOR_ID_MESA),
+            device: string(glx_ext_sys::GLX_RENDERER_DEVICE_ID_MESA),
+            version: (major, minor, patch),
+            accelerated: integer(glx_ext_sys::GLX_RENDERER_ACCELERATED_MESA)?[0] != 0,
+            video_memory: integer(glx_ext_sys::GLX_RENDERER_VIDEO_MEMORY_MESA)?[0],
+            unified_memory: integer(glx_ext_sys::GLX_RENDERER_UNIFIED_MEMORY_ARCHITECTURE_MESA)?[0]
+                != 0,
+        })
     }
 
     /// Attempts to find a framebuffer configuration matching the specified visual.
@@ -143,7 +267,7 @@ impl<'a> GLX<'a> {
         screen: &XScreen,
         visual: &XVisual,
     ) -> Result<GLXFBConfig, GLXError> {
-        let configs = self.retrieve_framebuffer_configs(screen)?;
+        let configs = self.retrieve_framebuffer_configs(screen, &[0])?;
 
         let mut chosen_config = None;
 
@@ -159,34 +283,205 @@ impl<'a> GLX<'a> {
         chosen_config.ok_or(GLXError::NoFramebufferConfigFound)
     }
 
+    /// Chooses all framebuffer configurations matching the given criteria.
+    ///
+    /// The returned configurations are ranked with the best match first. Configurations
+    /// without caveats are preferred, followed by those closest to the requested sample count
+    /// and those with the least amount of unrequested color, depth and stencil bits.
+    /// Configurations whose attributes can not be queried are skipped.
+    ///
+    /// # Arguments
+    ///
+    /// * `screen` - The screen to choose the configurations for
+    /// * `criteria` - The criteria the configurations have to match
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wrap11::{FBConfigCriteria, XDisplay, GLX};
+    ///
+    /// let display = XDisplay::open(None).expect("Failed to open display");
+    /// let screen = display.default_screen();
+    /// let glx = GLX::create(&display).unwrap();
+    ///
+    /// let mut criteria = FBConfigCriteria::new();
+    /// criteria
+    ///     .rgba_sizes(8, 8, 8, 8)
+    ///     .sample_buffers(1)
+    ///     .samples(4)
+    ///     .argb_visual(true);
+    ///
+    /// let configs = glx
+    ///     .choose_framebuffer_configs(&screen, criteria)
+    ///     .expect("No transparent multisampled configuration available");
+    /// let best = &configs[0];
+    /// ```
+    pub fn choose_framebuffer_configs(
+        &self,
+        screen: &XScreen,
+        criteria: FBConfigCriteria,
+    ) -> Result<Vec<GLXFBConfig<'a>>, GLXError> {
+        let configs = self.retrieve_framebuffer_configs(screen, &criteria.into_native())?;
+
+        let mut ranked = Vec::with_capacity(configs.count());
+
+        for config in configs.iter() {
+            if criteria.requires_argb_visual() && !config.has_argb_visual() {
+                continue;
+            }
+
+            let Ok(info) = config.info() else {
+                continue;
+            };
+
+            let rank = criteria.rank(&info);
+            ranked.push((rank, config.extend_lifetime(self.display)));
+        }
+
+        if ranked.is_empty() {
+            return Err(GLXError::NoFramebufferConfigFound);
+        }
+
+        // Stable sort, configurations ranked equally keep the order GLX returned them in
+        ranked.sort_by_key(|(rank, _)| *rank);
+
+        Ok(ranked.into_iter().map(|(_, config)| config).collect())
+    }
+
     /// Converts an existing X11 pixmap into a GLX pixmap.
     ///
     /// This can be used to render to X11 pixmap's using OpenGL or to use an X11 pixmap as a texture
     /// in OpenGL.
     ///
-    /// It is always attempted to create an OpenGL 3.0 or newer context, however, this might fail
-    /// and an older context might be returned.
-    ///
     /// # Arguments
     ///
-    /// * `config` - The framebuffer configuration to use for the pixmap
+    /// * `config` - The framebuffer configuration to use for the pixmap, it has to support
+    ///   [`GLXDrawableTypes::PIXMAP`]
     /// * `x_pixmap` - The X11 pixmap to wrap
+    pub fn convert_pixmap(
+        &self,
+        config: &GLXFBConfig,
+        x_pixmap: XPixmap<'a>,
+    ) -> Result<GLXPixmap<'a>, GLXError> {
+        let pixmap = self.create_drawable(glx_sys::glXDestroyPixmap, || unsafe {
+            glx_sys::glXCreatePixmap(
+                self.display.handle(),
+                config.handle(),
+                x_pixmap.drawable_handle(),
+                [0].as_ptr(),
+            )
+        })?;
+
+        Ok(unsafe { GLXPixmap::new(pixmap, x_pixmap, self.display) })
+    }
+
+    /// Converts an existing X11 pixmap into a GLX pixmap which can be bound to a texture.
     ///
-    /// # Warning
+    /// This is commonly used by compositing managers to render redirected windows without
+    /// copying their contents, see [`GLXPixmap::bind_tex_image`].
     ///
-    /// Testing has revealed that this works mediocre at best, your millage may vary! On modern X11
-    /// connections its a hit or miss whether this works reliably.
+    /// # Arguments
     ///
-    pub fn convert_pixmap(&self, config: &GLXFBConfig, x_pixmap: XPixmap<'a>) -> GLXPixmap<'a> {
-        let pixmap = unsafe {
-            glx_sys::glXCreateGLXPixmap(
+    /// * `screen` - The screen the pixmap resides on
+    /// * `config` - The framebuffer configuration to use for the pixmap, it has to support
+    ///   binding to the requested texture format and target
+    /// * `x_pixmap` - The X11 pixmap to wrap
+    /// * `attributes` - The attributes of the texture the pixmap will be bound to
+    pub fn convert_pixmap_for_texture(
+        &self,
+        screen: &XScreen,
+        config: &GLXFBConfig,
+        x_pixmap: XPixmap<'a>,
+        attributes: PixmapTextureAttributes,
+    ) -> Result<GLXPixmap<'a>, GLXError> {
+        if !self
+            .query_extensions(screen)
+            .contains(&TEXTURE_FROM_PIXMAP_EXTENSION)
+        {
+            return Err(GLXError::MissingExtension(TEXTURE_FROM_PIXMAP_EXTENSION));
+        }
+
+        let functions = unsafe { TextureFromPixmapFns::load(|name| self.lookup_function(name))? };
+        let native = attributes.into_native();
+
+        let pixmap = self.create_drawable(glx_sys::glXDestroyPixmap, || unsafe {
+            glx_sys::glXCreatePixmap(
                 self.display.handle(),
-                &mut config.get_visual().unwrap().handle(),
+                config.handle(),
                 x_pixmap.drawable_handle(),
+                native.as_ptr(),
             )
-        };
+        })?;
+
+        Ok(unsafe { GLXPixmap::new(pixmap, x_pixmap, self.display) }
+            .with_texture_functions(functions))
+    }
+
+    /// Creates a GLX window on top of an existing X11 window.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The framebuffer configuration to use for the window, it has to support
+    ///   [`GLXDrawableTypes::WINDOW`] and match the visual of the window
+    /// * `window` - The X11 window to render to
+    pub fn create_window<'w>(
+        &self,
+        config: &GLXFBConfig,
+        window: &'w XWindow<'a>,
+    ) -> Result<GLXWindow<'w, 'a>, GLXError> {
+        let glx_window = self.create_drawable(glx_sys::glXDestroyWindow, || unsafe {
+            glx_sys::glXCreateWindow(
+                self.display.handle(),
+                config.handle(),
+                window.handle(),
+                [0].as_ptr(),
+            )
+        })?;
 
-        unsafe { GLXPixmap::new(pixmap, x_pixmap, self.display) }
+        Ok(unsafe { GLXWindow::new(glx_window, window, self.display) })
+    }
+
+    /// Creates an offscreen GLX pbuffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The framebuffer configuration to use for the pbuffer, it has to support
+    ///   [`GLXDrawableTypes::PBUFFER`]
+    /// * `attributes` - The attributes of the pbuffer
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wrap11::{FBConfigCriteria, GLXDrawableTypes, PbufferAttributes, XDisplay, GLX};
+    ///
+    /// let display = XDisplay::open(None).expect("Failed to open display");
+    /// let screen = display.default_screen();
+    /// let glx = GLX::create(&display).unwrap();
+    ///
+    /// let mut criteria = FBConfigCriteria::new();
+    /// criteria.drawable_types(GLXDrawableTypes::PBUFFER);
+    ///
+    /// let configs = glx.choose_framebuffer_configs(&screen, criteria).unwrap();
+    /// let config = &configs[0];
+    /// let context = glx.create_context(&screen, config).unwrap();
+    ///
+    /// let pbuffer = glx
+    ///     .create_pbuffer(&config, PbufferAttributes::new(256, 256))
+    ///     .expect("Failed to create pbuffer");
+    /// context.make_current(&pbuffer);
+    /// ```
+    pub fn create_pbuffer(
+        &self,
+        config: &GLXFBConfig,
+        attributes: PbufferAttributes,
+    ) -> Result<GLXPbuffer<'a>, GLXError> {
+        let native = attributes.into_native();
+
+        let pbuffer = self.create_drawable(glx_sys::glXDestroyPbuffer, || unsafe {
+            glx_sys::glXCreatePbuffer(self.display.handle(), config.handle(), native.as_ptr())
+        })?;
+
+        Ok(unsafe { GLXPbuffer::new(pbuffer, self.display) })
     }
 
     /// Creates an GLX OpenGL context.
@@ -198,26 +493,19 @@ impl<'a> GLX<'a> {
     ///
     /// # Examples
     ///
-    /// ```
-    /// # use desktop_control_x11_wrapper::GLXError;
-    /// #
-    /// # struct GLX {}
-    /// # impl GLX {
-    /// #     pub fn create_context(&self, screen: &(), config: &()) -> Result<GLXContext, GLXError> {
-    /// #         Ok(GLXContext {})
-    /// #     }
-    /// # }
-    /// #
-    /// # struct GLXContext;
-    /// # impl GLXContext {
-    /// #     pub fn make_current(&self, drawable: &()) {}
-    /// # }
-    /// #
-    /// # let screen = ();
-    /// # let config = ();
-    /// # let glx = GLX {};
-    /// # let window = ();
-    /// #
+    /// ```no_run
+    /// use wrap11::{FBConfigCriteria, XDisplay, GLX};
+    ///
+    /// let display = XDisplay::open(None).expect("Failed to open display");
+    /// let screen = display.default_screen();
+    /// let glx = GLX::create(&display).unwrap();
+    ///
+    /// let configs = glx
+    ///     .choose_framebuffer_configs(&screen, FBConfigCriteria::new())
+    ///     .unwrap();
+    /// let config = &configs[0];
+    /// let window = screen.create_simple_window(0, 0, 640, 480, 0, 0, 0);
+    ///
     /// let context = glx.create_context(&screen, &config).expect("Failed to create GLX context");
     /// context.make_current(&window);
     ///
@@ -229,56 +517,416 @@ impl<'a> GLX<'a> {
         screen: &XScreen,
         config: &GLXFBConfig,
     ) -> Result<GLXContext<'a>, GLXError> {
-        let extensions = self.query_extensions(screen);
+        self.create_context_with_attributes(screen, config, ContextAttributes::new(), None)
+    }
 
-        let glx_create_context_attribs_arb = unsafe {
-            glx_sys::glXGetProcAddressARB(
-                CString::new("glXCreateContextAttribsARB")
-                    .unwrap()
-                    .to_bytes()
-                    .as_ptr(),
-            )
-        };
+    /// Creates an GLX OpenGL context with specific attributes.
+    ///
+    /// If no attributes are set and `GLX_ARB_create_context` is not available or fails, a
+    /// legacy context is created instead. Otherwise the attributes are passed to
+    /// `glXCreateContextAttribsARB` and failures are reported as errors.
+    ///
+    /// # Arguments
+    ///
+    /// * `screen` - The screen to create the context on
+    /// * `config` - The framebuffer configuration to use for rendering
+    /// * `attributes` - The attributes of the context
+    /// * `share` - An existing context to share objects with
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wrap11::{ContextAttributes, FBConfigCriteria, GLXContextProfile, XDisplay, GLX};
+    ///
+    /// let display = XDisplay::open(None).expect("Failed to open display");
+    /// let screen = display.default_screen();
+    /// let glx = GLX::create(&display).unwrap();
+    ///
+    /// let configs = glx
+    ///     .choose_framebuffer_configs(&screen, FBConfigCriteria::new())
+    ///     .unwrap();
+    /// let config = &configs[0];
+    ///
+    /// let mut attributes = ContextAttributes::new();
+    /// attributes.version(3, 3).profile(GLXContextProfile::Core);
+    ///
+    /// let context = glx
+    ///     .create_context_with_attributes(&screen, &config, attributes, None)
+    ///     .expect("OpenGL 3.3 core is not available");
+    /// ```
+    pub fn create_context_with_attributes(
+        &self,
+        screen: &XScreen,
+        config: &GLXFBConfig,
+        attributes: ContextAttributes,
+        share: Option<&GLXContext>,
+    ) -> Result<GLXContext<'a>, GLXError> {
+        let share = share.map_or(std::ptr::null_mut(), |share| share.handle());
 
-        let arb_context_supported = extensions.contains(&ARB_CREATE_CONTEXT_EXTENSION);
+        let extensions = self.query_extensions(screen);
+        let create_context_attribs = self.lookup_function("glXCreateContextAttribsARB");
 
-        let glx_context = match (glx_create_context_attribs_arb, arb_context_supported) {
-            (Some(glx_create_context_attribs_arb), true) if false => {
-                #[rustfmt::skip]
-                    let context_attribs = &[
-                    glx_arb_sys::GLX_CONTEXT_MAJOR_VERSION_ARB, 3,
-                    glx_arb_sys::GLX_CONTEXT_MINOR_VERSION_ARB, 3,
-                    0
-                ];
+        let missing_extension = match create_context_attribs {
+            Some(_) => attributes.missing_extension(&extensions),
+            None => Some(ARB_CREATE_CONTEXT_EXTENSION),
+        };
 
-                let glx_create_context_attribs_arb = unsafe {
-                    std::mem::transmute::<_, GLXCreateContextAttribsARBFn>(
-                        glx_create_context_attribs_arb,
+        let glx_context = match (create_context_attribs, missing_extension) {
+            (Some(create_context_attribs), None) => {
+                let create_context_attribs = unsafe {
+                    std::mem::transmute::<unsafe extern "C" fn(), GLXCreateContextAttribsARBFn>(
+                        create_context_attribs,
                     )
                 };
 
-                unsafe {
-                    glx_create_context_attribs_arb(
+                let native = attributes.into_native();
+
+                // Failing to create the context raises an X error which would otherwise abort
+                let (glx_context, error) = self.display.trap_errors(|| unsafe {
+                    create_context_attribs(
                         self.display.handle(),
                         config.handle(),
-                        std::ptr::null_mut(),
+                        share,
                         1,
-                        context_attribs.as_ptr(),
+                        native.as_ptr(),
                     )
+                });
+
+                match (glx_context.is_null(), error) {
+                    (false, None) => glx_context,
+                    (false, Some(_)) => {
+                        unsafe { glx_sys::glXDestroyContext(self.display.handle(), glx_context) };
+                        std::ptr::null_mut()
+                    }
+                    (true, _) => std::ptr::null_mut(),
                 }
             }
-            (_, _) => unsafe {
-                glx_sys::glXCreateNewContext(
+            (_, Some(extension)) if !attributes.is_default() => {
+                return Err(GLXError::MissingExtension(extension));
+            }
+            (_, _) => std::ptr::null_mut(),
+        };
+
+        if !glx_context.is_null() {
+            return Ok(unsafe { GLXContext::new(glx_context, self.display) });
+        }
+
+        if let Some((major, minor)) = attributes.requested_version() {
+            return Err(GLXError::VersionUnavailable(major, minor));
+        } else if !attributes.is_default() {
+            return Err(GLXError::ContextCreationFailed);
+        }
+
+        let (glx_context, error) = self.display.trap_errors(|| unsafe {
+            glx_sys::glXCreateNewContext(
+                self.display.handle(),
+                config.handle(),
+                glx_sys::GLX_RGBA_TYPE,
+                share,
+                1,
+            )
+        });
+
+        match (glx_context.is_null(), error) {
+            (false, None) => Ok(unsafe { GLXContext::new(glx_context, self.display) }),
+            (false, Some(_)) => {
+                unsafe { glx_sys::glXDestroyContext(self.display.handle(), glx_context) };
+                Err(GLXError::ContextCreationFailed)
+            }
+            (true, _) => Err(GLXError::ContextCreationFailed),
+        }
+    }
+
+    /// Creates an GLX OpenGL context using the first set of attributes which succeeds.
+    ///
+    /// This can be used to request the newest version available from a list of candidates,
+    /// for example 4.6 core, 3.3 core and finally a legacy context.
+    ///
+    /// # Arguments
+    ///
+    /// * `screen` - The screen to create the context on
+    /// * `config` - The framebuffer configuration to use for rendering
+    /// * `candidates` - The attributes to try in order
+    /// * `share` - An existing context to share objects with
+    ///
+    /// # Panics
+    ///
+    /// If `candidates` is empty.
+    pub fn create_context_with_fallbacks(
+        &self,
+        screen: &XScreen,
+        config: &GLXFBConfig,
+        candidates: &[ContextAttributes],
+        share: Option<&GLXContext>,
+    ) -> Result<GLXContext<'a>, GLXError> {
+        assert!(!candidates.is_empty(), "at least one candidate is required");
+
+        let mut last_error = None;
+
+        for attributes in candidates {
+            match self.create_context_with_attributes(screen, config, *attributes, share) {
+                Ok(context) => return Ok(context),
+                Err(err) => last_error = Some(err),
+            }
+        }
+
+        Err(last_error.unwrap())
+    }
+
+    /// Sets the swap interval used when swapping the buffers of a drawable.
+    ///
+    /// The interval is the minimum amount of vertical retraces between two buffer swaps, 0
+    /// disables vsync and -1 enables adaptive vsync, which tears if a frame is late. The best
+    /// available extension is chosen and returned. `GLX_MESA_swap_control` and
+    /// `GLX_SGI_swap_control` apply the interval to the current context instead of the drawable,
+    /// so the context has to be made current on the drawable beforehand.
+    ///
+    /// # Arguments
+    ///
+    /// * `screen` - The screen the drawable resides on
+    /// * `drawable` - The drawable to set the interval for
+    /// * `interval` - The swap interval to set
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use wrap11::{XDisplay, GLX};
+    ///
+    /// let display = XDisplay::open(None).expect("Failed to open display");
+    /// let screen = display.default_screen();
+    /// let glx = GLX::create(&display).unwrap();
+    /// let window = screen.create_simple_window(0, 0, 640, 480, 0, 0, 0);
+    ///
+    /// // Prefer adaptive vsync and fall back to regular vsync
+    /// let control = glx
+    ///     .set_swap_interval(&screen, &window, -1)
+    ///     .or_else(|_| glx.set_swap_interval(&screen, &window, 1))
+    ///     .expect("Swap control is not supported");
+    ///
+    /// println!("Swap interval set using {}", control.extension_name());
+    /// ```
+    pub fn set_swap_interval<'b, D>(
+        &self,
+        screen: &XScreen,
+        drawable: &D,
+        interval: i32,
+    ) -> Result<GLXSwapControl, GLXError>
+    where
+        D: GLXDrawable<'b>,
+    {
+        let control = GLXSwapControl::select(&self.query_extensions(screen), interval)?;
+
+        let missing = || GLXError::MissingExtension(control.extension_name());
+
+        let result = match control {
+            GLXSwapControl::Ext => unsafe {
+                let swap_interval = self
+                    .lookup_function("glXSwapIntervalEXT")
+                    .ok_or_else(missing)?;
+                let swap_interval = std::mem::transmute::<
+                    unsafe extern "C" fn(),
+                    GLXSwapIntervalEXTFn,
+                >(swap_interval);
+
+                swap_interval(
                     self.display.handle(),
-                    config.handle(),
-                    glx_sys::GLX_RGBA_TYPE,
-                    std::ptr::null_mut(),
-                    1,
-                )
+                    drawable.glx_drawable_handle(),
+                    interval,
+                );
+                0
+            },
+            GLXSwapControl::Mesa | GLXSwapControl::Sgi => unsafe {
+                let name = match control {
+                    GLXSwapControl::Mesa => "glXSwapIntervalMESA",
+                    _ => "glXSwapIntervalSGI",
+                };
+
+                let swap_interval = self.lookup_function(name).ok_or_else(missing)?;
+                let swap_interval =
+                    std::mem::transmute::<unsafe extern "C" fn(), GLXSwapIntervalFn>(swap_interval);
+
+                swap_interval(interval)
             },
         };
 
-        Ok(unsafe { GLXContext::new(glx_context, self.display) })
+        match result {
+            0 => Ok(control),
+            v => Err(GLXError::GenericError(v)),
+        }
+    }
+
+    /// Retrieves the swap interval currently used for a drawable.
+    ///
+    /// Returns [`None`] if the interval can't be queried, which is the case when only
+    /// `GLX_SGI_swap_control` is supported or the drawable is not a valid GLX drawable.
+    /// Adaptive vsync is reported as a negative interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `screen` - The screen the drawable resides on
+    /// * `drawable` - The drawable to query the interval of
+    pub fn get_swap_interval<'b, D>(&self, screen: &XScreen, drawable: &D) -> Option<i32>
+    where
+        D: GLXDrawable<'b>,
+    {
+        let extensions = self.query_extensions(screen);
+
+        if extensions.contains(&GLXSwapControl::Ext.extension_name()) {
+            let interval =
+                self.query_drawable(drawable, glx_sys::ext::GLX_SWAP_INTERVAL_EXT)? as i32;
+
+            let adaptive = extensions.contains(&"GLX_EXT_swap_control_tear")
+                && self.query_drawable(drawable, glx_ext_sys::GLX_LATE_SWAPS_TEAR_EXT)? != 0;
+
+            Some(if adaptive { -interval } else { interval })
+        } else if extensions.contains(&GLXSwapControl::Mesa.extension_name()) {
+            let get_swap_interval = self.lookup_function("glXGetSwapIntervalMESA")?;
+            let get_swap_interval = unsafe {
+                std::mem::transmute::<unsafe extern "C" fn(), GLXGetSwapIntervalMESAFn>(
+                    get_swap_interval,
+                )
+            };
+
+            Some(unsafe { get_swap_interval() })
+        } else {
+            None
+        }
+    }
+
+    /// Retrieves the maximum swap interval supported for a drawable.
+    ///
+    /// Returns [`None`] if `GLX_EXT_swap_control` is not supported or the drawable is not a valid
+    /// GLX drawable.
+    ///
+    /// # Arguments
+    ///
+    /// * `screen` - The screen the drawable resides on
+    /// * `drawable` - The drawable to query the maximum interval of
+    pub fn max_swap_interval<'b, D>(&self, screen: &XScreen, drawable: &D) -> Option<u32>
+    where
+        D: GLXDrawable<'b>,
+    {
+        if self
+            .query_extensions(screen)
+            .contains(&GLXSwapControl::Ext.extension_name())
+        {
+            self.query_drawable(drawable, glx_sys::ext::GLX_MAX_SWAP_INTERVAL_EXT)
+        } else {
+            None
+        }
+    }
+
+    /// Loads the `GLX_OML_sync_control` frame pacing interface.
+    ///
+    /// # Arguments
+    ///
+    /// * `screen` - The screen to load the interface for
+    pub fn load_sync_control(&self, screen: &XScreen) -> Result<GLXSyncControl<'a>, GLXError> {
+        if !self
+            .query_extensions(screen)
+            .contains(&GLXSyncControl::EXTENSION_NAME)
+        {
+            return Err(GLXError::MissingExtension(GLXSyncControl::EXTENSION_NAME));
+        }
+
+        unsafe { GLXSyncControl::load(|name| self.lookup_function(name), self.display) }
+    }
+
+    /// Loads the `GLX_NV_swap_group` swap group interface.
+    ///
+    /// # Arguments
+    ///
+    /// * `screen` - The screen to load the interface for
+    pub fn load_swap_group(&self, screen: &XScreen) -> Result<GLXSwapGroup<'a>, GLXError> {
+        if !self
+            .query_extensions(screen)
+            .contains(&GLXSwapGroup::EXTENSION_NAME)
+        {
+            return Err(GLXError::MissingExtension(GLXSwapGroup::EXTENSION_NAME));
+        }
+
+        unsafe {
+            GLXSwapGroup::load(
+                |name| self.lookup_function(name),
+                screen.number(),
+                self.display,
+            )
+        }
+    }
+
+    /// Adds a drawable to the `GLX_SGIX_swap_group` swap group of another drawable.
+    ///
+    /// Drawables in the same swap group swap their buffers at the same time. The drawable is
+    /// removed from the group it was in before.
+    ///
+    /// # Arguments
+    ///
+    /// * `screen` - The screen the drawables reside on
+    /// * `drawable` - The drawable to add to the group
+    /// * `member` - A drawable of the group to join
+    pub fn join_swap_group_sgix<'b, 'c, D, M>(
+        &self,
+        screen: &XScreen,
+        drawable: &D,
+        member: &M,
+    ) -> Result<(), GLXError>
+    where
+        D: GLXDrawable<'b>,
+        M: GLXDrawable<'c>,
+    {
+        self.join_swap_group_sgix_raw(screen, drawable, member.glx_drawable_handle())
+    }
+
+    /// Removes a drawable from its `GLX_SGIX_swap_group` swap group.
+    ///
+    /// # Arguments
+    ///
+    /// * `screen` - The screen the drawable resides on
+    /// * `drawable` - The drawable to remove from its group
+    pub fn leave_swap_group_sgix<'b, D>(
+        &self,
+        screen: &XScreen,
+        drawable: &D,
+    ) -> Result<(), GLXError>
+    where
+        D: GLXDrawable<'b>,
+    {
+        self.join_swap_group_sgix_raw(screen, drawable, 0)
+    }
+
+    fn join_swap_group_sgix_raw<'b, D>(
+        &self,
+        screen: &XScreen,
+        drawable: &D,
+        member: xlib_sys::Drawable,
+    ) -> Result<(), GLXError>
+    where
+        D: GLXDrawable<'b>,
+    {
+        const EXTENSION_NAME: &str = "GLX_SGIX_swap_group";
+
+        if !self.query_extensions(screen).contains(&EXTENSION_NAME) {
+            return Err(GLXError::MissingExtension(EXTENSION_NAME));
+        }
+
+        let join_swap_group = self
+            .lookup_function("glXJoinSwapGroupSGIX")
+            .ok_or(GLXError::MissingExtension(EXTENSION_NAME))?;
+
+        unsafe {
+            let join_swap_group = std::mem::transmute::<
+                unsafe extern "C" fn(),
+                GLXJoinSwapGroupSGIXFn,
+            >(join_swap_group);
+
+            join_swap_group(
+                self.display.handle(),
+                drawable.glx_drawable_handle(),
+                member,
+            );
+        }
+
+        Ok(())
     }
 
     /// Queries all available GLX extensions.
@@ -314,9 +962,53 @@ impl<'a> GLX<'a> {
             .collect()
     }
 
+    /// Queries an attribute of a GLX drawable.
+    ///
+    /// Returns [`None`] if the drawable is not a valid GLX drawable, for example a window which
+    /// has never been made current.
+    fn query_drawable<'b, D>(&self, drawable: &D, attribute: i32) -> Option<u32>
+    where
+        D: GLXDrawable<'b>,
+    {
+        let mut value = 0;
+
+        let (_, error) = self.display.trap_errors(|| unsafe {
+            glx_sys::glXQueryDrawable(
+                self.display.handle(),
+                drawable.glx_drawable_handle(),
+                attribute,
+                &mut value,
+            )
+        });
+
+        error.is_none().then_some(value)
+    }
+
+    /// Creates a GLX drawable while capturing X errors.
+    ///
+    /// If creation raises an error the client side state of the drawable is released again
+    /// using `destroy`. The X server never created the drawable in that case, so the error
+    /// raised by destroying it is captured as well.
+    fn create_drawable(
+        &self,
+        destroy: unsafe extern "C" fn(*mut xlib_sys::Display, xlib_sys::XID),
+        create: impl FnOnce() -> xlib_sys::XID,
+    ) -> Result<xlib_sys::XID, GLXError> {
+        match self.display.trap_errors(create) {
+            (0, _) => Err(GLXError::DrawableCreationFailed),
+            (drawable, None) => Ok(drawable),
+            (drawable, Some(_)) => {
+                self.display
+                    .trap_errors(|| unsafe { destroy(self.display.handle(), drawable) });
+                Err(GLXError::DrawableCreationFailed)
+            }
+        }
+    }
+
     fn retrieve_framebuffer_configs(
         &self,
         screen: &XScreen,
+        attributes: &[i32],
     ) -> Result<GLXFBConfigArray<'a>, GLXError> {
         let mut config_count = 0;
 
@@ -324,7 +1016,7 @@ impl<'a> GLX<'a> {
             glx_sys::glXChooseFBConfig(
                 self.display.handle(),
                 screen.number(),
-                [0].as_ptr(),
+                attributes.as_ptr(),
                 &mut config_count,
             )
         };
@@ -352,6 +1044,26 @@ pub enum GLXError {
     #[error("no framebuffer configuration could be found for the requested attributes")]
     NoFramebufferConfigFound,
 
+    /// A GLX extension required for the requested operation is not available.
+    #[error("the GLX extension {0} is not available")]
+    MissingExtension(&'static str),
+
+    /// The implementation could not create a context of the requested version.
+    #[error("an OpenGL {0}.{1} context with the requested attributes is not available")]
+    VersionUnavailable(i32, i32),
+
+    /// The operation requires the context to be current on the calling thread.
+    #[error("the GLX context is not current on the calling thread")]
+    ContextNotCurrent,
+
+    /// The implementation failed to create a GLX drawable.
+    #[error("failed to create the GLX drawable")]
+    DrawableCreationFailed,
+
+    /// The implementation failed to create a context.
+    #[error("failed to create the GLX context")]
+    ContextCreationFailed,
+
     /// An attempt was made to request an invalid attribute from a GLX framebuffer configuration.
     #[error("0x{0:X} is not a valid GLX FBConfig attribute")]
     BadAttribute(i32),
@@ -0,0 +1,127 @@
+use crate::glx_sys;
+use crate::XDisplay;
+
+/// Attributes used to create a GLX pbuffer.
+#[derive(Debug, Clone, Copy)]
+pub struct PbufferAttributes {
+    width: u32,
+    height: u32,
+    largest: bool,
+    preserved_contents: bool,
+}
+
+impl PbufferAttributes {
+    /// Creates a new set of pbuffer attributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the pbuffer
+    /// * `height` - The height of the pbuffer
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            largest: false,
+            preserved_contents: true,
+        }
+    }
+
+    /// Sets whether the largest available pbuffer should be created if the requested size is
+    /// not available.
+    pub fn largest(&mut self, largest: bool) -> &mut Self {
+        self.largest = largest;
+        self
+    }
+
+    /// Sets whether the contents of the pbuffer should be preserved when resources are
+    /// reclaimed by the implementation, enabled by default.
+    pub fn preserved_contents(&mut self, preserved: bool) -> &mut Self {
+        self.preserved_contents = preserved;
+        self
+    }
+
+    /// Turns the attributes into a zero terminated GLX attribute list.
+    pub fn into_native(self) -> Vec<i32> {
+        vec![
+            glx_sys::GLX_PBUFFER_WIDTH,
+            self.width as i32,
+            glx_sys::GLX_PBUFFER_HEIGHT,
+            self.height as i32,
+            glx_sys::GLX_LARGEST_PBUFFER,
+            self.largest as i32,
+            glx_sys::GLX_PRESERVED_CONTENTS,
+            self.preserved_contents as i32,
+            0,
+        ]
+    }
+}
+
+/// A GLX pbuffer.
+///
+/// Pbuffers are offscreen GLX drawables which have no X11 drawable backing them, they can only
+/// be rendered to using OpenGL. The handle of a pbuffer is only valid for GLX calls, which is
+/// why it implements [`GLXDrawable`](crate::GLXDrawable) but not [`XDrawable`](crate::XDrawable).
+#[derive(Debug)]
+pub struct GLXPbuffer<'a> {
+    handle: glx_sys::GLXPbuffer,
+    display: &'a XDisplay,
+}
+
+impl<'a> GLXPbuffer<'a> {
+    /// Wraps a native GLX pbuffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The native GLX pbuffer to wrap
+    /// * `display` - The display this pbuffer belongs to
+    ///
+    /// # Safety
+    ///
+    /// It is up to the caller to ensure all arguments are valid.
+    pub unsafe fn new(handle: glx_sys::GLXPbuffer, display: &'a XDisplay) -> Self {
+        Self { handle, display }
+    }
+
+    /// Retrieves the underlying native platform handle for the pbuffer.
+    pub fn handle(&self) -> glx_sys::GLXPbuffer {
+        self.handle
+    }
+
+    /// Retrieves the actual width of the pbuffer.
+    ///
+    /// Returns [`None`] if the pbuffer can't be queried.
+    pub fn width(&self) -> Option<u32> {
+        self.query(glx_sys::GLX_WIDTH)
+    }
+
+    /// Retrieves the actual height of the pbuffer.
+    ///
+    /// Returns [`None`] if the pbuffer can't be queried.
+    pub fn height(&self) -> Option<u32> {
+        self.query(glx_sys::GLX_HEIGHT)
+    }
+
+    /// Determines whether the contents of the pbuffer are preserved.
+    ///
+    /// Returns [`None`] if the pbuffer can't be queried.
+    pub fn preserves_contents(&self) -> Option<bool> {
+        self.query(glx_sys::GLX_PRESERVED_CONTENTS)
+            .map(|preserved| preserved != 0)
+    }
+
+    fn query(&self, attribute: i32) -> Option<u32> {
+        let mut value = 0;
+
+        let (_, error) = self.display.trap_errors(|| unsafe {
+            glx_sys::glXQueryDrawable(self.display.handle(), self.handle, attribute, &mut value)
+        });
+
+        error.is_none().then_some(value)
+    }
+}
+
+impl<'a> Drop for GLXPbuffer<'a> {
+    fn drop(&mut self) {
+        unsafe { glx_sys::glXDestroyPbuffer(self.display.handle(), self.handle) };
+    }
+}
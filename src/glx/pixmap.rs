@@ -1,13 +1,180 @@
-use crate::glx_sys;
-use crate::XDisplay;
-use crate::{XDrawable, XPixmap};
-use x11::xlib::Drawable;
+use crate::XPixmap;
+use crate::{glx_ext_sys, glx_sys, xlib_sys};
+use crate::{GLXError, GLXFBConfigInfo, GLXTextureTargets, XDisplay};
+
+/// Type alias for the [`glXBindTexImageEXT`] C function.
+type GLXBindTexImageEXTFn =
+    unsafe extern "C" fn(*mut xlib_sys::Display, xlib_sys::Drawable, i32, *const i32);
+
+/// Type alias for the [`glXReleaseTexImageEXT`] C function.
+type GLXReleaseTexImageEXTFn =
+    unsafe extern "C" fn(*mut xlib_sys::Display, xlib_sys::Drawable, i32);
+
+/// The name of the GLX extension providing texture binding of pixmaps.
+pub(crate) const TEXTURE_FROM_PIXMAP_EXTENSION: &str = "GLX_EXT_texture_from_pixmap";
+
+/// The format of a texture a GLX pixmap is bound to.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(i32)]
+pub enum GLXTextureFormat {
+    /// The pixmap can't be bound to a texture
+    None = glx_ext_sys::GLX_TEXTURE_FORMAT_NONE_EXT,
+
+    /// The texture has no alpha channel
+    Rgb = glx_ext_sys::GLX_TEXTURE_FORMAT_RGB_EXT,
+
+    /// The texture has an alpha channel
+    Rgba = glx_ext_sys::GLX_TEXTURE_FORMAT_RGBA_EXT,
+}
+
+/// The target of a texture a GLX pixmap is bound to.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(i32)]
+pub enum GLXTextureTarget {
+    /// `GL_TEXTURE_1D`
+    Texture1D = glx_ext_sys::GLX_TEXTURE_1D_EXT,
+
+    /// `GL_TEXTURE_2D`
+    Texture2D = glx_ext_sys::GLX_TEXTURE_2D_EXT,
+
+    /// `GL_TEXTURE_RECTANGLE`, addressed using non normalized coordinates
+    Rectangle = glx_ext_sys::GLX_TEXTURE_RECTANGLE_EXT,
+}
+
+/// Attributes used to create a GLX pixmap which can be bound to a texture.
+#[derive(Debug, Clone, Copy)]
+pub struct PixmapTextureAttributes {
+    format: GLXTextureFormat,
+    target: GLXTextureTarget,
+    mipmap: bool,
+}
+
+impl PixmapTextureAttributes {
+    /// Creates a new set of texture attributes.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The format of the texture
+    /// * `target` - The target of the texture
+    pub fn new(format: GLXTextureFormat, target: GLXTextureTarget) -> Self {
+        Self {
+            format,
+            target,
+            mipmap: false,
+        }
+    }
+
+    /// Selects the texture attributes best supported by a framebuffer configuration.
+    ///
+    /// RGBA textures are chosen if the pixmap has an alpha channel and the configuration
+    /// supports them, 2D textures are preferred over rectangle textures. Returns [`None`] if
+    /// the configuration can't be bound to textures at all.
+    ///
+    /// # Arguments
+    ///
+    /// * `info` - The attributes of the framebuffer configuration
+    /// * `alpha` - Whether the pixmap has an alpha channel which should be used
+    pub fn select(info: &GLXFBConfigInfo, alpha: bool) -> Option<Self> {
+        let format = if alpha && info.bind_to_texture_rgba {
+            GLXTextureFormat::Rgba
+        } else if info.bind_to_texture_rgb {
+            GLXTextureFormat::Rgb
+        } else if info.bind_to_texture_rgba {
+            GLXTextureFormat::Rgba
+        } else {
+            return None;
+        };
+
+        let target = if info
+            .bind_to_texture_targets
+            .contains(GLXTextureTargets::TEXTURE_2D)
+        {
+            GLXTextureTarget::Texture2D
+        } else if info
+            .bind_to_texture_targets
+            .contains(GLXTextureTargets::RECTANGLE)
+        {
+            GLXTextureTarget::Rectangle
+        } else {
+            return None;
+        };
+
+        Some(Self::new(format, target))
+    }
+
+    /// Sets whether mipmap levels should be allocated for the texture.
+    ///
+    /// The configuration has to support binding to mipmapped textures.
+    pub fn mipmap(&mut self, mipmap: bool) -> &mut Self {
+        self.mipmap = mipmap;
+        self
+    }
+
+    /// Retrieves the format of the texture.
+    pub fn format(&self) -> GLXTextureFormat {
+        self.format
+    }
+
+    /// Retrieves the target of the texture.
+    pub fn target(&self) -> GLXTextureTarget {
+        self.target
+    }
+
+    /// Turns the attributes into a zero terminated GLX attribute list.
+    pub fn into_native(self) -> Vec<i32> {
+        vec![
+            glx_ext_sys::GLX_TEXTURE_FORMAT_EXT,
+            self.format as i32,
+            glx_ext_sys::GLX_TEXTURE_TARGET_EXT,
+            self.target as i32,
+            glx_ext_sys::GLX_MIPMAP_TEXTURE_EXT,
+            self.mipmap as i32,
+            0,
+        ]
+    }
+}
+
+/// Functions provided by `GLX_EXT_texture_from_pixmap`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TextureFromPixmapFns {
+    bind: GLXBindTexImageEXTFn,
+    release: GLXReleaseTexImageEXTFn,
+}
+
+impl TextureFromPixmapFns {
+    /// Loads the functions using the given lookup function.
+    ///
+    /// # Safety
+    ///
+    /// It is up to the caller to ensure that the lookup function returns the correct functions.
+    pub(crate) unsafe fn load(
+        lookup: impl Fn(&str) -> Option<unsafe extern "C" fn()>,
+    ) -> Result<Self, GLXError> {
+        type RawFn = unsafe extern "C" fn();
+
+        let missing = || GLXError::MissingExtension(TEXTURE_FROM_PIXMAP_EXTENSION);
+
+        let bind = lookup("glXBindTexImageEXT").ok_or_else(missing)?;
+        let release = lookup("glXReleaseTexImageEXT").ok_or_else(missing)?;
+
+        Ok(Self {
+            bind: std::mem::transmute::<RawFn, GLXBindTexImageEXTFn>(bind),
+            release: std::mem::transmute::<RawFn, GLXReleaseTexImageEXTFn>(release),
+        })
+    }
+}
 
 /// A GLX pixmap.
+///
+/// GLX pixmaps are separate GLX drawables created on top of an X11 pixmap, X11 requests still
+/// have to be sent to the backing pixmap. The handle of a GLX pixmap is only valid for GLX
+/// calls, which is why it implements [`GLXDrawable`](crate::GLXDrawable) but not
+/// [`XDrawable`](crate::XDrawable).
 #[derive(Debug)]
 pub struct GLXPixmap<'a> {
     handle: glx_sys::GLXPixmap,
     backing: XPixmap<'a>,
+    texture: Option<TextureFromPixmapFns>,
     display: &'a XDisplay,
 }
 
@@ -31,10 +198,17 @@ impl<'a> GLXPixmap<'a> {
         Self {
             handle,
             backing,
+            texture: None,
             display,
         }
     }
 
+    /// Enables binding the pixmap to textures using the given functions.
+    pub(crate) fn with_texture_functions(mut self, functions: TextureFromPixmapFns) -> Self {
+        self.texture = Some(functions);
+        self
+    }
+
     /// Retrieves the underlying native platform handle for the pixmap.
     pub fn handle(&self) -> glx_sys::GLXPixmap {
         self.handle
@@ -44,20 +218,50 @@ impl<'a> GLXPixmap<'a> {
     pub fn backing(&self) -> &XPixmap {
         &self.backing
     }
-}
 
-impl<'a> Drop for GLXPixmap<'a> {
-    fn drop(&mut self) {
-        unsafe { glx_sys::glXDestroyGLXPixmap(self.display.handle(), self.handle) };
+    /// Binds the pixmap to the texture currently bound to the target of the pixmap.
+    ///
+    /// The pixmap has to be created using
+    /// [`GLX::convert_pixmap_for_texture`](crate::GLX::convert_pixmap_for_texture) and a
+    /// context has to be current. The contents of the texture are undefined if the pixmap is
+    /// drawn to while bound, [`Self::release_tex_image`] has to be called before.
+    pub fn bind_tex_image(&self) -> Result<(), GLXError> {
+        let functions = self
+            .texture
+            .ok_or(GLXError::MissingExtension(TEXTURE_FROM_PIXMAP_EXTENSION))?;
+
+        unsafe {
+            (functions.bind)(
+                self.display.handle(),
+                self.handle,
+                glx_ext_sys::GLX_FRONT_LEFT_EXT,
+                std::ptr::null(),
+            )
+        };
+
+        Ok(())
     }
-}
 
-impl<'a> XDrawable<'a> for GLXPixmap<'a> {
-    fn drawable_handle(&self) -> Drawable {
-        self.handle
+    /// Releases the pixmap from the texture it has been bound to.
+    pub fn release_tex_image(&self) -> Result<(), GLXError> {
+        let functions = self
+            .texture
+            .ok_or(GLXError::MissingExtension(TEXTURE_FROM_PIXMAP_EXTENSION))?;
+
+        unsafe {
+            (functions.release)(
+                self.display.handle(),
+                self.handle,
+                glx_ext_sys::GLX_FRONT_LEFT_EXT,
+            )
+        };
+
+        Ok(())
     }
+}
 
-    fn display(&self) -> &'a XDisplay {
-        self.display
+impl<'a> Drop for GLXPixmap<'a> {
+    fn drop(&mut self) {
+        unsafe { glx_sys::glXDestroyPixmap(self.display.handle(), self.handle) };
     }
 }
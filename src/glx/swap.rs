@@ -0,0 +1,583 @@
+use crate::xlib_sys;
+use crate::{GLXDrawable, GLXError, XDisplay};
+
+/// Type alias for the [`glXSwapIntervalEXT`] C function.
+pub(crate) type GLXSwapIntervalEXTFn =
+    unsafe extern "C" fn(*mut xlib_sys::Display, xlib_sys::Drawable, i32);
+
+/// Type alias for the [`glXSwapIntervalMESA`] and [`glXSwapIntervalSGI`] C functions.
+pub(crate) type GLXSwapIntervalFn = unsafe extern "C" fn(i32) -> i32;
+
+/// Type alias for the [`glXGetSwapIntervalMESA`] C function.
+pub(crate) type GLXGetSwapIntervalMESAFn = unsafe extern "C" fn() -> i32;
+
+/// Type alias for the [`glXGetSyncValuesOML`] C function.
+type GLXGetSyncValuesOMLFn = unsafe extern "C" fn(
+    *mut xlib_sys::Display,
+    xlib_sys::Drawable,
+    *mut i64,
+    *mut i64,
+    *mut i64,
+) -> xlib_sys::Bool;
+
+/// Type alias for the [`glXGetMscRateOML`] C function.
+type GLXGetMscRateOMLFn = unsafe extern "C" fn(
+    *mut xlib_sys::Display,
+    xlib_sys::Drawable,
+    *mut i32,
+    *mut i32,
+) -> xlib_sys::Bool;
+
+/// Type alias for the [`glXSwapBuffersMscOML`] C function.
+type GLXSwapBuffersMscOMLFn =
+    unsafe extern "C" fn(*mut xlib_sys::Display, xlib_sys::Drawable, i64, i64, i64) -> i64;
+
+/// Type alias for the [`glXWaitForMscOML`] C function.
+type GLXWaitForMscOMLFn = unsafe extern "C" fn(
+    *mut xlib_sys::Display,
+    xlib_sys::Drawable,
+    i64,
+    i64,
+    i64,
+    *mut i64,
+    *mut i64,
+    *mut i64,
+) -> xlib_sys::Bool;
+
+/// Type alias for the [`glXWaitForSbcOML`] C function.
+type GLXWaitForSbcOMLFn = unsafe extern "C" fn(
+    *mut xlib_sys::Display,
+    xlib_sys::Drawable,
+    i64,
+    *mut i64,
+    *mut i64,
+    *mut i64,
+) -> xlib_sys::Bool;
+
+/// Type alias for the [`glXJoinSwapGroupNV`] C function.
+type GLXJoinSwapGroupNVFn =
+    unsafe extern "C" fn(*mut xlib_sys::Display, xlib_sys::Drawable, u32) -> xlib_sys::Bool;
+
+/// Type alias for the [`glXBindSwapBarrierNV`] C function.
+type GLXBindSwapBarrierNVFn =
+    unsafe extern "C" fn(*mut xlib_sys::Display, u32, u32) -> xlib_sys::Bool;
+
+/// Type alias for the [`glXQuerySwapGroupNV`] C function.
+type GLXQuerySwapGroupNVFn = unsafe extern "C" fn(
+    *mut xlib_sys::Display,
+    xlib_sys::Drawable,
+    *mut u32,
+    *mut u32,
+) -> xlib_sys::Bool;
+
+/// Type alias for the [`glXQueryMaxSwapGroupsNV`] C function.
+type GLXQueryMaxSwapGroupsNVFn =
+    unsafe extern "C" fn(*mut xlib_sys::Display, i32, *mut u32, *mut u32) -> xlib_sys::Bool;
+
+/// Type alias for the [`glXQueryFrameCountNV`] C function.
+type GLXQueryFrameCountNVFn =
+    unsafe extern "C" fn(*mut xlib_sys::Display, i32, *mut u32) -> xlib_sys::Bool;
+
+/// Type alias for the [`glXResetFrameCountNV`] C function.
+type GLXResetFrameCountNVFn = unsafe extern "C" fn(*mut xlib_sys::Display, i32) -> xlib_sys::Bool;
+
+/// Type alias for the [`glXJoinSwapGroupSGIX`] C function.
+pub(crate) type GLXJoinSwapGroupSGIXFn =
+    unsafe extern "C" fn(*mut xlib_sys::Display, xlib_sys::Drawable, xlib_sys::Drawable);
+
+/// The GLX extension used to control the swap interval.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum GLXSwapControl {
+    /// `GLX_EXT_swap_control`, the interval is set per drawable
+    Ext,
+
+    /// `GLX_MESA_swap_control`, the interval is set for the current context
+    Mesa,
+
+    /// `GLX_SGI_swap_control`, the interval is set for the current context and can't be 0
+    Sgi,
+}
+
+impl GLXSwapControl {
+    /// Retrieves the name of the GLX extension.
+    pub fn extension_name(&self) -> &'static str {
+        match self {
+            Self::Ext => "GLX_EXT_swap_control",
+            Self::Mesa => "GLX_MESA_swap_control",
+            Self::Sgi => "GLX_SGI_swap_control",
+        }
+    }
+
+    /// Selects the preferred swap control extension which supports the given interval.
+    ///
+    /// # Arguments
+    ///
+    /// * `extensions` - The GLX extensions supported by the screen
+    /// * `interval` - The swap interval to set, -1 for adaptive vsync
+    pub(crate) fn select(extensions: &[&str], interval: i32) -> Result<Self, GLXError> {
+        if interval < 0 {
+            // Adaptive vsync is only available through the EXT extension
+            return if extensions.contains(&"GLX_EXT_swap_control_tear")
+                && extensions.contains(&Self::Ext.extension_name())
+            {
+                Ok(Self::Ext)
+            } else {
+                Err(GLXError::MissingExtension("GLX_EXT_swap_control_tear"))
+            };
+        }
+
+        [Self::Ext, Self::Mesa, Self::Sgi]
+            .into_iter()
+            .filter(|control| *control != Self::Sgi || interval > 0)
+            .find(|control| extensions.contains(&control.extension_name()))
+            .ok_or(GLXError::MissingExtension(Self::Ext.extension_name()))
+    }
+}
+
+/// Values of the `GLX_OML_sync_control` counters.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct GLXSyncValues {
+    /// The unadjusted system time in microseconds of the last vertical retrace
+    pub ust: i64,
+
+    /// The media stream counter, incremented on every vertical retrace
+    pub msc: i64,
+
+    /// The swap buffer counter, incremented on every completed swap
+    pub sbc: i64,
+}
+
+/// Frame pacing interface provided by `GLX_OML_sync_control`.
+///
+/// The functions are loaded once when the interface is created through
+/// [`GLX::load_sync_control`](crate::GLX::load_sync_control).
+#[derive(Debug)]
+pub struct GLXSyncControl<'a> {
+    get_sync_values: GLXGetSyncValuesOMLFn,
+    get_msc_rate: GLXGetMscRateOMLFn,
+    swap_buffers_msc: GLXSwapBuffersMscOMLFn,
+    wait_for_msc: GLXWaitForMscOMLFn,
+    wait_for_sbc: GLXWaitForSbcOMLFn,
+    display: &'a XDisplay,
+}
+
+impl<'a> GLXSyncControl<'a> {
+    /// The name of the GLX extension providing sync control.
+    pub const EXTENSION_NAME: &'static str = "GLX_OML_sync_control";
+
+    /// Loads the sync control functions using the given lookup function.
+    ///
+    /// # Arguments
+    ///
+    /// * `lookup` - Function used to look up the GLX functions
+    /// * `display` - The display the functions are used with
+    ///
+    /// # Safety
+    ///
+    /// It is up to the caller to ensure that `GLX_OML_sync_control` is supported and that the
+    /// lookup function returns the correct functions.
+    pub(crate) unsafe fn load(
+        lookup: impl Fn(&str) -> Option<unsafe extern "C" fn()>,
+        display: &'a XDisplay,
+    ) -> Result<Self, GLXError> {
+        let missing = || GLXError::MissingExtension(Self::EXTENSION_NAME);
+        let load = |name| lookup(name).ok_or_else(missing);
+
+        type RawFn = unsafe extern "C" fn();
+
+        Ok(Self {
+            get_sync_values: std::mem::transmute::<RawFn, GLXGetSyncValuesOMLFn>(load(
+                "glXGetSyncValuesOML",
+            )?),
+            get_msc_rate: std::mem::transmute::<RawFn, GLXGetMscRateOMLFn>(load(
+                "glXGetMscRateOML",
+            )?),
+            swap_buffers_msc: std::mem::transmute::<RawFn, GLXSwapBuffersMscOMLFn>(load(
+                "glXSwapBuffersMscOML",
+            )?),
+            wait_for_msc: std::mem::transmute::<RawFn, GLXWaitForMscOMLFn>(load(
+                "glXWaitForMscOML",
+            )?),
+            wait_for_sbc: std::mem::transmute::<RawFn, GLXWaitForSbcOMLFn>(load(
+                "glXWaitForSbcOML",
+            )?),
+            display,
+        })
+    }
+
+    /// Retrieves the current counter values of a drawable.
+    ///
+    /// # Arguments
+    ///
+    /// * `drawable` - The drawable to query the counters of
+    pub fn get_sync_values<'b, D>(&self, drawable: &D) -> Option<GLXSyncValues>
+    where
+        D: GLXDrawable<'b>,
+    {
+        let mut values = GLXSyncValues::default();
+
+        let ok = unsafe {
+            (self.get_sync_values)(
+                self.display.handle(),
+                drawable.glx_drawable_handle(),
+                &mut values.ust,
+                &mut values.msc,
+                &mut values.sbc,
+            )
+        };
+
+        (ok != 0).then_some(values)
+    }
+
+    /// Retrieves the rate at which the media stream counter of a drawable is incremented.
+    ///
+    /// The rate is returned in hertz as a fraction of numerator and denominator.
+    ///
+    /// # Arguments
+    ///
+    /// * `drawable` - The drawable to query the rate of
+    pub fn get_msc_rate<'b, D>(&self, drawable: &D) -> Option<(i32, i32)>
+    where
+        D: GLXDrawable<'b>,
+    {
+        let mut numerator = 0;
+        let mut denominator = 0;
+
+        let ok = unsafe {
+            (self.get_msc_rate)(
+                self.display.handle(),
+                drawable.glx_drawable_handle(),
+                &mut numerator,
+                &mut denominator,
+            )
+        };
+
+        (ok != 0 && denominator != 0).then_some((numerator, denominator))
+    }
+
+    /// Schedules a buffer swap of a drawable at a specific media stream counter value.
+    ///
+    /// If `target_msc` has already passed, the swap happens at the next counter value for
+    /// which `msc % divisor == remainder`. Returns the swap buffer counter value the swap will
+    /// complete at.
+    ///
+    /// # Arguments
+    ///
+    /// * `drawable` - The drawable to swap the buffers of
+    /// * `target_msc` - The media stream counter value to swap at, 0 to only use the divisor
+    /// * `divisor` - The divisor used once the target has passed
+    /// * `remainder` - The remainder used once the target has passed
+    pub fn swap_buffers_msc<'b, D>(
+        &self,
+        drawable: &D,
+        target_msc: i64,
+        divisor: i64,
+        remainder: i64,
+    ) -> i64
+    where
+        D: GLXDrawable<'b>,
+    {
+        unsafe {
+            (self.swap_buffers_msc)(
+                self.display.handle(),
+                drawable.glx_drawable_handle(),
+                target_msc,
+                divisor,
+                remainder,
+            )
+        }
+    }
+
+    /// Blocks until the media stream counter of a drawable reaches a specific value.
+    ///
+    /// If `target_msc` has already passed, this waits for the next counter value for which
+    /// `msc % divisor == remainder`. Returns the counter values at the time of the wakeup.
+    ///
+    /// # Arguments
+    ///
+    /// * `drawable` - The drawable to wait for
+    /// * `target_msc` - The media stream counter value to wait for
+    /// * `divisor` - The divisor used once the target has passed
+    /// * `remainder` - The remainder used once the target has passed
+    pub fn wait_for_msc<'b, D>(
+        &self,
+        drawable: &D,
+        target_msc: i64,
+        divisor: i64,
+        remainder: i64,
+    ) -> Option<GLXSyncValues>
+    where
+        D: GLXDrawable<'b>,
+    {
+        let mut values = GLXSyncValues::default();
+
+        let ok = unsafe {
+            (self.wait_for_msc)(
+                self.display.handle(),
+                drawable.glx_drawable_handle(),
+                target_msc,
+                divisor,
+                remainder,
+                &mut values.ust,
+                &mut values.msc,
+                &mut values.sbc,
+            )
+        };
+
+        (ok != 0).then_some(values)
+    }
+
+    /// Blocks until the swap buffer counter of a drawable reaches a specific value.
+    ///
+    /// Returns the counter values at the time the swap completed.
+    ///
+    /// # Arguments
+    ///
+    /// * `drawable` - The drawable to wait for
+    /// * `target_sbc` - The swap buffer counter value to wait for, 0 for all pending swaps
+    pub fn wait_for_sbc<'b, D>(&self, drawable: &D, target_sbc: i64) -> Option<GLXSyncValues>
+    where
+        D: GLXDrawable<'b>,
+    {
+        let mut values = GLXSyncValues::default();
+
+        let ok = unsafe {
+            (self.wait_for_sbc)(
+                self.display.handle(),
+                drawable.glx_drawable_handle(),
+                target_sbc,
+                &mut values.ust,
+                &mut values.msc,
+                &mut values.sbc,
+            )
+        };
+
+        (ok != 0).then_some(values)
+    }
+}
+
+/// Swap group interface provided by `GLX_NV_swap_group`.
+///
+/// Drawables in the same swap group swap their buffers at the same time, swap groups bound to
+/// the same swap barrier additionally swap in sync across systems connected by a sync device.
+/// The functions are loaded once when the interface is created through
+/// [`GLX::load_swap_group`](crate::GLX::load_swap_group).
+#[derive(Debug)]
+pub struct GLXSwapGroup<'a> {
+    join_swap_group: GLXJoinSwapGroupNVFn,
+    bind_swap_barrier: GLXBindSwapBarrierNVFn,
+    query_swap_group: GLXQuerySwapGroupNVFn,
+    query_max_swap_groups: GLXQueryMaxSwapGroupsNVFn,
+    query_frame_count: GLXQueryFrameCountNVFn,
+    reset_frame_count: GLXResetFrameCountNVFn,
+    screen: i32,
+    display: &'a XDisplay,
+}
+
+impl<'a> GLXSwapGroup<'a> {
+    /// The name of the GLX extension providing swap groups.
+    pub const EXTENSION_NAME: &'static str = "GLX_NV_swap_group";
+
+    /// Loads the swap group functions using the given lookup function.
+    ///
+    /// # Arguments
+    ///
+    /// * `lookup` - Function used to look up the GLX functions
+    /// * `screen` - The number of the screen the functions are used with
+    /// * `display` - The display the functions are used with
+    ///
+    /// # Safety
+    ///
+    /// It is up to the caller to ensure that `GLX_NV_swap_group` is supported and that the
+    /// lookup function returns the correct functions.
+    pub(crate) unsafe fn load(
+        lookup: impl Fn(&str) -> Option<unsafe extern "C" fn()>,
+        screen: i32,
+        display: &'a XDisplay,
+    ) -> Result<Self, GLXError> {
+        let missing = || GLXError::MissingExtension(Self::EXTENSION_NAME);
+        let load = |name| lookup(name).ok_or_else(missing);
+
+        type RawFn = unsafe extern "C" fn();
+
+        Ok(Self {
+            join_swap_group: std::mem::transmute::<RawFn, GLXJoinSwapGroupNVFn>(load(
+                "glXJoinSwapGroupNV",
+            )?),
+            bind_swap_barrier: std::mem::transmute::<RawFn, GLXBindSwapBarrierNVFn>(load(
+                "glXBindSwapBarrierNV",
+            )?),
+            query_swap_group: std::mem::transmute::<RawFn, GLXQuerySwapGroupNVFn>(load(
+                "glXQuerySwapGroupNV",
+            )?),
+            query_max_swap_groups: std::mem::transmute::<RawFn, GLXQueryMaxSwapGroupsNVFn>(load(
+                "glXQueryMaxSwapGroupsNV",
+            )?),
+            query_frame_count: std::mem::transmute::<RawFn, GLXQueryFrameCountNVFn>(load(
+                "glXQueryFrameCountNV",
+            )?),
+            reset_frame_count: std::mem::transmute::<RawFn, GLXResetFrameCountNVFn>(load(
+                "glXResetFrameCountNV",
+            )?),
+            screen,
+            display,
+        })
+    }
+
+    /// Adds a drawable to a swap group, removing it from the group it was in before.
+    ///
+    /// Returns `false` if the group does not exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `drawable` - The drawable to add to the group
+    /// * `group` - The swap group to join, 0 to leave the current group
+    pub fn join<'b, D>(&self, drawable: &D, group: u32) -> bool
+    where
+        D: GLXDrawable<'b>,
+    {
+        unsafe {
+            (self.join_swap_group)(self.display.handle(), drawable.glx_drawable_handle(), group)
+                != 0
+        }
+    }
+
+    /// Binds a swap group to a swap barrier.
+    ///
+    /// Returns `false` if the group or the barrier does not exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `group` - The swap group to bind
+    /// * `barrier` - The swap barrier to bind to, 0 to unbind the group
+    pub fn bind_barrier(&self, group: u32, barrier: u32) -> bool {
+        unsafe { (self.bind_swap_barrier)(self.display.handle(), group, barrier) != 0 }
+    }
+
+    /// Retrieves the swap group and swap barrier a drawable is part of.
+    ///
+    /// A value of 0 means the drawable is not part of a group or the group is not bound to a
+    /// barrier.
+    ///
+    /// # Arguments
+    ///
+    /// * `drawable` - The drawable to query
+    pub fn query<'b, D>(&self, drawable: &D) -> Option<(u32, u32)>
+    where
+        D: GLXDrawable<'b>,
+    {
+        let mut group = 0;
+        let mut barrier = 0;
+
+        let ok = unsafe {
+            (self.query_swap_group)(
+                self.display.handle(),
+                drawable.glx_drawable_handle(),
+                &mut group,
+                &mut barrier,
+            )
+        };
+
+        (ok != 0).then_some((group, barrier))
+    }
+
+    /// Retrieves the maximum number of swap groups and swap barriers of the screen.
+    pub fn max_groups(&self) -> Option<(u32, u32)> {
+        let mut groups = 0;
+        let mut barriers = 0;
+
+        let ok = unsafe {
+            (self.query_max_swap_groups)(
+                self.display.handle(),
+                self.screen,
+                &mut groups,
+                &mut barriers,
+            )
+        };
+
+        (ok != 0).then_some((groups, barriers))
+    }
+
+    /// Retrieves the frame counter of the screen.
+    ///
+    /// The frame counter is shared by all systems connected by a sync device and incremented
+    /// on every synchronized swap.
+    pub fn frame_count(&self) -> Option<u32> {
+        let mut count = 0;
+
+        let ok =
+            unsafe { (self.query_frame_count)(self.display.handle(), self.screen, &mut count) };
+
+        (ok != 0).then_some(count)
+    }
+
+    /// Resets the frame counter of the screen to 0.
+    ///
+    /// Returns `false` if the counter could not be reset, for example because this system is not
+    /// the timing master of the sync device.
+    pub fn reset_frame_count(&self) -> bool {
+        unsafe { (self.reset_frame_count)(self.display.handle(), self.screen) != 0 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXT: &str = "GLX_EXT_swap_control";
+    const TEAR: &str = "GLX_EXT_swap_control_tear";
+    const MESA: &str = "GLX_MESA_swap_control";
+    const SGI: &str = "GLX_SGI_swap_control";
+
+    #[test]
+    fn prefers_ext_over_mesa_and_sgi() {
+        assert_eq!(
+            GLXSwapControl::select(&[SGI, MESA, EXT], 1).unwrap(),
+            GLXSwapControl::Ext
+        );
+        assert_eq!(
+            GLXSwapControl::select(&[SGI, MESA], 1).unwrap(),
+            GLXSwapControl::Mesa
+        );
+        assert_eq!(
+            GLXSwapControl::select(&[SGI], 1).unwrap(),
+            GLXSwapControl::Sgi
+        );
+    }
+
+    #[test]
+    fn adaptive_vsync_requires_swap_control_tear() {
+        assert!(matches!(
+            GLXSwapControl::select(&[EXT, MESA], -1),
+            Err(GLXError::MissingExtension(TEAR))
+        ));
+        assert!(matches!(
+            GLXSwapControl::select(&[TEAR], -1),
+            Err(GLXError::MissingExtension(TEAR))
+        ));
+        assert_eq!(
+            GLXSwapControl::select(&[EXT, TEAR], -1).unwrap(),
+            GLXSwapControl::Ext
+        );
+    }
+
+    #[test]
+    fn sgi_is_skipped_for_interval_zero() {
+        assert!(matches!(
+            GLXSwapControl::select(&[SGI], 0),
+            Err(GLXError::MissingExtension(EXT))
+        ));
+        assert_eq!(
+            GLXSwapControl::select(&[SGI, MESA], 0).unwrap(),
+            GLXSwapControl::Mesa
+        );
+    }
+
+    #[test]
+    fn reports_ext_if_nothing_is_available() {
+        assert!(matches!(
+            GLXSwapControl::select(&[], 1),
+            Err(GLXError::MissingExtension(EXT))
+        ));
+    }
+}
@@ -0,0 +1,65 @@
+use crate::glx_sys;
+use crate::xlib_sys;
+use crate::XDisplay;
+use crate::XWindow;
+
+/// A GLX window.
+///
+/// GLX windows are separate GLX drawables created on top of an X11 window, X11 requests still
+/// have to be sent to the backing window. The backing window stays borrowed for as long as the
+/// GLX window exists, destroying it would implicitly destroy the GLX window as well.
+///
+/// The handle of a GLX window is only valid for GLX calls, which is why it implements
+/// [`GLXDrawable`](crate::GLXDrawable) but not [`XDrawable`](crate::XDrawable).
+#[derive(Debug)]
+pub struct GLXWindow<'w, 'a> {
+    handle: glx_sys::GLXWindow,
+    backing: &'w XWindow<'a>,
+    display: &'a XDisplay,
+}
+
+impl<'w, 'a> GLXWindow<'w, 'a> {
+    /// Wraps a native GLX window.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The native GLX window to wrap
+    /// * `backing` - The X11 window backing this GLX window
+    /// * `display` - The display this window belongs to
+    ///
+    /// # Safety
+    ///
+    /// It is up to the caller to ensure all arguments are valid.
+    pub unsafe fn new(
+        handle: glx_sys::GLXWindow,
+        backing: &'w XWindow<'a>,
+        display: &'a XDisplay,
+    ) -> Self {
+        Self {
+            handle,
+            backing,
+            display,
+        }
+    }
+
+    /// Retrieves the underlying native platform handle for the window.
+    pub fn handle(&self) -> glx_sys::GLXWindow {
+        self.handle
+    }
+
+    /// Retrieves the X11 window backing this GLX window.
+    pub fn backing(&self) -> &'w XWindow<'a> {
+        self.backing
+    }
+
+    /// Retrieves the native handle of the X11 window backing this GLX window.
+    pub fn backing_handle(&self) -> xlib_sys::Window {
+        self.backing.handle()
+    }
+}
+
+impl<'w, 'a> Drop for GLXWindow<'w, 'a> {
+    fn drop(&mut self) {
+        unsafe { glx_sys::glXDestroyWindow(self.display.handle(), self.handle) };
+    }
+}
@@ -4,11 +4,16 @@
 //! this wrapper does not reflect all of X11. A lot of error checks are also missing (due to X11
 //! bad error handling mechanism).
 
+#[cfg(feature = "egl")]
+mod egl;
 mod ext;
 mod glx;
+mod sys;
 mod x11;
 
 pub use self::x11::*;
+#[cfg(feature = "egl")]
+pub use egl::*;
 pub use glx::*;
 
 pub use ::x11::glx as glx_sys;
@@ -16,7 +21,16 @@ pub use ::x11::glx::arb as glx_arb_sys;
 pub use ::x11::keysym as xkeysym_sys;
 pub use ::x11::xcomposite as xcomposite_sys;
 pub use ::x11::xfixes as xfixes_sys;
+#[cfg(feature = "xft")]
+pub use ::x11::xft as xft_sys;
 pub use ::x11::xinput2 as xinput2_sys;
 pub use ::x11::xlib as xlib_sys;
 pub use ::x11::xrandr as xrandr_sys;
+pub use ::x11::xrender as xrender_sys;
+pub use ::x11::xshm as xshm_sys;
 pub use ::x11::xtest as xtest_sys;
+#[cfg(feature = "egl")]
+pub use sys::egl as egl_sys;
+pub use sys::glx_ext as glx_ext_sys;
+#[cfg(feature = "damage")]
+pub use sys::xdamage as xdamage_sys;
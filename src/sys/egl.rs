@@ -0,0 +1,180 @@
+//! Bindings for `libEGL`.
+
+#![allow(non_upper_case_globals, non_snake_case, non_camel_case_types)]
+
+use std::os::raw::{c_char, c_void};
+
+pub type EGLBoolean = u32;
+pub type EGLenum = u32;
+pub type EGLint = i32;
+pub type EGLAttrib = isize;
+pub type EGLDisplay = *mut c_void;
+pub type EGLConfig = *mut c_void;
+pub type EGLContext = *mut c_void;
+pub type EGLSurface = *mut c_void;
+
+pub const EGL_FALSE: EGLBoolean = 0;
+pub const EGL_TRUE: EGLBoolean = 1;
+
+pub const EGL_NO_DISPLAY: EGLDisplay = std::ptr::null_mut();
+pub const EGL_NO_CONTEXT: EGLContext = std::ptr::null_mut();
+pub const EGL_NO_SURFACE: EGLSurface = std::ptr::null_mut();
+
+pub const EGL_NONE: EGLint = 0x3038;
+pub const EGL_DONT_CARE: EGLint = -1;
+
+// errors
+pub const EGL_SUCCESS: EGLint = 0x3000;
+pub const EGL_NOT_INITIALIZED: EGLint = 0x3001;
+pub const EGL_BAD_ACCESS: EGLint = 0x3002;
+pub const EGL_BAD_ALLOC: EGLint = 0x3003;
+pub const EGL_BAD_ATTRIBUTE: EGLint = 0x3004;
+pub const EGL_BAD_CONFIG: EGLint = 0x3005;
+pub const EGL_BAD_CONTEXT: EGLint = 0x3006;
+pub const EGL_BAD_CURRENT_SURFACE: EGLint = 0x3007;
+pub const EGL_BAD_DISPLAY: EGLint = 0x3008;
+pub const EGL_BAD_MATCH: EGLint = 0x3009;
+pub const EGL_BAD_NATIVE_PIXMAP: EGLint = 0x300A;
+pub const EGL_BAD_NATIVE_WINDOW: EGLint = 0x300B;
+pub const EGL_BAD_PARAMETER: EGLint = 0x300C;
+pub const EGL_BAD_SURFACE: EGLint = 0x300D;
+pub const EGL_CONTEXT_LOST: EGLint = 0x300E;
+
+// config attributes
+pub const EGL_BUFFER_SIZE: EGLint = 0x3020;
+pub const EGL_ALPHA_SIZE: EGLint = 0x3021;
+pub const EGL_BLUE_SIZE: EGLint = 0x3022;
+pub const EGL_GREEN_SIZE: EGLint = 0x3023;
+pub const EGL_RED_SIZE: EGLint = 0x3024;
+pub const EGL_DEPTH_SIZE: EGLint = 0x3025;
+pub const EGL_STENCIL_SIZE: EGLint = 0x3026;
+pub const EGL_CONFIG_CAVEAT: EGLint = 0x3027;
+pub const EGL_CONFIG_ID: EGLint = 0x3028;
+pub const EGL_MAX_PBUFFER_HEIGHT: EGLint = 0x302A;
+pub const EGL_MAX_PBUFFER_PIXELS: EGLint = 0x302B;
+pub const EGL_MAX_PBUFFER_WIDTH: EGLint = 0x302C;
+pub const EGL_NATIVE_RENDERABLE: EGLint = 0x302D;
+pub const EGL_NATIVE_VISUAL_ID: EGLint = 0x302E;
+pub const EGL_NATIVE_VISUAL_TYPE: EGLint = 0x302F;
+pub const EGL_SAMPLES: EGLint = 0x3031;
+pub const EGL_SAMPLE_BUFFERS: EGLint = 0x3032;
+pub const EGL_SURFACE_TYPE: EGLint = 0x3033;
+pub const EGL_MIN_SWAP_INTERVAL: EGLint = 0x303B;
+pub const EGL_MAX_SWAP_INTERVAL: EGLint = 0x303C;
+pub const EGL_RENDERABLE_TYPE: EGLint = 0x3040;
+pub const EGL_CONFORMANT: EGLint = 0x3042;
+
+// config caveats
+pub const EGL_SLOW_CONFIG: EGLint = 0x3050;
+pub const EGL_NON_CONFORMANT_CONFIG: EGLint = 0x3051;
+
+// surface types
+pub const EGL_PBUFFER_BIT: EGLint = 0x0001;
+pub const EGL_PIXMAP_BIT: EGLint = 0x0002;
+pub const EGL_WINDOW_BIT: EGLint = 0x0004;
+
+// renderable types
+pub const EGL_OPENGL_ES_BIT: EGLint = 0x0001;
+pub const EGL_OPENVG_BIT: EGLint = 0x0002;
+pub const EGL_OPENGL_ES2_BIT: EGLint = 0x0004;
+pub const EGL_OPENGL_BIT: EGLint = 0x0008;
+pub const EGL_OPENGL_ES3_BIT: EGLint = 0x0040;
+
+// query strings
+pub const EGL_VENDOR: EGLint = 0x3053;
+pub const EGL_VERSION: EGLint = 0x3054;
+pub const EGL_EXTENSIONS: EGLint = 0x3055;
+pub const EGL_CLIENT_APIS: EGLint = 0x308D;
+
+// surface attributes
+pub const EGL_HEIGHT: EGLint = 0x3056;
+pub const EGL_WIDTH: EGLint = 0x3057;
+pub const EGL_LARGEST_PBUFFER: EGLint = 0x3058;
+
+// client APIs
+pub const EGL_OPENGL_ES_API: EGLenum = 0x30A0;
+pub const EGL_OPENGL_API: EGLenum = 0x30A2;
+
+// context attributes
+pub const EGL_CONTEXT_MAJOR_VERSION: EGLint = 0x3098;
+pub const EGL_CONTEXT_MINOR_VERSION: EGLint = 0x30FB;
+pub const EGL_CONTEXT_OPENGL_PROFILE_MASK: EGLint = 0x30FD;
+pub const EGL_CONTEXT_OPENGL_RESET_NOTIFICATION_STRATEGY: EGLint = 0x31BD;
+pub const EGL_CONTEXT_OPENGL_DEBUG: EGLint = 0x31B0;
+pub const EGL_CONTEXT_OPENGL_FORWARD_COMPATIBLE: EGLint = 0x31B1;
+pub const EGL_CONTEXT_OPENGL_ROBUST_ACCESS: EGLint = 0x31B2;
+pub const EGL_CONTEXT_OPENGL_CORE_PROFILE_BIT: EGLint = 0x0001;
+pub const EGL_CONTEXT_OPENGL_COMPATIBILITY_PROFILE_BIT: EGLint = 0x0002;
+pub const EGL_NO_RESET_NOTIFICATION: EGLint = 0x31BE;
+pub const EGL_LOSE_CONTEXT_ON_RESET: EGLint = 0x31BF;
+
+// EGL_KHR_platform_x11
+pub const EGL_PLATFORM_X11_KHR: EGLenum = 0x31D5;
+pub const EGL_PLATFORM_X11_SCREEN_KHR: EGLAttrib = 0x31D6;
+
+#[link(name = "EGL")]
+extern "C" {
+    pub fn eglGetError() -> EGLint;
+
+    pub fn eglInitialize(dpy: EGLDisplay, major: *mut EGLint, minor: *mut EGLint) -> EGLBoolean;
+
+    pub fn eglTerminate(dpy: EGLDisplay) -> EGLBoolean;
+
+    pub fn eglQueryString(dpy: EGLDisplay, name: EGLint) -> *const c_char;
+
+    pub fn eglGetProcAddress(procname: *const c_char) -> Option<unsafe extern "C" fn()>;
+
+    pub fn eglChooseConfig(
+        dpy: EGLDisplay,
+        attrib_list: *const EGLint,
+        configs: *mut EGLConfig,
+        config_size: EGLint,
+        num_config: *mut EGLint,
+    ) -> EGLBoolean;
+
+    pub fn eglGetConfigAttrib(
+        dpy: EGLDisplay,
+        config: EGLConfig,
+        attribute: EGLint,
+        value: *mut EGLint,
+    ) -> EGLBoolean;
+
+    pub fn eglBindAPI(api: EGLenum) -> EGLBoolean;
+
+    pub fn eglCreateContext(
+        dpy: EGLDisplay,
+        config: EGLConfig,
+        share_context: EGLContext,
+        attrib_list: *const EGLint,
+    ) -> EGLContext;
+
+    pub fn eglDestroyContext(dpy: EGLDisplay, ctx: EGLContext) -> EGLBoolean;
+
+    pub fn eglMakeCurrent(
+        dpy: EGLDisplay,
+        draw: EGLSurface,
+        read: EGLSurface,
+        ctx: EGLContext,
+    ) -> EGLBoolean;
+
+    pub fn eglGetCurrentContext() -> EGLContext;
+
+    pub fn eglCreatePbufferSurface(
+        dpy: EGLDisplay,
+        config: EGLConfig,
+        attrib_list: *const EGLint,
+    ) -> EGLSurface;
+
+    pub fn eglDestroySurface(dpy: EGLDisplay, surface: EGLSurface) -> EGLBoolean;
+
+    pub fn eglQuerySurface(
+        dpy: EGLDisplay,
+        surface: EGLSurface,
+        attribute: EGLint,
+        value: *mut EGLint,
+    ) -> EGLBoolean;
+
+    pub fn eglSwapBuffers(dpy: EGLDisplay, surface: EGLSurface) -> EGLBoolean;
+
+    pub fn eglSwapInterval(dpy: EGLDisplay, interval: EGLint) -> EGLBoolean;
+}
@@ -0,0 +1,54 @@
+//! GLX extension tokens not covered by the `x11` crate.
+
+use std::os::raw::c_int;
+
+// GLX_ARB_framebuffer_sRGB
+pub const GLX_FRAMEBUFFER_SRGB_CAPABLE_ARB: c_int = 0x20B2;
+
+// GLX_ARB_create_context_robustness
+pub const GLX_CONTEXT_ROBUST_ACCESS_BIT_ARB: c_int = 0x0004;
+pub const GLX_CONTEXT_RESET_NOTIFICATION_STRATEGY_ARB: c_int = 0x8256;
+pub const GLX_NO_RESET_NOTIFICATION_ARB: c_int = 0x8261;
+pub const GLX_LOSE_CONTEXT_ON_RESET_ARB: c_int = 0x8252;
+
+// GLX_ARB_create_context_no_error
+pub const GLX_CONTEXT_OPENGL_NO_ERROR_ARB: c_int = 0x31B3;
+
+// GLX_ARB_context_flush_control
+pub const GLX_CONTEXT_RELEASE_BEHAVIOR_ARB: c_int = 0x2097;
+pub const GLX_CONTEXT_RELEASE_BEHAVIOR_NONE_ARB: c_int = 0;
+pub const GLX_CONTEXT_RELEASE_BEHAVIOR_FLUSH_ARB: c_int = 0x2098;
+
+// GLX_EXT_create_context_es2_profile
+pub const GLX_CONTEXT_ES2_PROFILE_BIT_EXT: c_int = 0x0004;
+
+// GLX_EXT_swap_control_tear
+pub const GLX_LATE_SWAPS_TEAR_EXT: c_int = 0x20F3;
+
+// GLX_EXT_texture_from_pixmap
+pub const GLX_TEXTURE_1D_BIT_EXT: c_int = 0x0001;
+pub const GLX_TEXTURE_2D_BIT_EXT: c_int = 0x0002;
+pub const GLX_TEXTURE_RECTANGLE_BIT_EXT: c_int = 0x0004;
+pub const GLX_BIND_TO_TEXTURE_RGB_EXT: c_int = 0x20D0;
+pub const GLX_BIND_TO_TEXTURE_RGBA_EXT: c_int = 0x20D1;
+pub const GLX_BIND_TO_MIPMAP_TEXTURE_EXT: c_int = 0x20D2;
+pub const GLX_BIND_TO_TEXTURE_TARGETS_EXT: c_int = 0x20D3;
+pub const GLX_Y_INVERTED_EXT: c_int = 0x20D4;
+pub const GLX_TEXTURE_FORMAT_EXT: c_int = 0x20D5;
+pub const GLX_TEXTURE_TARGET_EXT: c_int = 0x20D6;
+pub const GLX_MIPMAP_TEXTURE_EXT: c_int = 0x20D7;
+pub const GLX_TEXTURE_FORMAT_NONE_EXT: c_int = 0x20D8;
+pub const GLX_TEXTURE_FORMAT_RGB_EXT: c_int = 0x20D9;
+pub const GLX_TEXTURE_FORMAT_RGBA_EXT: c_int = 0x20DA;
+pub const GLX_TEXTURE_1D_EXT: c_int = 0x20DB;
+pub const GLX_TEXTURE_2D_EXT: c_int = 0x20DC;
+pub const GLX_TEXTURE_RECTANGLE_EXT: c_int = 0x20DD;
+pub const GLX_FRONT_LEFT_EXT: c_int = 0x20DE;
+
+// GLX_MESA_query_renderer
+pub const GLX_RENDERER_VENDOR_ID_MESA: c_int = 0x8183;
+pub const GLX_RENDERER_DEVICE_ID_MESA: c_int = 0x8184;
+pub const GLX_RENDERER_VERSION_MESA: c_int = 0x8185;
+pub const GLX_RENDERER_ACCELERATED_MESA: c_int = 0x8186;
+pub const GLX_RENDERER_VIDEO_MEMORY_MESA: c_int = 0x8187;
+pub const GLX_RENDERER_UNIFIED_MEMORY_ARCHITECTURE_MESA: c_int = 0x8188;
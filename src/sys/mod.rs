@@ -0,0 +1,7 @@
+//! Native bindings for X11 extensions not covered by the `x11` crate.
+
+#[cfg(feature = "egl")]
+pub mod egl;
+pub mod glx_ext;
+#[cfg(feature = "damage")]
+pub mod xdamage;
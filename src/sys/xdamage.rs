@@ -0,0 +1,65 @@
+//! Bindings for `libXdamage`.
+
+#![allow(non_upper_case_globals, non_snake_case)]
+
+use std::os::raw::{c_int, c_ulong};
+
+use crate::xfixes_sys::XserverRegion;
+use crate::xlib_sys::{Bool, Display, Drawable, Status, Time, XRectangle, XID};
+
+pub type Damage = XID;
+
+pub const XDamageNotify: c_int = 0;
+pub const XDamageNumberEvents: c_int = 1;
+
+pub const BadDamage: c_int = 0;
+pub const XDamageNumberErrors: c_int = 1;
+
+pub const XDamageReportRawRectangles: c_int = 0;
+pub const XDamageReportDeltaRectangles: c_int = 1;
+pub const XDamageReportBoundingBox: c_int = 2;
+pub const XDamageReportNonEmpty: c_int = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+pub struct XDamageNotifyEvent {
+    pub type_: c_int,
+    pub serial: c_ulong,
+    pub send_event: Bool,
+    pub display: *mut Display,
+    pub drawable: Drawable,
+    pub damage: Damage,
+    pub level: c_int,
+    pub more: Bool,
+    pub timestamp: Time,
+    pub area: XRectangle,
+    pub geometry: XRectangle,
+}
+
+#[link(name = "Xdamage")]
+extern "C" {
+    pub fn XDamageQueryExtension(
+        dpy: *mut Display,
+        event_base_return: *mut c_int,
+        error_base_return: *mut c_int,
+    ) -> Bool;
+
+    pub fn XDamageQueryVersion(
+        dpy: *mut Display,
+        major_version_return: *mut c_int,
+        minor_version_return: *mut c_int,
+    ) -> Status;
+
+    pub fn XDamageCreate(dpy: *mut Display, drawable: Drawable, level: c_int) -> Damage;
+
+    pub fn XDamageDestroy(dpy: *mut Display, damage: Damage);
+
+    pub fn XDamageSubtract(
+        dpy: *mut Display,
+        damage: Damage,
+        repair: XserverRegion,
+        parts: XserverRegion,
+    );
+
+    pub fn XDamageAdd(dpy: *mut Display, drawable: Drawable, region: XserverRegion);
+}
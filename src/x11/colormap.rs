@@ -1,4 +1,5 @@
-use crate::{xlib_sys, XDisplay};
+use crate::{xlib_sys, XDisplay, XVisual};
+use std::ffi::CString;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[repr(i32)]
@@ -35,6 +36,145 @@ impl ColormapState {
     }
 }
 
+/// An X11 color with 16 bits per channel.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct XColor {
+    /// The pixel value of the color, only meaningful once the color has been allocated
+    pub pixel: u64,
+
+    /// The red channel of the color
+    pub red: u16,
+
+    /// The green channel of the color
+    pub green: u16,
+
+    /// The blue channel of the color
+    pub blue: u16,
+}
+
+impl XColor {
+    /// Creates a new color from 16 bit channels.
+    ///
+    /// # Arguments
+    ///
+    /// * `red` - The red channel of the color
+    /// * `green` - The green channel of the color
+    /// * `blue` - The blue channel of the color
+    pub fn from_rgb(red: u16, green: u16, blue: u16) -> Self {
+        Self {
+            pixel: 0,
+            red,
+            green,
+            blue,
+        }
+    }
+
+    /// Creates a new color from 8 bit channels.
+    ///
+    /// # Arguments
+    ///
+    /// * `red` - The red channel of the color
+    /// * `green` - The green channel of the color
+    /// * `blue` - The blue channel of the color
+    pub fn from_rgb8(red: u8, green: u8, blue: u8) -> Self {
+        Self::from_rgb(red as u16 * 257, green as u16 * 257, blue as u16 * 257)
+    }
+
+    /// Converts this color into its native representation with all channels enabled.
+    pub fn to_native(self) -> xlib_sys::XColor {
+        xlib_sys::XColor {
+            pixel: self.pixel,
+            red: self.red,
+            green: self.green,
+            blue: self.blue,
+            flags: (xlib_sys::DoRed | xlib_sys::DoGreen | xlib_sys::DoBlue) as _,
+            pad: 0,
+        }
+    }
+}
+
+impl From<xlib_sys::XColor> for XColor {
+    fn from(value: xlib_sys::XColor) -> Self {
+        Self {
+            pixel: value.pixel,
+            red: value.red,
+            green: value.green,
+            blue: value.blue,
+        }
+    }
+}
+
+/// A pixel value as used by drawing operations.
+///
+/// Pixel values depend on the visual of the drawable, use [`Pixel::from_color`] to convert
+/// colors for TrueColor and DirectColor visuals and [`XColormap::alloc_color`] for all others,
+/// wrapping the pixel value of the allocated color.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Pixel(pub u64);
+
+impl Pixel {
+    /// Converts a color into a pixel value using the channel masks of a visual.
+    ///
+    /// Returns [`None`] if the visual has no channel masks, which is the case for all visuals
+    /// using a colormap to look up colors.
+    ///
+    /// # Arguments
+    ///
+    /// * `visual` - The visual the pixel value will be used with
+    /// * `color` - The color to convert
+    pub fn from_color(visual: &XVisual, color: XColor) -> Option<Self> {
+        let (red_mask, green_mask, blue_mask) = visual.color_masks();
+
+        if red_mask == 0 || green_mask == 0 || blue_mask == 0 {
+            return None;
+        }
+
+        let channel = |value: u16, mask: u64| {
+            let shift = mask.trailing_zeros();
+            let max = mask >> shift;
+
+            ((value as u64 * max + 32767) / 65535) << shift
+        };
+
+        Some(Self(
+            channel(color.red, red_mask)
+                | channel(color.green, green_mask)
+                | channel(color.blue, blue_mask),
+        ))
+    }
+
+    /// Converts an 8 bit RGB color into a pixel value using the channel masks of a visual.
+    ///
+    /// See [`Pixel::from_color`] for details.
+    ///
+    /// # Arguments
+    ///
+    /// * `visual` - The visual the pixel value will be used with
+    /// * `red` - The red channel of the color
+    /// * `green` - The green channel of the color
+    /// * `blue` - The blue channel of the color
+    pub fn from_rgb8(visual: &XVisual, red: u8, green: u8, blue: u8) -> Option<Self> {
+        Self::from_color(visual, XColor::from_rgb8(red, green, blue))
+    }
+
+    /// Retrieves the raw pixel value.
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+impl From<u64> for Pixel {
+    fn from(value: u64) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Pixel> for u64 {
+    fn from(value: Pixel) -> Self {
+        value.0
+    }
+}
+
 /// Describes how a colormap handle is owned
 #[derive(Debug)]
 pub enum ColormapHandleOwnership {
@@ -80,6 +220,140 @@ impl<'a> XColormap<'a> {
     pub fn handle(&self) -> xlib_sys::Colormap {
         self.handle
     }
+
+    /// Allocates the closest color the hardware supports.
+    ///
+    /// Returns the allocated color with its pixel value set, or [`None`] if the colormap is
+    /// full.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color to allocate
+    pub fn alloc_color(&self, color: XColor) -> Option<XColor> {
+        let mut native = color.to_native();
+
+        if unsafe { xlib_sys::XAllocColor(self.display.handle(), self.handle, &mut native) } != 0 {
+            Some(native.into())
+        } else {
+            None
+        }
+    }
+
+    /// Allocates a color by its name, for example `"steel blue"`.
+    ///
+    /// Returns the allocated color along with the exact color the name describes, or [`None`]
+    /// if the name is unknown or the colormap is full.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the color
+    ///
+    /// # Panics
+    ///
+    /// If the name contains a nul character.
+    pub fn alloc_named_color(&self, name: impl AsRef<str>) -> Option<(XColor, XColor)> {
+        let name = CString::new(name.as_ref()).unwrap();
+
+        let mut allocated = XColor::default().to_native();
+        let mut exact = XColor::default().to_native();
+
+        let result = unsafe {
+            xlib_sys::XAllocNamedColor(
+                self.display.handle(),
+                self.handle,
+                name.as_ptr(),
+                &mut allocated,
+                &mut exact,
+            )
+        };
+
+        if result != 0 {
+            Some((allocated.into(), exact.into()))
+        } else {
+            None
+        }
+    }
+
+    /// Parses a color specification without allocating it.
+    ///
+    /// Supported are color names from the X color database as well as `#rgb`, `#rrggbb`,
+    /// `rgb:r/g/b` and `rgbi:r/g/b` specifications.
+    ///
+    /// # Arguments
+    ///
+    /// * `spec` - The color specification to parse
+    ///
+    /// # Panics
+    ///
+    /// If the specification contains a nul character.
+    pub fn parse_color(&self, spec: impl AsRef<str>) -> Option<XColor> {
+        let spec = CString::new(spec.as_ref()).unwrap();
+        let mut native = XColor::default().to_native();
+
+        let result = unsafe {
+            xlib_sys::XParseColor(
+                self.display.handle(),
+                self.handle,
+                spec.as_ptr(),
+                &mut native,
+            )
+        };
+
+        if result != 0 {
+            Some(native.into())
+        } else {
+            None
+        }
+    }
+
+    /// Looks up the colors stored for pixel values.
+    ///
+    /// # Arguments
+    ///
+    /// * `pixels` - The pixel values to look up
+    pub fn query_colors(&self, pixels: &[Pixel]) -> Vec<XColor> {
+        let mut colors = pixels
+            .iter()
+            .map(|pixel| {
+                XColor {
+                    pixel: pixel.0,
+                    ..Default::default()
+                }
+                .to_native()
+            })
+            .collect::<Vec<_>>();
+
+        unsafe {
+            xlib_sys::XQueryColors(
+                self.display.handle(),
+                self.handle,
+                colors.as_mut_ptr(),
+                colors.len() as _,
+            )
+        };
+
+        colors.into_iter().map(XColor::from).collect()
+    }
+
+    /// Frees previously allocated colors.
+    ///
+    /// # Arguments
+    ///
+    /// * `pixels` - The pixel values of the colors to free
+    /// * `planes` - The planes to free along with the pixel values
+    pub fn free_colors(&self, pixels: &[Pixel], planes: u64) {
+        let mut pixels = pixels.iter().map(|pixel| pixel.0).collect::<Vec<_>>();
+
+        unsafe {
+            xlib_sys::XFreeColors(
+                self.display.handle(),
+                self.handle,
+                pixels.as_mut_ptr(),
+                pixels.len() as _,
+                planes,
+            )
+        };
+    }
 }
 
 impl<'a> Drop for XColormap<'a> {
@@ -1,9 +1,108 @@
+use crate::ext::xcursor;
 use crate::{xfixes_sys, xlib_sys, XDisplay};
+use std::collections::HashSet;
+use std::ffi::{CStr, CString};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// The cursor shapes available in the standard X11 cursor font.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(u32)]
+pub enum CursorShape {
+    XCursor = 0,
+    Arrow = 2,
+    BasedArrowDown = 4,
+    BasedArrowUp = 6,
+    Boat = 8,
+    Bogosity = 10,
+    BottomLeftCorner = 12,
+    BottomRightCorner = 14,
+    BottomSide = 16,
+    BottomTee = 18,
+    BoxSpiral = 20,
+    CenterPtr = 22,
+    Circle = 24,
+    Clock = 26,
+    CoffeeMug = 28,
+    Cross = 30,
+    CrossReverse = 32,
+    Crosshair = 34,
+    DiamondCross = 36,
+    Dot = 38,
+    Dotbox = 40,
+    DoubleArrow = 42,
+    DraftLarge = 44,
+    DraftSmall = 46,
+    DrapedBox = 48,
+    Exchange = 50,
+    Fleur = 52,
+    Gobbler = 54,
+    Gumby = 56,
+    Hand1 = 58,
+    Hand2 = 60,
+    Heart = 62,
+    Icon = 64,
+    IronCross = 66,
+    LeftPtr = 68,
+    LeftSide = 70,
+    LeftTee = 72,
+    LeftButton = 74,
+    LlAngle = 76,
+    LrAngle = 78,
+    Man = 80,
+    MiddleButton = 82,
+    Mouse = 84,
+    Pencil = 86,
+    Pirate = 88,
+    Plus = 90,
+    QuestionArrow = 92,
+    RightPtr = 94,
+    RightSide = 96,
+    RightTee = 98,
+    RightButton = 100,
+    RtlLogo = 102,
+    Sailboat = 104,
+    SbDownArrow = 106,
+    SbHDoubleArrow = 108,
+    SbLeftArrow = 110,
+    SbRightArrow = 112,
+    SbUpArrow = 114,
+    SbVDoubleArrow = 116,
+    Shuttle = 118,
+    Sizing = 120,
+    Spider = 122,
+    Spraycan = 124,
+    Star = 126,
+    Target = 128,
+    TCross = 130,
+    TopLeftArrow = 132,
+    TopLeftCorner = 134,
+    TopRightCorner = 136,
+    TopSide = 138,
+    TopTee = 140,
+    Trek = 142,
+    UlAngle = 144,
+    Umbrella = 146,
+    UrAngle = 148,
+    Watch = 150,
+    XTerm = 152,
+}
+
+/// Describes how a cursor handle is owned
+#[derive(Debug)]
+pub enum CursorHandleOwnership {
+    /// The cursor handle is not owned at all
+    Foreign,
+
+    /// The cursor is our own handle
+    Owned,
+}
 
 #[derive(Debug)]
 pub struct XCursor<'a> {
     handle: xlib_sys::Cursor,
-    _display: &'a XDisplay,
+    display: &'a XDisplay,
+    ownership: CursorHandleOwnership,
 }
 
 impl<'a> XCursor<'a> {
@@ -13,14 +112,20 @@ impl<'a> XCursor<'a> {
     ///
     /// * `handle` - The X11 cursor to wrap
     /// * `display` - The display the cursor belongs to
+    /// * `ownership` - The ownership of the passed cursor handle
     ///
     /// # Safety
     ///
     /// It is up to the caller to ensure all arguments are valid.
-    pub unsafe fn new(handle: xlib_sys::Cursor, display: &'a XDisplay) -> Self {
+    pub unsafe fn new(
+        handle: xlib_sys::Cursor,
+        display: &'a XDisplay,
+        ownership: CursorHandleOwnership,
+    ) -> Self {
         Self {
             handle,
-            _display: display,
+            display,
+            ownership,
         }
     }
 
@@ -28,6 +133,53 @@ impl<'a> XCursor<'a> {
     pub fn handle(&self) -> xlib_sys::Cursor {
         self.handle
     }
+
+    /// Retrieves the name of the cursor, if it has one.
+    pub fn name(&self) -> Option<String> {
+        let mut atom = 0;
+
+        unsafe {
+            let name =
+                xfixes_sys::XFixesGetCursorName(self.display.handle(), self.handle, &mut atom);
+            if name.is_null() {
+                return None;
+            }
+
+            let out = CStr::from_ptr(name).to_string_lossy().into_owned();
+            xlib_sys::XFree(name as _);
+
+            if atom == 0 || out.is_empty() {
+                None
+            } else {
+                Some(out)
+            }
+        }
+    }
+
+    /// Changes the name of the cursor.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The new name of the cursor
+    ///
+    /// # Panics
+    ///
+    /// If the name contains a nul character.
+    pub fn set_name(&self, name: impl AsRef<str>) {
+        let name = CString::new(name.as_ref()).unwrap();
+
+        unsafe {
+            xfixes_sys::XFixesSetCursorName(self.display.handle(), self.handle, name.as_ptr())
+        };
+    }
+}
+
+impl<'a> Drop for XCursor<'a> {
+    fn drop(&mut self) {
+        if matches!(self.ownership, CursorHandleOwnership::Owned) {
+            unsafe { xlib_sys::XFreeCursor(self.display.handle(), self.handle) };
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -69,22 +221,48 @@ impl XCursorImage {
         unsafe { &*self.handle }.yhot
     }
 
+    /// Retrieves the serial of the cursor this image has been taken from.
+    pub fn serial(&self) -> u64 {
+        unsafe { &*self.handle }.cursor_serial as _
+    }
+
+    /// Retrieves the name of the cursor this image has been taken from, if it has one.
+    pub fn name(&self) -> Option<String> {
+        let name = unsafe { &*self.handle }.name;
+
+        if name.is_null() {
+            return None;
+        }
+
+        let name = unsafe { CStr::from_ptr(name) }.to_string_lossy();
+        if name.is_empty() {
+            None
+        } else {
+            Some(name.into_owned())
+        }
+    }
+
+    /// Retrieves an iterator over the pixels of the image in ARGB format.
+    ///
+    /// The pixels are ordered row by row, starting at the top left corner.
+    pub fn argb_pixels(&self) -> impl Iterator<Item = u32> + '_ {
+        let size = self.width() as usize * self.height() as usize;
+        let pixels = unsafe { std::slice::from_raw_parts((*self.handle).pixels, size) };
+
+        pixels.iter().map(|&pixel| (pixel & 0xFFFFFFFF) as u32)
+    }
+
     /// Retrieves the cursor image as RGBA.
     pub fn rgba_data(&self) -> Vec<u8> {
-        let size = (self.width() * self.height()) as usize;
+        let size = self.width() as usize * self.height() as usize;
 
         let mut buffer = Vec::with_capacity(size * 4);
 
-        unsafe {
-            for i in 0..size {
-                let pixel = *(*self.handle).pixels.add(i);
-                let pixel = (pixel & 0xFFFFFFFF) as u32;
-
-                buffer.push(((pixel & 0x00FF0000) >> 16) as _);
-                buffer.push(((pixel & 0x0000FF00) >> 8) as _);
-                buffer.push((pixel & 0x000000FF) as _);
-                buffer.push(((pixel & 0xFF000000) >> 24) as _);
-            }
+        for pixel in self.argb_pixels() {
+            buffer.push(((pixel & 0x00FF0000) >> 16) as _);
+            buffer.push(((pixel & 0x0000FF00) >> 8) as _);
+            buffer.push((pixel & 0x000000FF) as _);
+            buffer.push(((pixel & 0xFF000000) >> 24) as _);
         }
 
         buffer
@@ -96,3 +274,277 @@ impl Drop for XCursorImage {
         unsafe { xlib_sys::XFree(self.handle as _) };
     }
 }
+
+/// A single frame of a cursor loaded from a cursor theme.
+#[derive(Debug, Clone)]
+pub struct XCursorFrame {
+    /// The width of the frame in pixels.
+    pub width: u32,
+
+    /// The height of the frame in pixels.
+    pub height: u32,
+
+    /// The x coordinate of the hotspot.
+    pub hotspot_x: u32,
+
+    /// The y coordinate of the hotspot.
+    pub hotspot_y: u32,
+
+    /// The delay in milliseconds before the next frame is shown, only meaningful for animations.
+    pub delay: u32,
+
+    /// The pixels of the frame in premultiplied ARGB format, row by row.
+    pub pixels: Vec<u32>,
+}
+
+/// Possible errors that might occur while loading cursors from a theme.
+#[derive(Debug, Error)]
+pub enum XCursorThemeError {
+    /// The cursor could neither be found in the theme nor in any theme it inherits from.
+    #[error("cursor {0} not found in the cursor theme")]
+    NotFound(String),
+
+    /// The cursor file could not be read.
+    #[error("failed to read cursor file: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The cursor file is not a valid Xcursor file.
+    #[error("invalid cursor file: {0}")]
+    InvalidFile(&'static str),
+
+    /// The cursor file does not contain any images.
+    #[error("the cursor file does not contain any images")]
+    NoImages,
+
+    /// The X server failed to create a cursor from the loaded images.
+    #[error("the cursor could not be created")]
+    CreationFailed,
+}
+
+/// An Xcursor theme as found on the file system.
+///
+/// Themes are looked up in the directories of the `XCURSOR_PATH` environment variable, or the
+/// default search path if it is not set. Cursors missing from a theme are looked up in the
+/// themes it inherits from and finally in the `default` theme.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct XCursorTheme {
+    name: String,
+    size: u32,
+    search_path: Vec<PathBuf>,
+}
+
+impl XCursorTheme {
+    /// The search path used when `XCURSOR_PATH` is not set.
+    const DEFAULT_SEARCH_PATH: &'static str =
+        "~/.local/share/icons:~/.icons:/usr/share/icons:/usr/share/pixmaps";
+
+    /// The name of the theme used as a last resort.
+    const FALLBACK_THEME: &'static str = "default";
+
+    /// Creates a theme description with an explicit name and size.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the theme
+    /// * `size` - The nominal cursor size to select images for
+    pub fn new(name: impl Into<String>, size: u32) -> Self {
+        Self {
+            name: name.into(),
+            size,
+            search_path: Self::search_path_from_environment(),
+        }
+    }
+
+    /// Determines the theme the user has configured for a display.
+    ///
+    /// The theme name and size are taken from the `XCURSOR_THEME` and `XCURSOR_SIZE`
+    /// environment variables, falling back to the `Xcursor.theme` and `Xcursor.size`
+    /// resources. If no size is configured, it is derived from `Xft.dpi` or the screen size.
+    ///
+    /// # Arguments
+    ///
+    /// * `display` - The display to look up the configuration for
+    pub fn from_display(display: &XDisplay) -> Self {
+        let name = std::env::var("XCURSOR_THEME")
+            .ok()
+            .filter(|name| !name.is_empty())
+            .or_else(|| display.get_default("Xcursor", "theme").map(String::from))
+            .unwrap_or_else(|| String::from(Self::FALLBACK_THEME));
+
+        let size = std::env::var("XCURSOR_SIZE")
+            .ok()
+            .and_then(|size| size.parse().ok())
+            .or_else(|| {
+                display
+                    .get_default("Xcursor", "size")
+                    .and_then(|size| size.parse().ok())
+            })
+            .filter(|&size| size > 0)
+            .or_else(|| {
+                display
+                    .get_default("Xft", "dpi")
+                    .and_then(|dpi| dpi.parse::<u32>().ok())
+                    .map(|dpi| dpi * 16 / 72)
+                    .filter(|&size| size > 0)
+            })
+            .unwrap_or_else(|| {
+                let screen = display.default_screen().handle();
+                let dimension = unsafe { (*screen).width.min((*screen).height) };
+
+                (dimension / 48).max(1) as u32
+            });
+
+        Self::new(name, size)
+    }
+
+    /// Retrieves the name of the theme.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Retrieves the nominal cursor size images are selected for.
+    pub fn size(&self) -> u32 {
+        self.size
+    }
+
+    /// Retrieves the directories themes are looked up in.
+    pub fn search_path(&self) -> &[PathBuf] {
+        &self.search_path
+    }
+
+    /// Builds the search path from `XCURSOR_PATH`, expanding `~` to the home directory.
+    fn search_path_from_environment() -> Vec<PathBuf> {
+        let path = std::env::var("XCURSOR_PATH")
+            .ok()
+            .filter(|path| !path.is_empty())
+            .unwrap_or_else(|| String::from(Self::DEFAULT_SEARCH_PATH));
+
+        let home = std::env::var_os("HOME").map(PathBuf::from);
+
+        path.split(':')
+            .filter(|entry| !entry.is_empty())
+            .filter_map(|entry| match entry.strip_prefix('~') {
+                Some(rest) => home
+                    .as_ref()
+                    .map(|home| home.join(rest.trim_start_matches('/'))),
+                None => Some(PathBuf::from(entry)),
+            })
+            .collect()
+    }
+
+    /// Reads the names of the themes a theme inherits from.
+    ///
+    /// # Arguments
+    ///
+    /// * `theme_dir` - The directory of the theme
+    fn read_inherits(theme_dir: &Path) -> Vec<String> {
+        let index = match std::fs::read_to_string(theme_dir.join("index.theme")) {
+            Ok(v) => v,
+            Err(_) => return Vec::new(),
+        };
+
+        index
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("Inherits"))
+            .filter_map(|rest| rest.trim_start().strip_prefix('='))
+            .flat_map(|value| value.split([',', ';']))
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+            .collect()
+    }
+
+    /// Searches a theme and the themes it inherits from for a cursor file.
+    ///
+    /// # Arguments
+    ///
+    /// * `theme` - The name of the theme to search
+    /// * `cursor` - The name of the cursor to search for
+    /// * `visited` - The themes which have already been searched
+    fn scan_theme(
+        &self,
+        theme: &str,
+        cursor: &str,
+        visited: &mut HashSet<String>,
+    ) -> Option<PathBuf> {
+        if !visited.insert(theme.to_string()) {
+            return None;
+        }
+
+        let theme_dirs = self
+            .search_path
+            .iter()
+            .map(|dir| dir.join(theme))
+            .filter(|dir| dir.is_dir())
+            .collect::<Vec<_>>();
+
+        for dir in &theme_dirs {
+            let file = dir.join("cursors").join(cursor);
+            if file.is_file() {
+                return Some(file);
+            }
+        }
+
+        for dir in &theme_dirs {
+            for inherited in Self::read_inherits(dir) {
+                if let Some(file) = self.scan_theme(&inherited, cursor, visited) {
+                    return Some(file);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Looks up the file of a cursor in this theme.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - The name of the cursor to look up, for example `left_ptr`
+    pub fn find_cursor_file(&self, cursor: impl AsRef<str>) -> Option<PathBuf> {
+        let cursor = cursor.as_ref();
+        let mut visited = HashSet::new();
+
+        self.scan_theme(&self.name, cursor, &mut visited)
+            .or_else(|| self.scan_theme(Self::FALLBACK_THEME, cursor, &mut visited))
+    }
+
+    /// Loads the frames of a cursor from this theme.
+    ///
+    /// Only the frames with the nominal size closest to the size of this theme are returned.
+    /// Static cursors consist of a single frame, animated cursors of multiple.
+    ///
+    /// # Arguments
+    ///
+    /// * `cursor` - The name of the cursor to load, for example `left_ptr`
+    pub fn load_frames(
+        &self,
+        cursor: impl AsRef<str>,
+    ) -> Result<Vec<XCursorFrame>, XCursorThemeError> {
+        let cursor = cursor.as_ref();
+
+        let file = self
+            .find_cursor_file(cursor)
+            .ok_or_else(|| XCursorThemeError::NotFound(cursor.to_string()))?;
+
+        let data = std::fs::read(file)?;
+        let images = xcursor::parse(&data).map_err(XCursorThemeError::InvalidFile)?;
+
+        let frames = xcursor::select_size(images, self.size)
+            .into_iter()
+            .map(|image| XCursorFrame {
+                width: image.width,
+                height: image.height,
+                hotspot_x: image.xhot,
+                hotspot_y: image.yhot,
+                delay: image.delay,
+                pixels: image.pixels,
+            })
+            .collect::<Vec<_>>();
+
+        if frames.is_empty() {
+            Err(XCursorThemeError::NoImages)
+        } else {
+            Ok(frames)
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{xdamage_sys, XDisplay, XServerRegion};
+
+/// Determines how damage to a drawable is reported.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum DamageReportLevel {
+    /// Every rectangle damaged by a rendering operation is reported
+    RawRectangles = xdamage_sys::XDamageReportRawRectangles,
+
+    /// Only rectangles which are not already part of the accumulated damage are reported
+    DeltaRectangles = xdamage_sys::XDamageReportDeltaRectangles,
+
+    /// Only changes of the bounding box of the accumulated damage are reported
+    BoundingBox = xdamage_sys::XDamageReportBoundingBox,
+
+    /// An event is only reported when the accumulated damage becomes non-empty
+    NonEmpty = xdamage_sys::XDamageReportNonEmpty,
+}
+
+impl DamageReportLevel {
+    /// Wraps an existing X11 damage report level.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - The native X11 damage report level to wrap
+    pub fn new(level: i32) -> Self {
+        match level {
+            xdamage_sys::XDamageReportRawRectangles => Self::RawRectangles,
+            xdamage_sys::XDamageReportDeltaRectangles => Self::DeltaRectangles,
+            xdamage_sys::XDamageReportBoundingBox => Self::BoundingBox,
+            xdamage_sys::XDamageReportNonEmpty => Self::NonEmpty,
+            x => unreachable!("Invalid X damage report level: {}", x),
+        }
+    }
+}
+
+/// Tracks the damage done to a drawable by rendering operations.
+///
+/// Damage accumulates on the X server until it is subtracted again, events are reported
+/// according to the [`DamageReportLevel`] the damage object has been created with.
+#[derive(Debug)]
+pub struct XDamage<'a> {
+    handle: xdamage_sys::Damage,
+    display: &'a XDisplay,
+}
+
+impl<'a> XDamage<'a> {
+    /// Wraps an existing X11 damage object.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The X11 damage object to wrap
+    /// * `display` - The display the damage object belongs to
+    ///
+    /// # Safety
+    ///
+    /// It is up to the caller to ensure all arguments are valid.
+    pub unsafe fn new(handle: xdamage_sys::Damage, display: &'a XDisplay) -> Self {
+        Self { handle, display }
+    }
+
+    /// Retrieves the underlying native X11 damage handle.
+    pub fn handle(&self) -> xdamage_sys::Damage {
+        self.handle
+    }
+
+    /// Removes damage from the accumulated damage.
+    ///
+    /// # Arguments
+    ///
+    /// * `repair` - The region to remove, or [`None`] to remove all damage
+    /// * `parts` - If given, receives the damage which has been removed
+    pub fn subtract(&self, repair: Option<&XServerRegion>, parts: Option<&XServerRegion>) {
+        unsafe {
+            xdamage_sys::XDamageSubtract(
+                self.display.handle(),
+                self.handle,
+                repair.map(|r| r.handle()).unwrap_or(0),
+                parts.map(|r| r.handle()).unwrap_or(0),
+            )
+        };
+    }
+
+    /// Removes all accumulated damage and returns it as a region.
+    pub fn take(&self) -> XServerRegion<'a> {
+        let parts = self.display.create_region(&[]);
+        self.subtract(None, Some(&parts));
+
+        parts
+    }
+}
+
+impl<'a> Drop for XDamage<'a> {
+    fn drop(&mut self) {
+        unsafe { xdamage_sys::XDamageDestroy(self.display.handle(), self.handle) };
+    }
+}
@@ -1,11 +1,44 @@
+use crate::x11::render::{gradient_stops_to_native, to_fixed};
+#[cfg(feature = "damage")]
+use crate::xdamage_sys;
 use crate::{
-    xfixes_sys, xlib_sys, xtest_sys, WindowShapeKind, XBitmapPadding, XCursorImage, XDrawable,
-    XEvent, XFont, XImage, XImageFormat, XPixmap, XRectangle, XServerRegion, XVisual, XWindow, XGC,
+    xfixes_sys, xinput2_sys, xlib_sys, xrender_sys, xshm_sys, xtest_sys, CursorHandleOwnership,
+    CursorShape, PictGradientStop, PictPoint, PictStandardFormat, WindowShapeKind, XBitmapPadding,
+    XColor, XCursor, XCursorFrame, XCursorImage, XCursorTheme, XCursorThemeError, XDrawable,
+    XEvent, XFont, XFontInfo, XFontSet, XIDeviceInfo, XIHierarchyChange, XIReturnMode, XImage,
+    XImageFormat, XInputDevice, XPictFormat, XPicture, XPixmap, XRectangle, XRenderColor,
+    XServerRegion, XShmImage, XTransferImage, XVisual, XWindow, XGC,
 };
+#[cfg(feature = "xft")]
+use crate::{xft_sys, XColormap, XftColor, XftFont, XftFontList};
 use crate::{XAtom, XLibError, XScreen};
 use std::ffi::{CStr, CString};
 use std::mem::MaybeUninit;
 use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+/// The XInput2 version announced to the X server.
+const XINPUT2_MAJOR_VERSION: i32 = 2;
+const XINPUT2_MINOR_VERSION: i32 = 4;
+
+/// Code of the last X error captured by [`trap_error_handler`], 0 if none.
+static TRAPPED_ERROR: AtomicU8 = AtomicU8::new(0);
+
+/// Serializes swapping the process wide error handler in [`XDisplay::trap_errors`].
+///
+/// Without it, two threads trapping errors at the same time could restore the trap handler as
+/// the previous handler, swallowing all further errors of the process.
+static TRAP_LOCK: Mutex<()> = Mutex::new(());
+
+/// X error handler which records the error code instead of aborting.
+unsafe extern "C" fn trap_error_handler(
+    _display: *mut xlib_sys::Display,
+    event: *mut xlib_sys::XErrorEvent,
+) -> i32 {
+    TRAPPED_ERROR.store((*event).error_code, Ordering::SeqCst);
+    0
+}
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[repr(i32)]
@@ -33,7 +66,11 @@ pub struct XDisplay {
     ownership: DisplayOwnership,
     handle: *mut xlib_sys::Display,
     xfixes_event_base: i32,
+    #[cfg(feature = "damage")]
+    damage_event_base: Option<i32>,
+    shm_pixmaps: Option<bool>,
     xinput2_opcode: i32,
+    xinput2_version: Option<(i32, i32)>,
 }
 
 impl XDisplay {
@@ -80,11 +117,53 @@ impl XDisplay {
             xfixes_sys::XFixesQueryExtension(handle, &mut xfixes_event_base, &mut xfixes_error_base)
         };
 
+        #[cfg(feature = "damage")]
+        let damage_event_base = unsafe {
+            let mut damage_event_base = 0;
+            let mut damage_error_base = 0;
+
+            if xdamage_sys::XDamageQueryExtension(
+                handle,
+                &mut damage_event_base,
+                &mut damage_error_base,
+            ) != 0
+            {
+                // The damage extension requires the version to be negotiated before use
+                let mut major = 1;
+                let mut minor = 1;
+                xdamage_sys::XDamageQueryVersion(handle, &mut major, &mut minor);
+
+                Some(damage_event_base)
+            } else {
+                None
+            }
+        };
+
+        let shm_pixmaps = unsafe {
+            // Shared memory only works if the X server runs on the same machine, this avoids
+            // attempting to attach segments over connections which are known to be remote
+            let display_string = CStr::from_ptr(xlib_sys::XDisplayString(handle)).to_bytes();
+            let is_local = display_string.starts_with(b":") || display_string.starts_with(b"unix:");
+
+            let mut major = 0;
+            let mut minor = 0;
+            let mut pixmaps = xlib_sys::False;
+
+            if is_local
+                && xshm_sys::XShmQueryExtension(handle) != 0
+                && xshm_sys::XShmQueryVersion(handle, &mut major, &mut minor, &mut pixmaps) != 0
+            {
+                Some(pixmaps != 0 && xshm_sys::XShmPixmapFormat(handle) == xlib_sys::ZPixmap)
+            } else {
+                None
+            }
+        };
+
         let mut xinput2_opcode = 0;
         let mut xinput2_event_base = 0;
         let mut xinput2_error_base = 0;
 
-        unsafe {
+        let xinput2_present = unsafe {
             let name = CString::new("XInputExtension").unwrap();
 
             xlib_sys::XQueryExtension(
@@ -93,15 +172,65 @@ impl XDisplay {
                 &mut xinput2_opcode,
                 &mut xinput2_event_base,
                 &mut xinput2_error_base,
-            );
+            ) != 0
+        };
+
+        if !xinput2_present {
+            xinput2_opcode = 0;
         }
 
-        XDisplay {
+        let mut display = XDisplay {
             ownership,
             handle,
             xfixes_event_base,
+            #[cfg(feature = "damage")]
+            damage_event_base,
+            shm_pixmaps,
             xinput2_opcode,
+            xinput2_version: None,
+        };
+
+        // The XInput2 version can only be announced once per connection, the owner of a foreign
+        // display may already have done so or may want to do so itself
+        if display.ownership == DisplayOwnership::Owned {
+            display.negotiate_xinput2(XINPUT2_MAJOR_VERSION, XINPUT2_MINOR_VERSION);
         }
+
+        display
+    }
+
+    /// Announces the XInput2 version supported by the client to the X server.
+    ///
+    /// XInput2 requests behave like version 2.0 until the client announced its version. This is
+    /// done automatically for owned displays, but has to be done explicitly for foreign displays
+    /// if their owner did not already do so.
+    ///
+    /// # Arguments
+    ///
+    /// * `major` - The major XInput2 version supported by the client
+    /// * `minor` - The minor XInput2 version supported by the client
+    ///
+    /// Returns the highest version supported by both the client and the X server, or [`None`] if
+    /// the X server does not support XInput2 or the version could not be negotiated.
+    pub fn negotiate_xinput2(&mut self, major: i32, minor: i32) -> Option<(i32, i32)> {
+        // Extension opcodes are never zero, it is only zero if the extension is not present
+        if self.xinput2_opcode == 0 {
+            return None;
+        }
+
+        let mut major = major;
+        let mut minor = minor;
+
+        // Announcing a version lower than a previously announced one fails with BadValue
+        let (status, error) = self.trap_errors(|| unsafe {
+            xinput2_sys::XIQueryVersion(self.handle, &mut major, &mut minor)
+        });
+
+        if status == 0 && error.is_none() {
+            self.xinput2_version = Some((major, minor));
+        }
+
+        self.xinput2_version
     }
 
     /// Retrieves the name of the display that [`xlib_sys::XOpenDisplay`] would attempt to use.
@@ -180,6 +309,34 @@ impl XDisplay {
         unsafe { xlib_sys::XSync(self.handle, discard.into()) };
     }
 
+    /// Runs a function while capturing X errors instead of aborting the process.
+    ///
+    /// The display is synchronized before and after running the function, so all errors caused
+    /// by requests issued in the function are captured. Returns the result of the function and
+    /// the code of the last error raised while it ran. Note that the error handler is process
+    /// wide, errors caused by other threads during this time are captured as well.
+    ///
+    /// Concurrent calls are serialized, so the function must not trap errors itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The function to run
+    pub(crate) fn trap_errors<T>(&self, f: impl FnOnce() -> T) -> (T, Option<u8>) {
+        let _guard = TRAP_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+
+        self.sync(false);
+        TRAPPED_ERROR.store(0, Ordering::SeqCst);
+
+        let previous = unsafe { xlib_sys::XSetErrorHandler(Some(trap_error_handler)) };
+        let result = f();
+        self.sync(false);
+        unsafe { xlib_sys::XSetErrorHandler(previous) };
+
+        let error = TRAPPED_ERROR.swap(0, Ordering::SeqCst);
+
+        (result, (error != 0).then_some(error))
+    }
+
     /// Flushes all commands from the queue.
     ///
     /// Other than [`sync`] this function does not read incoming events.
@@ -244,6 +401,223 @@ impl XDisplay {
         }
     }
 
+    /// Lists the names of the fonts available on the X server.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The pattern the names have to match, may contain `*` and `?` wildcards
+    /// * `max` - The maximum amount of names to return
+    ///
+    /// # Panics
+    ///
+    /// If the pattern contains a nul character.
+    pub fn list_fonts(&self, pattern: impl AsRef<str>, max: usize) -> Vec<String> {
+        let pattern = CString::new(pattern.as_ref()).unwrap();
+        let mut count = 0;
+
+        let names =
+            unsafe { xlib_sys::XListFonts(self.handle, pattern.as_ptr(), max as _, &mut count) };
+
+        if names.is_null() {
+            return Vec::new();
+        }
+
+        let out = (0..count as usize)
+            .map(|i| unsafe { CStr::from_ptr(*names.add(i)) })
+            .map(|name| name.to_string_lossy().into_owned())
+            .collect();
+
+        unsafe { xlib_sys::XFreeFontNames(names) };
+
+        out
+    }
+
+    /// Lists the names of the fonts available on the X server along with their metadata.
+    ///
+    /// This does not load the fonts, thus no per character metrics are available.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The pattern the names have to match, may contain `*` and `?` wildcards
+    /// * `max` - The maximum amount of names to return
+    ///
+    /// # Panics
+    ///
+    /// If the pattern contains a nul character.
+    pub fn list_fonts_with_info(
+        &self,
+        pattern: impl AsRef<str>,
+        max: usize,
+    ) -> Vec<(String, XFontInfo)> {
+        let pattern = CString::new(pattern.as_ref()).unwrap();
+        let mut count = 0;
+        let mut info = std::ptr::null_mut();
+
+        let names = unsafe {
+            xlib_sys::XListFontsWithInfo(
+                self.handle,
+                pattern.as_ptr(),
+                max as _,
+                &mut count,
+                &mut info,
+            )
+        };
+
+        if names.is_null() {
+            return Vec::new();
+        }
+
+        let out = (0..count as usize)
+            .map(|i| unsafe {
+                let name = CStr::from_ptr(*names.add(i)).to_string_lossy().into_owned();
+                let info = XFontInfo::from_native(&*info.add(i), self);
+
+                (name, info)
+            })
+            .collect();
+
+        unsafe { xlib_sys::XFreeFontInfo(names, info, count) };
+
+        out
+    }
+
+    /// Attempts to create a font set for drawing text in the current locale.
+    ///
+    /// See [`XFontSet::init_locale`] for setting up the locale. The font set is created even if
+    /// no font could be found for some charsets, see [`XFontSet::missing_charsets`].
+    ///
+    /// # Arguments
+    ///
+    /// * `base_font_names` - Comma separated list of font name patterns to select fonts from
+    ///
+    /// # Panics
+    ///
+    /// If the base font names contain a nul character.
+    pub fn create_font_set(&self, base_font_names: impl AsRef<str>) -> Option<XFontSet> {
+        let base_font_names = CString::new(base_font_names.as_ref()).unwrap();
+
+        let mut missing_list = std::ptr::null_mut();
+        let mut missing_count = 0;
+        let mut default_string = std::ptr::null_mut();
+
+        let font_set = unsafe {
+            xlib_sys::XCreateFontSet(
+                self.handle,
+                base_font_names.as_ptr(),
+                &mut missing_list,
+                &mut missing_count,
+                &mut default_string,
+            )
+        };
+
+        let missing_charsets = if missing_list.is_null() {
+            Vec::new()
+        } else {
+            let charsets = (0..missing_count as usize)
+                .map(|i| unsafe { CStr::from_ptr(*missing_list.add(i)) })
+                .map(|charset| charset.to_string_lossy().into_owned())
+                .collect();
+
+            unsafe { xlib_sys::XFreeStringList(missing_list) };
+
+            charsets
+        };
+
+        if font_set.is_null() {
+            None
+        } else {
+            Some(unsafe { XFontSet::new(font_set, missing_charsets, self) })
+        }
+    }
+
+    /// Attempts to open an Xft font by its fontconfig pattern, for example "Noto Sans:size=11".
+    ///
+    /// # Arguments
+    ///
+    /// * `screen` - The screen the font will be used on
+    /// * `pattern` - The fontconfig pattern describing the font
+    ///
+    /// # Panics
+    ///
+    /// If the pattern contains a nul character.
+    #[cfg(feature = "xft")]
+    pub fn open_xft_font(&self, screen: &XScreen, pattern: impl AsRef<str>) -> Option<XftFont> {
+        let pattern = CString::new(pattern.as_ref()).unwrap();
+
+        let font =
+            unsafe { xft_sys::XftFontOpenName(self.handle(), screen.number(), pattern.as_ptr()) };
+
+        if font.is_null() {
+            None
+        } else {
+            Some(unsafe { XftFont::new(font, self) })
+        }
+    }
+
+    /// Opens a list of Xft fonts for falling back on missing characters.
+    ///
+    /// Patterns which can not be opened are skipped, [`None`] is returned if no pattern could be
+    /// opened.
+    ///
+    /// # Arguments
+    ///
+    /// * `screen` - The screen the fonts will be used on
+    /// * `patterns` - The fontconfig patterns describing the fonts in order of preference
+    ///
+    /// # Panics
+    ///
+    /// If a pattern contains a nul character.
+    #[cfg(feature = "xft")]
+    pub fn open_xft_font_list<S: AsRef<str>>(
+        &self,
+        screen: &XScreen,
+        patterns: &[S],
+    ) -> Option<XftFontList> {
+        let fonts = patterns
+            .iter()
+            .filter_map(|pattern| self.open_xft_font(screen, pattern))
+            .collect::<Vec<_>>();
+
+        if fonts.is_empty() {
+            None
+        } else {
+            Some(XftFontList::new(fonts))
+        }
+    }
+
+    /// Attempts to allocate a color for drawing with Xft.
+    ///
+    /// # Arguments
+    ///
+    /// * `visual` - The visual of the drawable the color will be used on
+    /// * `colormap` - The colormap of the drawable the color will be used on
+    /// * `color` - The color to allocate
+    #[cfg(feature = "xft")]
+    pub fn alloc_xft_color<'c, 'a>(
+        &'a self,
+        visual: &XVisual,
+        colormap: &'c XColormap<'a>,
+        color: XRenderColor,
+    ) -> Option<XftColor<'c, 'a>> {
+        let mut native = unsafe { std::mem::zeroed() };
+
+        let result = unsafe {
+            xft_sys::XftColorAllocValue(
+                self.handle(),
+                visual.handle(),
+                colormap.handle(),
+                &color.to_native(),
+                &mut native,
+            )
+        };
+
+        if result == 0 {
+            None
+        } else {
+            Some(unsafe { XftColor::new(native, visual, colormap, self) })
+        }
+    }
+
     /// Attempts to find the default font for the display.
     ///
     /// # Arguments
@@ -342,11 +716,486 @@ impl XDisplay {
         unsafe { XImage::new(image, self) }
     }
 
+    /// Creates a new image backed by shared memory in [`XImageFormat::ZPixmap`] format.
+    ///
+    /// Returns [`None`] if the MIT-SHM extension is not available, the shared memory segment
+    /// could not be created or the X server failed to attach it (for example because it does not
+    /// share the IPC namespace with this process).
+    ///
+    /// # Arguments
+    ///
+    /// * `visual` - The visual to use backing the image
+    /// * `depth` - The depth of the image
+    /// * `width` - The width of the image
+    /// * `height` - The height of the image
+    pub fn create_shm_image(
+        &self,
+        visual: &XVisual,
+        depth: u32,
+        width: u32,
+        height: u32,
+    ) -> Option<XShmImage> {
+        if !self.shm_available() {
+            return None;
+        }
+
+        let mut segment = Box::new(xshm_sys::XShmSegmentInfo {
+            shmseg: 0,
+            shmid: -1,
+            shmaddr: std::ptr::null_mut(),
+            readOnly: xlib_sys::False,
+        });
+
+        unsafe {
+            let image = xshm_sys::XShmCreateImage(
+                self.handle,
+                visual.handle(),
+                depth,
+                XImageFormat::ZPixmap as _,
+                std::ptr::null_mut(),
+                segment.as_mut(),
+                width,
+                height,
+            );
+
+            if image.is_null() {
+                return None;
+            }
+
+            let size = (*image).bytes_per_line as usize * (*image).height as usize;
+            segment.shmid = libc::shmget(libc::IPC_PRIVATE, size, libc::IPC_CREAT | 0o600);
+
+            if segment.shmid < 0 {
+                xlib_sys::XDestroyImage(image);
+                return None;
+            }
+
+            let address = libc::shmat(segment.shmid, std::ptr::null(), 0);
+
+            // The segment is destroyed once both the X server and this process have detached,
+            // so it does not leak even if the process dies
+            libc::shmctl(segment.shmid, libc::IPC_RMID, std::ptr::null_mut());
+
+            if address as isize == -1 {
+                xlib_sys::XDestroyImage(image);
+                return None;
+            }
+
+            segment.shmaddr = address as _;
+            (*image).data = address as _;
+
+            // The X server reports attach failures asynchronously as an X error
+            let (attached, error) =
+                self.trap_errors(|| xshm_sys::XShmAttach(self.handle, segment.as_mut()));
+
+            if attached == 0 || error.is_some() {
+                (*image).data = std::ptr::null_mut();
+                xlib_sys::XDestroyImage(image);
+                libc::shmdt(address);
+                return None;
+            }
+
+            Some(XShmImage::new(image, segment, self))
+        }
+    }
+
+    /// Creates a new image for transferring pixel data in [`XImageFormat::ZPixmap`] format.
+    ///
+    /// The image is backed by shared memory if possible and falls back to a regular image
+    /// otherwise. The pixel data is initially zeroed. Returns [`None`] if the image could not be
+    /// allocated.
+    ///
+    /// # Arguments
+    ///
+    /// * `visual` - The visual to use backing the image
+    /// * `depth` - The depth of the image
+    /// * `width` - The width of the image
+    /// * `height` - The height of the image
+    pub fn create_transfer_image(
+        &self,
+        visual: &XVisual,
+        depth: u32,
+        width: u32,
+        height: u32,
+    ) -> Option<XTransferImage> {
+        if let Some(image) = self.create_shm_image(visual, depth, width, height) {
+            return Some(XTransferImage::Shared(image));
+        }
+
+        unsafe {
+            let image = xlib_sys::XCreateImage(
+                self.handle,
+                visual.handle(),
+                depth,
+                XImageFormat::ZPixmap as _,
+                0,
+                std::ptr::null_mut(),
+                width,
+                height,
+                XBitmapPadding::Bit32 as _,
+                0,
+            );
+
+            if image.is_null() {
+                return None;
+            }
+
+            let size = (*image).bytes_per_line as usize * height as usize;
+            let data = libc::calloc(size, 1);
+
+            if data.is_null() {
+                xlib_sys::XDestroyImage(image);
+                return None;
+            }
+
+            (*image).data = data as _; // will be freed by X11
+
+            Some(XTransferImage::Plain(XImage::new(image, self)))
+        }
+    }
+
     /// Retrieves the current cursor image.
     pub fn get_cursor_image(&self) -> XCursorImage {
         unsafe { XCursorImage::new(xfixes_sys::XFixesGetCursorImage(self.handle)) }
     }
 
+    /// Creates a cursor from the standard X11 cursor font.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape of the cursor
+    pub fn create_font_cursor(&self, shape: CursorShape) -> XCursor {
+        unsafe {
+            let cursor = xlib_sys::XCreateFontCursor(self.handle, shape as _);
+
+            XCursor::new(cursor, self, CursorHandleOwnership::Owned)
+        }
+    }
+
+    /// Creates a two color cursor from bitmaps.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The bitmap defining the shape of the cursor, must have a depth of 1
+    /// * `mask` - The bitmap selecting which source pixels are displayed, [`None`] for all
+    /// * `foreground` - The color to use for set bits in the source
+    /// * `background` - The color to use for unset bits in the source
+    /// * `hotspot_x` - The x coordinate of the hotspot relative to the source
+    /// * `hotspot_y` - The y coordinate of the hotspot relative to the source
+    pub fn create_pixmap_cursor(
+        &self,
+        source: &XPixmap,
+        mask: Option<&XPixmap>,
+        foreground: XColor,
+        background: XColor,
+        hotspot_x: u32,
+        hotspot_y: u32,
+    ) -> XCursor {
+        let mut foreground = foreground.to_native();
+        let mut background = background.to_native();
+
+        unsafe {
+            let cursor = xlib_sys::XCreatePixmapCursor(
+                self.handle,
+                source.handle(),
+                mask.map(|m| m.handle()).unwrap_or(0),
+                &mut foreground,
+                &mut background,
+                hotspot_x,
+                hotspot_y,
+            );
+
+            XCursor::new(cursor, self, CursorHandleOwnership::Owned)
+        }
+    }
+
+    /// Creates a full color cursor with alpha channel from an RGBA buffer.
+    ///
+    /// This requires the Render extension to be present on the X server.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the cursor image
+    /// * `height` - The height of the cursor image
+    /// * `hotspot_x` - The x coordinate of the hotspot
+    /// * `hotspot_y` - The y coordinate of the hotspot
+    /// * `rgba` - The pixels of the cursor image in RGBA order, not premultiplied
+    ///
+    /// Returns [`None`] if the Render extension is not available, the image is empty or too
+    /// large, or the X server failed to create the cursor.
+    ///
+    /// # Panics
+    ///
+    /// If the size of `rgba` does not match the width and height.
+    pub fn create_argb_cursor(
+        &self,
+        width: u32,
+        height: u32,
+        hotspot_x: u32,
+        hotspot_y: u32,
+        rgba: &[u8],
+    ) -> Option<XCursor> {
+        assert_eq!(rgba.len() as u64, width as u64 * height as u64 * 4);
+
+        let premultiply = |channel: u8, alpha: u8| (channel as u32 * alpha as u32 + 127) / 255;
+
+        let pixels = rgba
+            .chunks_exact(4)
+            .map(|p| {
+                let alpha = p[3];
+
+                ((alpha as u32) << 24)
+                    | (premultiply(p[0], alpha) << 16)
+                    | (premultiply(p[1], alpha) << 8)
+                    | premultiply(p[2], alpha)
+            })
+            .collect::<Vec<_>>();
+
+        unsafe {
+            let cursor = self.create_render_cursor(width, height, hotspot_x, hotspot_y, &pixels)?;
+
+            Some(XCursor::new(cursor, self, CursorHandleOwnership::Owned))
+        }
+    }
+
+    /// Creates a cursor from frames loaded from a cursor theme.
+    ///
+    /// A single frame results in a static cursor, multiple frames in an animated cursor. This
+    /// requires the Render extension to be present on the X server.
+    ///
+    /// # Arguments
+    ///
+    /// * `frames` - The frames of the cursor
+    ///
+    /// Returns [`None`] if the Render extension is not available, a frame is empty or too
+    /// large, or the X server failed to create the cursor.
+    ///
+    /// # Panics
+    ///
+    /// If no frames are given or the size of the pixels of a frame does not match its width and
+    /// height.
+    pub fn create_cursor_from_frames(&self, frames: &[XCursorFrame]) -> Option<XCursor> {
+        assert!(!frames.is_empty(), "A cursor requires at least one frame");
+
+        let create_frame = |frame: &XCursorFrame| {
+            assert_eq!(
+                frame.pixels.len() as u64,
+                frame.width as u64 * frame.height as u64
+            );
+
+            unsafe {
+                self.create_render_cursor(
+                    frame.width,
+                    frame.height,
+                    frame.hotspot_x,
+                    frame.hotspot_y,
+                    &frame.pixels,
+                )
+            }
+        };
+
+        if frames.len() == 1 {
+            let cursor = create_frame(&frames[0])?;
+            return Some(unsafe { XCursor::new(cursor, self, CursorHandleOwnership::Owned) });
+        }
+
+        let mut animation = Vec::with_capacity(frames.len());
+
+        for frame in frames {
+            match create_frame(frame) {
+                Some(cursor) => animation.push(xrender_sys::XAnimCursor {
+                    cursor,
+                    delay: frame.delay as _,
+                }),
+                None => break,
+            }
+        }
+
+        let (cursor, error) = if animation.len() == frames.len() {
+            self.trap_errors(|| unsafe {
+                xrender_sys::XRenderCreateAnimCursor(
+                    self.handle,
+                    animation.len() as _,
+                    animation.as_mut_ptr(),
+                )
+            })
+        } else {
+            (0, None)
+        };
+
+        // The animated cursor keeps its own references to the frames
+        for frame in &animation {
+            unsafe { xlib_sys::XFreeCursor(self.handle, frame.cursor) };
+        }
+
+        match (cursor, error) {
+            (0, _) => None,
+            (cursor, Some(_)) => {
+                unsafe { xlib_sys::XFreeCursor(self.handle, cursor) };
+                None
+            }
+            (cursor, None) => {
+                Some(unsafe { XCursor::new(cursor, self, CursorHandleOwnership::Owned) })
+            }
+        }
+    }
+
+    /// Loads a cursor from a cursor theme.
+    ///
+    /// # Arguments
+    ///
+    /// * `theme` - The theme to load the cursor from
+    /// * `name` - The name of the cursor, for example `left_ptr`
+    pub fn load_theme_cursor(
+        &self,
+        theme: &XCursorTheme,
+        name: impl AsRef<str>,
+    ) -> Result<XCursor, XCursorThemeError> {
+        let frames = theme.load_frames(name)?;
+
+        self.create_cursor_from_frames(&frames)
+            .ok_or(XCursorThemeError::CreationFailed)
+    }
+
+    /// Creates a cursor using the Render extension.
+    ///
+    /// Returns [`None`] if the Render extension is not available, the image is empty or too
+    /// large, or the X server failed to create the cursor.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the cursor image
+    /// * `height` - The height of the cursor image
+    /// * `hotspot_x` - The x coordinate of the hotspot
+    /// * `hotspot_y` - The y coordinate of the hotspot
+    /// * `pixels` - The pixels of the cursor image in premultiplied ARGB format
+    ///
+    /// # Safety
+    ///
+    /// The size of `pixels` must match the width and height.
+    unsafe fn create_render_cursor(
+        &self,
+        width: u32,
+        height: u32,
+        hotspot_x: u32,
+        hotspot_y: u32,
+        pixels: &[u32],
+    ) -> Option<xlib_sys::Cursor> {
+        // Pixmap sizes are limited to 16 bits by the protocol
+        if width == 0 || height == 0 || width > u16::MAX as u32 || height > u16::MAX as u32 {
+            return None;
+        }
+
+        let mut event_base = 0;
+        let mut error_base = 0;
+
+        if xrender_sys::XRenderQueryExtension(self.handle, &mut event_base, &mut error_base) == 0 {
+            return None;
+        }
+
+        let format =
+            xrender_sys::XRenderFindStandardFormat(self.handle, xrender_sys::PictStandardARGB32);
+
+        if format.is_null() {
+            return None;
+        }
+
+        let (cursor, error) = self.trap_errors(|| {
+            let root = xlib_sys::XDefaultRootWindow(self.handle);
+            let pixmap = xlib_sys::XCreatePixmap(self.handle, root, width, height, 32);
+            let gc = xlib_sys::XCreateGC(self.handle, pixmap, 0, std::ptr::null_mut());
+
+            let image = xlib_sys::XCreateImage(
+                self.handle,
+                std::ptr::null_mut(),
+                32,
+                xlib_sys::ZPixmap,
+                0,
+                pixels.as_ptr() as _,
+                width,
+                height,
+                32,
+                (width * 4) as _,
+            );
+
+            let mut cursor = 0;
+
+            if !image.is_null() {
+                // The pixels are in native byte order instead of the byte order of the server
+                (*image).byte_order = if cfg!(target_endian = "little") {
+                    xlib_sys::LSBFirst
+                } else {
+                    xlib_sys::MSBFirst
+                };
+
+                xlib_sys::XPutImage(self.handle, pixmap, gc, image, 0, 0, 0, 0, width, height);
+
+                // The data is borrowed and must not be freed by X11
+                (*image).data = std::ptr::null_mut();
+                xlib_sys::XDestroyImage(image);
+
+                let picture = xrender_sys::XRenderCreatePicture(
+                    self.handle,
+                    pixmap,
+                    format,
+                    0,
+                    std::ptr::null(),
+                );
+
+                cursor =
+                    xrender_sys::XRenderCreateCursor(self.handle, picture, hotspot_x, hotspot_y);
+
+                xrender_sys::XRenderFreePicture(self.handle, picture);
+            }
+
+            xlib_sys::XFreeGC(self.handle, gc);
+            xlib_sys::XFreePixmap(self.handle, pixmap);
+
+            cursor
+        });
+
+        match (cursor, error) {
+            (0, _) => None,
+            (cursor, Some(_)) => {
+                xlib_sys::XFreeCursor(self.handle, cursor);
+                None
+            }
+            (cursor, None) => Some(cursor),
+        }
+    }
+
+    /// Changes the shape of a cursor to the shape of another cursor.
+    ///
+    /// All windows currently using the destination cursor will display the new shape.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The cursor to take the shape from
+    /// * `destination` - The cursor to change
+    pub fn change_cursor(&self, source: &XCursor, destination: &XCursor) {
+        unsafe {
+            xfixes_sys::XFixesChangeCursor(self.handle, source.handle(), destination.handle())
+        };
+    }
+
+    /// Changes the shape of all cursors with a given name to the shape of another cursor.
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The cursor to take the shape from
+    /// * `name` - The name of the cursors to change
+    ///
+    /// # Panics
+    ///
+    /// If the name contains a nul character.
+    pub fn change_cursor_by_name(&self, source: &XCursor, name: impl AsRef<str>) {
+        let name = CString::new(name.as_ref()).unwrap();
+
+        unsafe {
+            xfixes_sys::XFixesChangeCursorByName(self.handle, source.handle(), name.as_ptr())
+        };
+    }
+
     /// Moves the mouse pointer to a specific position.
     ///
     /// # Arguments
@@ -461,12 +1310,7 @@ impl XDisplay {
     pub fn create_region(&self, rectangles: &[XRectangle]) -> XServerRegion {
         let mut rectangles = rectangles
             .iter()
-            .map(|r| xlib_sys::XRectangle {
-                x: r.x,
-                y: r.y,
-                width: r.width,
-                height: r.height,
-            })
+            .map(|&r| xlib_sys::XRectangle::from(r))
             .collect::<Vec<_>>();
 
         unsafe {
@@ -530,10 +1374,319 @@ impl XDisplay {
         self.xfixes_event_base
     }
 
+    /// Retrieves the event base id for damage events, or [`None`] if the damage extension is not
+    /// present on the X server.
+    #[cfg(feature = "damage")]
+    pub fn damage_event_base(&self) -> Option<i32> {
+        self.damage_event_base
+    }
+
+    /// Determines whether the MIT-SHM extension can be used with this display.
+    ///
+    /// This is only the case if the extension is present and the X server runs locally.
+    pub fn shm_available(&self) -> bool {
+        self.shm_pixmaps.is_some()
+    }
+
+    /// Determines whether the X server supports pixmaps backed by shared memory.
+    pub fn shm_pixmaps_available(&self) -> bool {
+        self.shm_pixmaps == Some(true)
+    }
+
+    /// Retrieves the XInput2 version negotiated with the X server.
+    ///
+    /// Returns [`None`] if the X server does not support XInput2 or no version has been
+    /// negotiated yet, see [`XDisplay::negotiate_xinput2`].
+    pub fn xinput2_version(&self) -> Option<(i32, i32)> {
+        self.xinput2_version
+    }
+
+    /// Queries information about XInput2 devices.
+    ///
+    /// # Arguments
+    ///
+    /// * `device` - The device to query, may be [`XInputDevice::all`] or
+    ///   [`XInputDevice::all_master`] to query multiple devices at once
+    ///
+    /// Returns an empty list if no XInput2 version has been negotiated with the X server.
+    pub fn query_devices(&self, device: &XInputDevice) -> Vec<XIDeviceInfo> {
+        if self.xinput2_version.is_none() {
+            return Vec::new();
+        }
+
+        let mut count = 0;
+        let infos = unsafe { xinput2_sys::XIQueryDevice(self.handle, device.id(), &mut count) };
+
+        if infos.is_null() {
+            return Vec::new();
+        }
+
+        let devices = unsafe { std::slice::from_raw_parts(infos, count as _) }
+            .iter()
+            .map(|info| unsafe { XIDeviceInfo::new(info, self) })
+            .collect();
+
+        unsafe { xinput2_sys::XIFreeDeviceInfo(infos) };
+
+        devices
+    }
+
+    /// Changes the XInput2 device hierarchy.
+    ///
+    /// All changes are applied in order within a single request. The X server reports the
+    /// result with a hierarchy event to all clients which selected for it.
+    ///
+    /// Returns `false` if the X server rejected the changes, for example because a device does
+    /// not exist or is of the wrong kind. Changes preceding the failing one may have been applied
+    /// already.
+    ///
+    /// # Arguments
+    ///
+    /// * `changes` - The changes to apply
+    ///
+    /// # Panics
+    ///
+    /// If the name of an added master device contains a nul byte.
+    pub fn change_hierarchy(&self, changes: &[XIHierarchyChange]) -> bool {
+        let mut names = Vec::new();
+        let mut native_changes = Vec::with_capacity(changes.len());
+
+        for change in changes {
+            let mut native = xinput2_sys::XIAnyHierarchyChangeInfo::default();
+
+            unsafe {
+                match change {
+                    XIHierarchyChange::AddMaster {
+                        name,
+                        send_core,
+                        enable,
+                    } => {
+                        let name = CString::new(name.as_str()).unwrap();
+
+                        *native.add() = xinput2_sys::XIAddMasterInfo {
+                            _type: xinput2_sys::XIAddMaster,
+                            name: name.as_ptr() as _,
+                            send_core: i32::from(*send_core),
+                            enable: i32::from(*enable),
+                        };
+
+                        // The string data stays in place when the vector reallocates
+                        names.push(name);
+                    }
+                    XIHierarchyChange::RemoveMaster {
+                        device,
+                        return_mode,
+                    } => {
+                        let (return_mode, return_pointer, return_keyboard) = match return_mode {
+                            XIReturnMode::Float => (xinput2_sys::XIFloating, 0, 0),
+                            XIReturnMode::AttachTo { pointer, keyboard } => {
+                                (xinput2_sys::XIAttachToMaster, pointer.id(), keyboard.id())
+                            }
+                        };
+
+                        *native.remove() = xinput2_sys::XIRemoveMasterInfo {
+                            _type: xinput2_sys::XIRemoveMaster,
+                            deviceid: device.id(),
+                            return_mode,
+                            return_pointer,
+                            return_keyboard,
+                        };
+                    }
+                    XIHierarchyChange::AttachSlave { device, master } => {
+                        *native.attach() = xinput2_sys::XIAttachSlaveInfo {
+                            _type: xinput2_sys::XIAttachSlave,
+                            deviceid: device.id(),
+                            new_master: master.id(),
+                        };
+                    }
+                    XIHierarchyChange::DetachSlave { device } => {
+                        *native.detach() = xinput2_sys::XIDetachSlaveInfo {
+                            _type: xinput2_sys::XIDetachSlave,
+                            deviceid: device.id(),
+                        };
+                    }
+                }
+            }
+
+            native_changes.push(native);
+        }
+
+        // The X server reports invalid changes asynchronously as an X error
+        let (status, error) = self.trap_errors(|| unsafe {
+            xinput2_sys::XIChangeHierarchy(
+                self.handle,
+                native_changes.as_mut_ptr(),
+                native_changes.len() as _,
+            )
+        });
+
+        status == xlib_sys::Success as i32 && error.is_none()
+    }
+
+    /// Sets the client pointer of a client.
+    ///
+    /// The client pointer is the master pointer used for core requests and events of a client
+    /// which do not specify a device.
+    ///
+    /// Returns `false` if the X server rejected the change, for example because the device is
+    /// not a master device or the window does not exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - A window owned by the client to change, or [`None`] for this client
+    /// * `device` - The master pointer (or its paired keyboard) to use as client pointer
+    pub fn set_client_pointer(&self, window: Option<&XWindow>, device: &XInputDevice) -> bool {
+        let window = window.map_or(0, XWindow::handle);
+
+        let (status, error) = self.trap_errors(|| unsafe {
+            xinput2_sys::XISetClientPointer(self.handle, window, device.id())
+        });
+
+        status == xlib_sys::Success as i32 && error.is_none()
+    }
+
+    /// Retrieves the client pointer of a client.
+    ///
+    /// Returns [`None`] if no client pointer has been set and the X server did not pick one.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - A window owned by the client to query, or [`None`] for this client
+    pub fn get_client_pointer(&self, window: Option<&XWindow>) -> Option<XInputDevice> {
+        let window = window.map_or(0, XWindow::handle);
+        let mut device = 0;
+
+        let found = unsafe { xinput2_sys::XIGetClientPointer(self.handle, window, &mut device) };
+
+        (found != 0).then(|| XInputDevice::from_id(device, self))
+    }
+
     /// Retrieves the opcode for the xinput2 extension.
     pub fn xinput2_opcode(&self) -> i32 {
         self.xinput2_opcode
     }
+
+    /// Looks up the picture format matching a visual.
+    ///
+    /// # Arguments
+    ///
+    /// * `visual` - The visual to look up the picture format for
+    pub fn find_visual_format(&self, visual: &XVisual) -> Option<XPictFormat> {
+        let format =
+            unsafe { xrender_sys::XRenderFindVisualFormat(self.handle(), visual.handle()) };
+
+        if format.is_null() {
+            None
+        } else {
+            Some(unsafe { XPictFormat::new(format) })
+        }
+    }
+
+    /// Looks up one of the standard picture formats.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The standard format to look up
+    pub fn find_standard_format(&self, format: PictStandardFormat) -> Option<XPictFormat> {
+        let format = unsafe { xrender_sys::XRenderFindStandardFormat(self.handle(), format as _) };
+
+        if format.is_null() {
+            None
+        } else {
+            Some(unsafe { XPictFormat::new(format) })
+        }
+    }
+
+    /// Creates a picture filled with a single color.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color of the picture
+    pub fn create_solid_fill(&self, color: XRenderColor) -> XPicture {
+        let color = color.to_native();
+
+        unsafe {
+            let picture = xrender_sys::XRenderCreateSolidFill(self.handle(), &color);
+
+            XPicture::new(picture, self)
+        }
+    }
+
+    /// Creates a picture filled with a linear gradient.
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - The point at which the gradient starts
+    /// * `end` - The point at which the gradient ends
+    /// * `stops` - The color stops of the gradient, sorted by offset
+    pub fn create_linear_gradient(
+        &self,
+        start: PictPoint,
+        end: PictPoint,
+        stops: &[PictGradientStop],
+    ) -> XPicture {
+        let gradient = xrender_sys::XLinearGradient {
+            p1: start.to_native(),
+            p2: end.to_native(),
+        };
+        let (offsets, colors) = gradient_stops_to_native(stops);
+
+        unsafe {
+            let picture = xrender_sys::XRenderCreateLinearGradient(
+                self.handle(),
+                &gradient,
+                offsets.as_ptr(),
+                colors.as_ptr(),
+                stops.len() as _,
+            );
+
+            XPicture::new(picture, self)
+        }
+    }
+
+    /// Creates a picture filled with a radial gradient between two circles.
+    ///
+    /// # Arguments
+    ///
+    /// * `inner_center` - The center of the circle at which the gradient starts
+    /// * `inner_radius` - The radius of the circle at which the gradient starts
+    /// * `outer_center` - The center of the circle at which the gradient ends
+    /// * `outer_radius` - The radius of the circle at which the gradient ends
+    /// * `stops` - The color stops of the gradient, sorted by offset
+    pub fn create_radial_gradient(
+        &self,
+        inner_center: PictPoint,
+        inner_radius: f64,
+        outer_center: PictPoint,
+        outer_radius: f64,
+        stops: &[PictGradientStop],
+    ) -> XPicture {
+        let gradient = xrender_sys::XRadialGradient {
+            inner: xrender_sys::XCircle {
+                x: to_fixed(inner_center.x),
+                y: to_fixed(inner_center.y),
+                radius: to_fixed(inner_radius),
+            },
+            outer: xrender_sys::XCircle {
+                x: to_fixed(outer_center.x),
+                y: to_fixed(outer_center.y),
+                radius: to_fixed(outer_radius),
+            },
+        };
+        let (offsets, colors) = gradient_stops_to_native(stops);
+
+        unsafe {
+            let picture = xrender_sys::XRenderCreateRadialGradient(
+                self.handle(),
+                &gradient,
+                offsets.as_ptr(),
+                colors.as_ptr(),
+                stops.len() as _,
+            );
+
+            XPicture::new(picture, self)
+        }
+    }
 }
 
 impl Drop for XDisplay {
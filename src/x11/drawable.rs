@@ -1,7 +1,11 @@
-use crate::xlib_sys;
 use crate::XDisplay;
-use crate::{XPixmap, XGC};
-use std::mem::MaybeUninit;
+#[cfg(feature = "damage")]
+use crate::{xdamage_sys, DamageReportLevel, XDamage};
+use crate::{xlib_sys, xrender_sys, xshm_sys};
+use crate::{
+    GCValues, PictureAttributes, RgbaImage, XImage, XImageFormat, XPictFormat, XPicture, XPixmap,
+    XRectangle, XShmImage, XTransferImage, XGC,
+};
 
 /// Describes the geometry of a [`XDrawable`].
 #[derive(Debug, Default)]
@@ -62,14 +66,23 @@ where
 
     /// Creates a new X11 graphics context for rendering to the drawable.
     fn create_gc(&'a self) -> XGC<Self> {
-        let mut values = MaybeUninit::uninit();
+        self.create_gc_with_values(GCValues::new())
+    }
+
+    /// Creates a new X11 graphics context with initial values for rendering to the drawable.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The initial values of the graphics context
+    fn create_gc_with_values(&'a self, values: GCValues) -> XGC<Self> {
+        let (mask, mut values) = values.into_native();
 
         let gc = unsafe {
             xlib_sys::XCreateGC(
                 self.display().handle(),
                 self.drawable_handle(),
-                0,
-                values.as_mut_ptr(),
+                mask,
+                &mut values,
             )
         };
 
@@ -104,4 +117,166 @@ where
 
         unsafe { XPixmap::new(pixmap, self.display()) }
     }
+
+    /// Starts tracking damage done to this drawable.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - How damage events are reported
+    #[cfg(feature = "damage")]
+    fn create_damage(&self, level: DamageReportLevel) -> XDamage<'a> {
+        let damage = unsafe {
+            xdamage_sys::XDamageCreate(self.display().handle(), self.drawable_handle(), level as _)
+        };
+
+        unsafe { XDamage::new(damage, self.display()) }
+    }
+
+    /// Creates a render picture for drawing to this drawable.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - The picture format, must match the depth and visual of the drawable
+    /// * `attributes` - The initial attributes of the picture
+    fn create_picture(&self, format: &XPictFormat, attributes: PictureAttributes) -> XPicture<'a> {
+        let (mask, attributes) = attributes.into_native();
+
+        let picture = unsafe {
+            xrender_sys::XRenderCreatePicture(
+                self.display().handle(),
+                self.drawable_handle(),
+                format.handle(),
+                mask,
+                &attributes,
+            )
+        };
+
+        unsafe { XPicture::new(picture, self.display()) }
+    }
+
+    /// Reads the contents of an area of this drawable into a new image.
+    ///
+    /// Returns [`None`] if the area does not lie completely within the drawable, or for windows,
+    /// if the window is not viewable or the area is not fully within the screen. The X error
+    /// raised in these cases is captured instead of aborting the process.
+    ///
+    /// # Arguments
+    ///
+    /// * `rectangle` - The area to read
+    /// * `plane_mask` - The planes to read
+    /// * `format` - The format of the image to create
+    fn get_image(
+        &self,
+        rectangle: XRectangle,
+        plane_mask: u64,
+        format: XImageFormat,
+    ) -> Option<XImage<'a>> {
+        let (image, error) = self.display().trap_errors(|| unsafe {
+            xlib_sys::XGetImage(
+                self.display().handle(),
+                self.drawable_handle(),
+                rectangle.x as _,
+                rectangle.y as _,
+                rectangle.width as _,
+                rectangle.height as _,
+                plane_mask as _,
+                format as _,
+            )
+        });
+
+        match (image.is_null(), error) {
+            (false, None) => Some(unsafe { XImage::new(image, self.display()) }),
+            (false, Some(_)) => {
+                unsafe { xlib_sys::XDestroyImage(image) };
+                None
+            }
+            (true, _) => None,
+        }
+    }
+
+    /// Captures the contents of an area of this drawable as RGBA pixels.
+    ///
+    /// Returns [`None`] under the same conditions as [`get_image`](Self::get_image). Note that
+    /// reading from a window only yields its visible contents, see
+    /// [`XWindow::capture_composited`](crate::XWindow::capture_composited) for capturing
+    /// obscured windows.
+    ///
+    /// # Arguments
+    ///
+    /// * `rectangle` - The area to capture
+    fn capture(&self, rectangle: XRectangle) -> Option<RgbaImage> {
+        self.get_image(rectangle, !0, XImageFormat::ZPixmap)
+            .map(|image| image.to_rgba8())
+    }
+
+    /// Reads the contents of this drawable into a shared memory image.
+    ///
+    /// The area read has the size of the image. Returns `false` under the same conditions as
+    /// [`get_image`](Self::get_image) returns [`None`].
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The image to read into
+    /// * `x` - The x coordinate of the area to read
+    /// * `y` - The y coordinate of the area to read
+    /// * `plane_mask` - The planes to read
+    fn get_shm_image(&self, image: &mut XShmImage, x: i32, y: i32, plane_mask: u64) -> bool {
+        let (status, error) = self.display().trap_errors(|| unsafe {
+            xshm_sys::XShmGetImage(
+                self.display().handle(),
+                self.drawable_handle(),
+                image.handle(),
+                x,
+                y,
+                plane_mask as _,
+            )
+        });
+
+        status != 0 && error.is_none()
+    }
+
+    /// Reads the contents of this drawable into a transfer image using the fastest available
+    /// path.
+    ///
+    /// The area read has the size of the image. Returns `false` under the same conditions as
+    /// [`get_image`](Self::get_image) returns [`None`].
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The image to read into
+    /// * `x` - The x coordinate of the area to read
+    /// * `y` - The y coordinate of the area to read
+    /// * `plane_mask` - The planes to read
+    fn get_transfer_image(
+        &self,
+        image: &mut XTransferImage,
+        x: i32,
+        y: i32,
+        plane_mask: u64,
+    ) -> bool {
+        match image {
+            XTransferImage::Shared(image) => self.get_shm_image(image, x, y, plane_mask),
+            XTransferImage::Plain(image) => {
+                let native = unsafe { &*image.handle() };
+
+                let (result, error) = self.display().trap_errors(|| unsafe {
+                    xlib_sys::XGetSubImage(
+                        self.display().handle(),
+                        self.drawable_handle(),
+                        x,
+                        y,
+                        native.width as _,
+                        native.height as _,
+                        plane_mask as _,
+                        native.format,
+                        image.handle(),
+                        0,
+                        0,
+                    )
+                });
+
+                !result.is_null() && error.is_none()
+            }
+        }
+    }
 }
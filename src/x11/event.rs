@@ -1,7 +1,9 @@
 use crate::x11::input::XInputDevice;
+#[cfg(feature = "damage")]
+use crate::{xdamage_sys, DamageReportLevel};
 use crate::{
     xfixes_sys, xinput2_sys, xlib_sys, ColormapHandleOwnership, ColormapState,
-    WindowHandleOwnership, XAtom, XColormap, XDisplay, XWindow,
+    WindowHandleOwnership, XAtom, XColormap, XDisplay, XRectangle, XWindow,
 };
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -522,6 +524,12 @@ pub enum XEventData<'a> {
     /// is set.
     CursorChanged(XDisplayCursorEvent<'a>),
 
+    /// A drawable tracked by an [`XDamage`][crate::XDamage] object has been damaged.
+    ///
+    /// Only generated when the damage extension is present on the X server.
+    #[cfg(feature = "damage")]
+    DamageNotify(XDamageNotifyEvent<'a>),
+
     /// The XInput2 hierarchy has changed.
     ///
     /// Only generated when [`XInputEventMask::HIERARCHY_CHANGED`][crate::XInputEventMask::HIERARCHY_CHANGED]
@@ -772,6 +780,17 @@ impl<'a> XEventData<'a> {
                     display,
                 ))
             }
+            #[cfg(feature = "damage")]
+            x if Some(x)
+                == display
+                    .damage_event_base()
+                    .map(|base| base + xdamage_sys::XDamageNotify) =>
+            {
+                let damage_event =
+                    *(&event as *const xlib_sys::XEvent as *const xdamage_sys::XDamageNotifyEvent);
+
+                Self::DamageNotify(XDamageNotifyEvent::new(damage_event, display))
+            }
             _ => Self::Unknown(event),
         }
     }
@@ -1446,6 +1465,16 @@ impl XExposeEvent {
         self.height
     }
 
+    /// Retrieves the exposed area as a rectangle.
+    pub fn rectangle(&self) -> XRectangle {
+        XRectangle {
+            x: self.x as _,
+            y: self.y as _,
+            width: self.width as _,
+            height: self.height as _,
+        }
+    }
+
     /// Retrieves the amount of expose events following this event.
     ///
     /// When it is desired to always redraw the full window, applications should ignore
@@ -2394,6 +2423,96 @@ impl<'a> XDisplayCursorEvent<'a> {
     pub fn cursor_name(&self) -> XAtom<'a> {
         self.cursor_name
     }
+
+    /// Retrieves the name of the cursor that changed as a string.
+    ///
+    /// Returns [`None`] if the cursor has no name.
+    pub fn cursor_name_string(&self) -> Option<String> {
+        if self.cursor_name.handle() == 0 {
+            None
+        } else {
+            Some(self.cursor_name.name())
+        }
+    }
+}
+
+#[cfg(feature = "damage")]
+#[derive(Debug)]
+pub struct XDamageNotifyEvent<'a> {
+    drawable: xlib_sys::Drawable,
+    damage: xdamage_sys::Damage,
+    level: DamageReportLevel,
+    more: bool,
+    timestamp: u64,
+    area: XRectangle,
+    geometry: XRectangle,
+    display: &'a XDisplay,
+}
+
+#[cfg(feature = "damage")]
+impl<'a> XDamageNotifyEvent<'a> {
+    /// Converts the X damage notify event data from its native representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The X native event
+    /// * `display` - The display the event occurred on
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure all arguments are valid.
+    pub unsafe fn new(event: xdamage_sys::XDamageNotifyEvent, display: &'a XDisplay) -> Self {
+        Self {
+            drawable: event.drawable,
+            damage: event.damage,
+            level: DamageReportLevel::new(event.level),
+            more: event.more != 0,
+            timestamp: event.timestamp as _,
+            area: XRectangle::from(event.area),
+            geometry: XRectangle::from(event.geometry),
+            display,
+        }
+    }
+
+    /// Retrieves the native X11 drawable which has been damaged.
+    pub fn drawable(&self) -> xlib_sys::Drawable {
+        self.drawable
+    }
+
+    /// Retrieves the native X11 damage object which reported the damage.
+    pub fn damage(&self) -> xdamage_sys::Damage {
+        self.damage
+    }
+
+    /// Retrieves the report level of the damage object which reported the damage.
+    pub fn level(&self) -> DamageReportLevel {
+        self.level
+    }
+
+    /// Determines whether more damage events for the same damage object follow this event.
+    pub fn more(&self) -> bool {
+        self.more
+    }
+
+    /// Retrieves the timestamp this event occurred at.
+    pub fn time(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// Retrieves the damaged area relative to the drawable.
+    pub fn area(&self) -> XRectangle {
+        self.area
+    }
+
+    /// Retrieves the geometry of the drawable at the time the damage occurred.
+    pub fn geometry(&self) -> XRectangle {
+        self.geometry
+    }
+
+    /// Retrieves the display the event occurred on.
+    pub fn display(&self) -> &'a XDisplay {
+        self.display
+    }
 }
 
 bitflags::bitflags! {
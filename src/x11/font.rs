@@ -1,4 +1,6 @@
-use crate::{xlib_sys, XDisplay};
+use crate::{xlib_sys, XAtom, XDisplay, XRectangle};
+use std::ffi::{CStr, CString};
+use std::fmt::{Display, Formatter};
 use std::mem::MaybeUninit;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -227,6 +229,119 @@ impl<'a> XFont<'a> {
 
         XTextExtents::new(direction, font_ascent, font_descent, overall)
     }
+
+    /// Retrieves the metadata of the font.
+    pub fn info(&self) -> XFontInfo<'a> {
+        unsafe { XFontInfo::from_native(&*self.handle, self.display) }
+    }
+
+    /// Retrieves the smallest metrics of all characters in the font.
+    pub fn min_bounds(&self) -> XChar {
+        XChar::new(unsafe { &*self.handle }.min_bounds)
+    }
+
+    /// Retrieves the largest metrics of all characters in the font.
+    pub fn max_bounds(&self) -> XChar {
+        XChar::new(unsafe { &*self.handle }.max_bounds)
+    }
+
+    /// Retrieves the metrics of a single character without querying the X server.
+    ///
+    /// Returns [`None`] if the font does not contain the character. For fonts with 2 byte
+    /// encodings the first byte is stored in bits 8 to 15 of `c`.
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - The encoded character to look up
+    pub fn char_metrics(&self, c: u32) -> Option<XChar> {
+        let font = unsafe { &*self.handle };
+
+        let byte1 = c >> 8;
+        let byte2 = c & 0xFF;
+
+        let index = if font.min_byte1 == 0 && font.max_byte1 == 0 {
+            if c < font.min_char_or_byte2 || c > font.max_char_or_byte2 {
+                return None;
+            }
+
+            c - font.min_char_or_byte2
+        } else {
+            if byte1 < font.min_byte1
+                || byte1 > font.max_byte1
+                || byte2 < font.min_char_or_byte2
+                || byte2 > font.max_char_or_byte2
+            {
+                return None;
+            }
+
+            let columns = font.max_char_or_byte2 - font.min_char_or_byte2 + 1;
+            (byte1 - font.min_byte1) * columns + (byte2 - font.min_char_or_byte2)
+        };
+
+        // Fonts without per character metrics use the same metrics for all characters
+        if font.per_char.is_null() {
+            return Some(XChar::new(font.max_bounds));
+        }
+
+        let metrics = unsafe { *font.per_char.add(index as usize) };
+
+        // Characters which do not exist in the font have all metrics set to zero
+        if metrics.lbearing == 0
+            && metrics.rbearing == 0
+            && metrics.width == 0
+            && metrics.ascent == 0
+            && metrics.descent == 0
+        {
+            None
+        } else {
+            Some(XChar::new(metrics))
+        }
+    }
+
+    /// Retrieves the raw value of a font property.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the property, for example `PIXEL_SIZE`
+    pub fn property(&self, name: &XAtom) -> Option<u64> {
+        let mut value = 0;
+
+        if unsafe { xlib_sys::XGetFontProperty(self.handle, name.handle(), &mut value) } != 0 {
+            Some(value as _)
+        } else {
+            None
+        }
+    }
+
+    /// Retrieves the value of a font property holding a string, for example `FAMILY_NAME`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the property
+    ///
+    /// Returns [`None`] if the property is not set or does not hold a string.
+    pub fn string_property(&self, name: &XAtom) -> Option<String> {
+        let value = self.property(name).filter(|&value| value != 0)?;
+
+        // Properties holding numbers, for example `PIXEL_SIZE`, raise BadAtom
+        let (raw_name, error) = self
+            .display
+            .trap_errors(|| unsafe { xlib_sys::XGetAtomName(self.display.handle(), value as _) });
+
+        if raw_name.is_null() {
+            return None;
+        }
+
+        let name = error.is_none().then(|| {
+            unsafe { CStr::from_ptr(raw_name) }
+                .to_string_lossy()
+                .into_owned()
+        });
+
+        unsafe { xlib_sys::XFree(raw_name as _) };
+
+        name
+    }
 }
 
 impl<'a> Drop for XFont<'a> {
@@ -236,3 +351,418 @@ impl<'a> Drop for XFont<'a> {
         }
     }
 }
+
+/// Metadata of an X11 font.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct XFontInfo<'a> {
+    /// The direction the font is drawn in
+    pub direction: XFontDirection,
+
+    /// The first character, or the first column for 2 byte encodings
+    pub min_char_or_byte2: u32,
+
+    /// The last character, or the last column for 2 byte encodings
+    pub max_char_or_byte2: u32,
+
+    /// The first row for 2 byte encodings
+    pub min_byte1: u32,
+
+    /// The last row for 2 byte encodings
+    pub max_byte1: u32,
+
+    /// Whether all characters between the first and last character exist
+    pub all_chars_exist: bool,
+
+    /// The character drawn in place of characters which do not exist
+    pub default_char: u32,
+
+    /// The smallest metrics of all characters
+    pub min_bounds: XChar,
+
+    /// The largest metrics of all characters
+    pub max_bounds: XChar,
+
+    /// The logical extent of the font above the baseline
+    pub ascent: i32,
+
+    /// The logical extent of the font below the baseline
+    pub descent: i32,
+
+    /// The properties of the font along with their raw values
+    pub properties: Vec<(XAtom<'a>, u64)>,
+}
+
+impl<'a> XFontInfo<'a> {
+    /// Copies the metadata of a native X11 font.
+    ///
+    /// # Arguments
+    ///
+    /// * `native` - The native X11 font
+    /// * `display` - The display the font belongs to
+    ///
+    /// # Safety
+    ///
+    /// It is up to the caller to ensure all arguments are valid.
+    pub unsafe fn from_native(native: &xlib_sys::XFontStruct, display: &'a XDisplay) -> Self {
+        let properties = if native.properties.is_null() {
+            Vec::new()
+        } else {
+            std::slice::from_raw_parts(native.properties, native.n_properties as _)
+                .iter()
+                .map(|prop| (XAtom::new(prop.name, display), prop.card32 as _))
+                .collect()
+        };
+
+        Self {
+            direction: XFontDirection::from_raw(native.direction as _),
+            min_char_or_byte2: native.min_char_or_byte2,
+            max_char_or_byte2: native.max_char_or_byte2,
+            min_byte1: native.min_byte1,
+            max_byte1: native.max_byte1,
+            all_chars_exist: native.all_chars_exist != 0,
+            default_char: native.default_char,
+            min_bounds: XChar::new(native.min_bounds),
+            max_bounds: XChar::new(native.max_bounds),
+            ascent: native.ascent,
+            descent: native.descent,
+            properties,
+        }
+    }
+
+    /// Retrieves the raw value of a font property.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the property
+    pub fn property(&self, name: &XAtom) -> Option<u64> {
+        self.properties
+            .iter()
+            .find(|(atom, _)| atom == name)
+            .map(|(_, value)| *value)
+    }
+}
+
+/// A font name in the X Logical Font Description format.
+///
+/// XLFD names consist of 14 fields separated by dashes, for example
+/// `-misc-fixed-medium-r-normal--13-120-75-75-c-70-iso10646-1`. Wildcards and unspecified
+/// numeric fields are represented as `*` and [`None`] respectively.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct XlfdName {
+    pub foundry: String,
+    pub family: String,
+    pub weight: String,
+    pub slant: String,
+    pub set_width: String,
+    pub add_style: String,
+    pub pixel_size: Option<u32>,
+    pub point_size: Option<u32>,
+    pub resolution_x: Option<u32>,
+    pub resolution_y: Option<u32>,
+    pub spacing: String,
+    pub average_width: Option<u32>,
+    pub charset_registry: String,
+    pub charset_encoding: String,
+}
+
+impl XlfdName {
+    /// Parses an XLFD name.
+    ///
+    /// Returns [`None`] if the name is not a well-formed XLFD name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name to parse
+    pub fn parse(name: &str) -> Option<Self> {
+        let fields = name.strip_prefix('-')?.split('-').collect::<Vec<_>>();
+
+        if fields.len() != 14 {
+            return None;
+        }
+
+        let numeric = |field: &str| match field {
+            "*" | "" => Some(None),
+            value => value.parse().ok().map(Some),
+        };
+
+        Some(Self {
+            foundry: fields[0].into(),
+            family: fields[1].into(),
+            weight: fields[2].into(),
+            slant: fields[3].into(),
+            set_width: fields[4].into(),
+            add_style: fields[5].into(),
+            pixel_size: numeric(fields[6])?,
+            point_size: numeric(fields[7])?,
+            resolution_x: numeric(fields[8])?,
+            resolution_y: numeric(fields[9])?,
+            spacing: fields[10].into(),
+            average_width: numeric(fields[11])?,
+            charset_registry: fields[12].into(),
+            charset_encoding: fields[13].into(),
+        })
+    }
+
+    /// Determines whether the font is scalable, which is indicated by a size of 0.
+    pub fn is_scalable(&self) -> bool {
+        self.pixel_size == Some(0) && self.point_size == Some(0) && self.average_width == Some(0)
+    }
+}
+
+impl Display for XlfdName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let numeric = |value: Option<u32>| value.map(|v| v.to_string()).unwrap_or("*".into());
+
+        write!(
+            f,
+            "-{}-{}-{}-{}-{}-{}-{}-{}-{}-{}-{}-{}-{}-{}",
+            self.foundry,
+            self.family,
+            self.weight,
+            self.slant,
+            self.set_width,
+            self.add_style,
+            numeric(self.pixel_size),
+            numeric(self.point_size),
+            numeric(self.resolution_x),
+            numeric(self.resolution_y),
+            self.spacing,
+            numeric(self.average_width),
+            self.charset_registry,
+            self.charset_encoding
+        )
+    }
+}
+
+/// Ink and logical extents of a text drawn with a font set.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct XFontSetExtents {
+    /// The bounding box of the pixels actually drawn
+    pub ink: XRectangle,
+
+    /// The bounding box including the spacing to adjacent text
+    pub logical: XRectangle,
+}
+
+/// X11 font set.
+///
+/// A font set combines multiple fonts in order to cover all characters required by the current
+/// locale, which allows drawing UTF-8 text.
+#[derive(Debug)]
+pub struct XFontSet<'a> {
+    handle: xlib_sys::XFontSet,
+    missing_charsets: Vec<String>,
+    display: &'a XDisplay,
+}
+
+impl<'a> XFontSet<'a> {
+    /// Initializes the locale of the process from the environment for use with font sets.
+    ///
+    /// This needs to be called before creating font sets, as Xlib selects the fonts based on
+    /// the current locale. Returns `false` if Xlib does not support the locale, in which case
+    /// only the "C" locale is used.
+    ///
+    /// # Safety
+    ///
+    /// Changing the locale is not thread safe and races with any concurrent use of the locale
+    /// or the environment. This function must be called before any other threads are started.
+    pub unsafe fn init_locale() -> bool {
+        let empty = CString::new("").unwrap();
+        let fallback = CString::new("C").unwrap();
+
+        libc::setlocale(libc::LC_CTYPE, empty.as_ptr());
+
+        if xlib_sys::XSupportsLocale() == 0 {
+            libc::setlocale(libc::LC_CTYPE, fallback.as_ptr());
+            return false;
+        }
+
+        xlib_sys::XSetLocaleModifiers(empty.as_ptr());
+
+        true
+    }
+
+    /// Wraps an existing X11 font set.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The X11 font set to wrap
+    /// * `missing_charsets` - The charsets no font could be found for
+    /// * `display` - The display the font set belongs to
+    ///
+    /// # Safety
+    ///
+    /// It is up to the caller to ensure all arguments are valid.
+    pub unsafe fn new(
+        handle: xlib_sys::XFontSet,
+        missing_charsets: Vec<String>,
+        display: &'a XDisplay,
+    ) -> Self {
+        Self {
+            handle,
+            missing_charsets,
+            display,
+        }
+    }
+
+    /// Retrieves the underlying native X11 font set handle.
+    pub fn handle(&self) -> xlib_sys::XFontSet {
+        self.handle
+    }
+
+    /// Retrieves the charsets of the locale no font could be found for.
+    ///
+    /// Characters of these charsets are drawn using the default string of the font set.
+    pub fn missing_charsets(&self) -> &[String] {
+        &self.missing_charsets
+    }
+
+    /// Retrieves the comma separated list of base font names the font set was created from.
+    pub fn base_font_names(&self) -> String {
+        unsafe { CStr::from_ptr(xlib_sys::XBaseFontNameListOfFontSet(self.handle)) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    /// Retrieves the name of the locale the font set is bound to.
+    pub fn locale(&self) -> String {
+        unsafe { CStr::from_ptr(xlib_sys::XLocaleOfFontSet(self.handle)) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    /// Retrieves the maximum extents of any character in the font set.
+    pub fn max_extents(&self) -> XFontSetExtents {
+        let extents = unsafe { &*xlib_sys::XExtentsOfFontSet(self.handle) };
+
+        XFontSetExtents {
+            ink: extents.max_ink_extent.into(),
+            logical: extents.max_logical_extent.into(),
+        }
+    }
+
+    /// Calculates the extents of the given UTF-8 text.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to calculate the extents for
+    pub fn text_extents(&self, text: impl AsRef<str>) -> XFontSetExtents {
+        let text_bytes = text.as_ref().as_bytes();
+
+        let mut ink = MaybeUninit::uninit();
+        let mut logical = MaybeUninit::uninit();
+
+        unsafe {
+            xlib_sys::Xutf8TextExtents(
+                self.handle,
+                text_bytes.as_ptr() as _,
+                text_bytes.len() as _,
+                ink.as_mut_ptr(),
+                logical.as_mut_ptr(),
+            );
+
+            XFontSetExtents {
+                ink: ink.assume_init().into(),
+                logical: logical.assume_init().into(),
+            }
+        }
+    }
+}
+
+impl<'a> Drop for XFontSet<'a> {
+    fn drop(&mut self) {
+        unsafe { xlib_sys::XFreeFontSet(self.display.handle(), self.handle) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXED: &str = "-misc-fixed-medium-r-normal--13-120-75-75-c-70-iso10646-1";
+
+    #[test]
+    fn parses_all_fields() {
+        let name = XlfdName::parse(FIXED).unwrap();
+
+        assert_eq!(name.foundry, "misc");
+        assert_eq!(name.family, "fixed");
+        assert_eq!(name.weight, "medium");
+        assert_eq!(name.slant, "r");
+        assert_eq!(name.set_width, "normal");
+        assert_eq!(name.add_style, "");
+        assert_eq!(name.pixel_size, Some(13));
+        assert_eq!(name.point_size, Some(120));
+        assert_eq!(name.resolution_x, Some(75));
+        assert_eq!(name.resolution_y, Some(75));
+        assert_eq!(name.spacing, "c");
+        assert_eq!(name.average_width, Some(70));
+        assert_eq!(name.charset_registry, "iso10646");
+        assert_eq!(name.charset_encoding, "1");
+        assert!(!name.is_scalable());
+    }
+
+    #[test]
+    fn formats_back_to_the_parsed_name() {
+        assert_eq!(XlfdName::parse(FIXED).unwrap().to_string(), FIXED);
+    }
+
+    #[test]
+    fn wildcards_are_unspecified_numbers() {
+        let name = XlfdName::parse("-*-*-*-*-*-*-*-*-*-*-*-*-*-*").unwrap();
+
+        assert_eq!(name.family, "*");
+        assert_eq!(name.pixel_size, None);
+        assert_eq!(name.average_width, None);
+        assert_eq!(name.to_string(), "-*-*-*-*-*-*-*-*-*-*-*-*-*-*");
+    }
+
+    #[test]
+    fn empty_numeric_fields_are_formatted_as_wildcards() {
+        let name =
+            XlfdName::parse("-misc-fixed-medium-r-normal---120-75-75-c--iso10646-1").unwrap();
+
+        assert_eq!(name.pixel_size, None);
+        assert_eq!(name.average_width, None);
+        assert_eq!(
+            name.to_string(),
+            "-misc-fixed-medium-r-normal--*-120-75-75-c-*-iso10646-1"
+        );
+    }
+
+    #[test]
+    fn empty_string_fields_are_kept() {
+        let name = XlfdName::parse("--------------").unwrap();
+
+        assert_eq!(name.foundry, "");
+        assert_eq!(name.spacing, "");
+        assert_eq!(name.to_string(), "-------*-*-*-*--*--");
+    }
+
+    #[test]
+    fn scalable_fonts_have_zero_sizes() {
+        let name =
+            XlfdName::parse("-adobe-helvetica-bold-o-normal--0-0-0-0-p-0-iso8859-1").unwrap();
+
+        assert!(name.is_scalable());
+    }
+
+    #[test]
+    fn rejects_malformed_names() {
+        assert_eq!(XlfdName::parse(""), None);
+        assert_eq!(XlfdName::parse("fixed"), None);
+        assert_eq!(XlfdName::parse(&FIXED[1..]), None);
+        assert_eq!(XlfdName::parse(&format!("{}-extra", FIXED)), None);
+        assert_eq!(
+            XlfdName::parse("-misc-fixed-medium-r-normal--large-120-75-75-c-70-iso10646-1"),
+            None
+        );
+    }
+
+    #[test]
+    fn default_name_round_trips() {
+        let name = XlfdName::default();
+
+        assert_eq!(XlfdName::parse(&name.to_string()), Some(name));
+    }
+}
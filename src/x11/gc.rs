@@ -1,6 +1,492 @@
-use crate::{xlib_sys, XFont, XImage};
+use crate::{
+    xfixes_sys, xlib_sys, xshm_sys, Pixel, XFont, XFontSet, XImage, XPixmap, XRectangle,
+    XServerRegion, XShmImage, XTransferImage,
+};
 use crate::{XDisplay, XDrawable};
 
+/// Logical operation used to combine source and destination pixels.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum GCFunction {
+    Clear = xlib_sys::GXclear,
+    And = xlib_sys::GXand,
+    AndReverse = xlib_sys::GXandReverse,
+    Copy = xlib_sys::GXcopy,
+    AndInverted = xlib_sys::GXandInverted,
+    Noop = xlib_sys::GXnoop,
+    Xor = xlib_sys::GXxor,
+    Or = xlib_sys::GXor,
+    Nor = xlib_sys::GXnor,
+    Equiv = xlib_sys::GXequiv,
+    Invert = xlib_sys::GXinvert,
+    OrReverse = xlib_sys::GXorReverse,
+    CopyInverted = xlib_sys::GXcopyInverted,
+    OrInverted = xlib_sys::GXorInverted,
+    Nand = xlib_sys::GXnand,
+    Set = xlib_sys::GXset,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum LineStyle {
+    /// The full path of the line is drawn
+    Solid = xlib_sys::LineSolid,
+
+    /// Only the even dashes are drawn
+    OnOffDash = xlib_sys::LineOnOffDash,
+
+    /// Even dashes are drawn with the foreground and odd dashes with the background
+    DoubleDash = xlib_sys::LineDoubleDash,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum CapStyle {
+    /// Like [`CapStyle::Butt`], but the final endpoint is not drawn for zero width lines
+    NotLast = xlib_sys::CapNotLast,
+
+    /// The line is square at the endpoint with no projection beyond
+    Butt = xlib_sys::CapButt,
+
+    /// The line has a circular arc at the endpoint
+    Round = xlib_sys::CapRound,
+
+    /// The line is square at the endpoint, projecting by half the line width
+    Projecting = xlib_sys::CapProjecting,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum JoinStyle {
+    /// The outer edges of the lines extend to meet at an angle
+    Miter = xlib_sys::JoinMiter,
+
+    /// Lines are joined with a circular arc
+    Round = xlib_sys::JoinRound,
+
+    /// Lines are joined with a triangular notch filled
+    Bevel = xlib_sys::JoinBevel,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum FillStyle {
+    /// Fill with the foreground color
+    Solid = xlib_sys::FillSolid,
+
+    /// Fill with the tile pixmap
+    Tiled = xlib_sys::FillTiled,
+
+    /// Fill with the foreground color masked by the stipple bitmap
+    Stippled = xlib_sys::FillStippled,
+
+    /// Fill with the foreground and background color as selected by the stipple bitmap
+    OpaqueStippled = xlib_sys::FillOpaqueStippled,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum FillRule {
+    /// A point is inside if an infinite ray from it crosses the path an odd number of times
+    EvenOdd = xlib_sys::EvenOddRule,
+
+    /// A point is inside if the path winds around it a non-zero number of times
+    Winding = xlib_sys::WindingRule,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum ArcMode {
+    /// Filled arcs are closed by a line between their endpoints
+    Chord = xlib_sys::ArcChord,
+
+    /// Filled arcs are closed by lines from their endpoints to their center
+    PieSlice = xlib_sys::ArcPieSlice,
+}
+
+/// Determines whether child windows clip drawing operations on a window.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum SubwindowMode {
+    /// Drawing is clipped by child windows
+    ClipByChildren = xlib_sys::ClipByChildren,
+
+    /// Drawing includes the area covered by child windows
+    IncludeInferiors = xlib_sys::IncludeInferiors,
+}
+
+/// Determines how the coordinates of multiple points are interpreted.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum CoordinateMode {
+    /// All points are relative to the origin of the drawable
+    Origin = xlib_sys::CoordModeOrigin,
+
+    /// All points except the first one are relative to the previous point
+    Previous = xlib_sys::CoordModePrevious,
+}
+
+/// Hint about the shape of a polygon, allowing the X server to optimize filling it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum PolygonShape {
+    /// The path may intersect itself
+    Complex = xlib_sys::Complex,
+
+    /// The path does not intersect itself, but the polygon is not convex
+    Nonconvex = xlib_sys::Nonconvex,
+
+    /// The path does not intersect itself and the polygon is convex
+    Convex = xlib_sys::Convex,
+}
+
+/// Describes the order of clip rectangles, allowing the X server to optimize clipping.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum ClipOrdering {
+    /// The rectangles are in no particular order
+    Unsorted = xlib_sys::Unsorted,
+
+    /// The rectangles are sorted by their y origin
+    YSorted = xlib_sys::YSorted,
+
+    /// The rectangles are sorted by their y origin and then by their x origin
+    YXSorted = xlib_sys::YXSorted,
+
+    /// Like [`ClipOrdering::YXSorted`], with rectangles in the same band sharing their height
+    YXBanded = xlib_sys::YXBanded,
+}
+
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct XPoint {
+    pub x: i16,
+    pub y: i16,
+}
+
+impl From<XPoint> for xlib_sys::XPoint {
+    fn from(value: XPoint) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct XSegment {
+    pub x1: i16,
+    pub y1: i16,
+    pub x2: i16,
+    pub y2: i16,
+}
+
+impl From<XSegment> for xlib_sys::XSegment {
+    fn from(value: XSegment) -> Self {
+        Self {
+            x1: value.x1,
+            y1: value.y1,
+            x2: value.x2,
+            y2: value.y2,
+        }
+    }
+}
+
+/// An arc within a bounding rectangle.
+///
+/// Angles are specified in 1/64 of a degree, starting at the 3 o'clock position and going
+/// counter-clockwise.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct XArc {
+    pub x: i16,
+    pub y: i16,
+    pub width: u16,
+    pub height: u16,
+    pub angle1: i16,
+    pub angle2: i16,
+}
+
+impl From<XArc> for xlib_sys::XArc {
+    fn from(value: XArc) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+            width: value.width,
+            height: value.height,
+            angle1: value.angle1,
+            angle2: value.angle2,
+        }
+    }
+}
+
+/// Values of a graphics context, used when creating or changing a graphics context.
+#[derive(Debug, Default, Clone)]
+pub struct GCValues<'creation, 'a> {
+    function: Option<GCFunction>,
+    plane_mask: Option<u64>,
+    foreground: Option<u64>,
+    background: Option<u64>,
+    line_width: Option<u32>,
+    line_style: Option<LineStyle>,
+    cap_style: Option<CapStyle>,
+    join_style: Option<JoinStyle>,
+    fill_style: Option<FillStyle>,
+    fill_rule: Option<FillRule>,
+    arc_mode: Option<ArcMode>,
+    tile: Option<&'creation XPixmap<'a>>,
+    stipple: Option<&'creation XPixmap<'a>>,
+    tile_stipple_origin: Option<(i32, i32)>,
+    font: Option<&'creation XFont<'a>>,
+    subwindow_mode: Option<SubwindowMode>,
+    graphics_exposures: Option<bool>,
+    clip_origin: Option<(i32, i32)>,
+    clip_mask: Option<Option<&'creation XPixmap<'a>>>,
+    dashes: Option<(i32, u8)>,
+}
+
+impl<'creation, 'a> GCValues<'creation, 'a> {
+    /// Creates a new set of graphics context values without any set.
+    pub fn new() -> Self {
+        GCValues::default()
+    }
+
+    /// Sets the logical operation used when drawing.
+    pub fn function(&mut self, function: GCFunction) -> &mut Self {
+        self.function = Some(function);
+        self
+    }
+
+    /// Sets the planes drawing operations apply to.
+    pub fn plane_mask(&mut self, plane_mask: u64) -> &mut Self {
+        self.plane_mask = Some(plane_mask);
+        self
+    }
+
+    /// Sets the foreground pixel value.
+    pub fn foreground(&mut self, foreground: impl Into<Pixel>) -> &mut Self {
+        self.foreground = Some(foreground.into().value());
+        self
+    }
+
+    /// Sets the background pixel value.
+    pub fn background(&mut self, background: impl Into<Pixel>) -> &mut Self {
+        self.background = Some(background.into().value());
+        self
+    }
+
+    /// Sets the width of lines in pixels, 0 selects fast thin lines.
+    pub fn line_width(&mut self, line_width: u32) -> &mut Self {
+        self.line_width = Some(line_width);
+        self
+    }
+
+    /// Sets how lines are dashed.
+    pub fn line_style(&mut self, line_style: LineStyle) -> &mut Self {
+        self.line_style = Some(line_style);
+        self
+    }
+
+    /// Sets how the endpoints of lines are drawn.
+    pub fn cap_style(&mut self, cap_style: CapStyle) -> &mut Self {
+        self.cap_style = Some(cap_style);
+        self
+    }
+
+    /// Sets how corners between connected lines are drawn.
+    pub fn join_style(&mut self, join_style: JoinStyle) -> &mut Self {
+        self.join_style = Some(join_style);
+        self
+    }
+
+    /// Sets how areas are filled.
+    pub fn fill_style(&mut self, fill_style: FillStyle) -> &mut Self {
+        self.fill_style = Some(fill_style);
+        self
+    }
+
+    /// Sets which points of a polygon are considered to be inside.
+    pub fn fill_rule(&mut self, fill_rule: FillRule) -> &mut Self {
+        self.fill_rule = Some(fill_rule);
+        self
+    }
+
+    /// Sets how filled arcs are closed.
+    pub fn arc_mode(&mut self, arc_mode: ArcMode) -> &mut Self {
+        self.arc_mode = Some(arc_mode);
+        self
+    }
+
+    /// Sets the pixmap used for [`FillStyle::Tiled`].
+    pub fn tile(&mut self, tile: &'creation XPixmap<'a>) -> &mut Self {
+        self.tile = Some(tile);
+        self
+    }
+
+    /// Sets the bitmap used for [`FillStyle::Stippled`] and [`FillStyle::OpaqueStippled`].
+    pub fn stipple(&mut self, stipple: &'creation XPixmap<'a>) -> &mut Self {
+        self.stipple = Some(stipple);
+        self
+    }
+
+    /// Sets the origin of the tile and stipple.
+    pub fn tile_stipple_origin(&mut self, x: i32, y: i32) -> &mut Self {
+        self.tile_stipple_origin = Some((x, y));
+        self
+    }
+
+    /// Sets the font used for drawing text.
+    pub fn font(&mut self, font: &'creation XFont<'a>) -> &mut Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Sets whether child windows clip drawing operations.
+    pub fn subwindow_mode(&mut self, mode: SubwindowMode) -> &mut Self {
+        self.subwindow_mode = Some(mode);
+        self
+    }
+
+    /// Sets whether graphics exposure events are generated.
+    pub fn graphics_exposures(&mut self, graphics_exposures: bool) -> &mut Self {
+        self.graphics_exposures = Some(graphics_exposures);
+        self
+    }
+
+    /// Sets the origin of the clip mask.
+    pub fn clip_origin(&mut self, x: i32, y: i32) -> &mut Self {
+        self.clip_origin = Some((x, y));
+        self
+    }
+
+    /// Sets the bitmap used for clipping, or [`None`] to disable clipping.
+    pub fn clip_mask(&mut self, clip_mask: Option<&'creation XPixmap<'a>>) -> &mut Self {
+        self.clip_mask = Some(clip_mask);
+        self
+    }
+
+    /// Sets dashes of equal length for dashed lines, see [`XGC::set_dashes`] for other patterns.
+    ///
+    /// # Panics
+    ///
+    /// If `length` is zero.
+    pub fn dashes(&mut self, offset: i32, length: u8) -> &mut Self {
+        assert_ne!(length, 0, "Dashes must not have a length of zero");
+
+        self.dashes = Some((offset, length));
+        self
+    }
+
+    /// Turns this struct into its native representation along with the associated value mask.
+    pub fn into_native(self) -> (u64, xlib_sys::XGCValues) {
+        let mut mask = 0;
+        let mut native = unsafe { std::mem::zeroed::<xlib_sys::XGCValues>() };
+
+        if let Some(function) = self.function {
+            native.function = function as _;
+            mask |= xlib_sys::GCFunction;
+        }
+
+        if let Some(plane_mask) = self.plane_mask {
+            native.plane_mask = plane_mask;
+            mask |= xlib_sys::GCPlaneMask;
+        }
+
+        if let Some(foreground) = self.foreground {
+            native.foreground = foreground;
+            mask |= xlib_sys::GCForeground;
+        }
+
+        if let Some(background) = self.background {
+            native.background = background;
+            mask |= xlib_sys::GCBackground;
+        }
+
+        if let Some(line_width) = self.line_width {
+            native.line_width = line_width as _;
+            mask |= xlib_sys::GCLineWidth;
+        }
+
+        if let Some(line_style) = self.line_style {
+            native.line_style = line_style as _;
+            mask |= xlib_sys::GCLineStyle;
+        }
+
+        if let Some(cap_style) = self.cap_style {
+            native.cap_style = cap_style as _;
+            mask |= xlib_sys::GCCapStyle;
+        }
+
+        if let Some(join_style) = self.join_style {
+            native.join_style = join_style as _;
+            mask |= xlib_sys::GCJoinStyle;
+        }
+
+        if let Some(fill_style) = self.fill_style {
+            native.fill_style = fill_style as _;
+            mask |= xlib_sys::GCFillStyle;
+        }
+
+        if let Some(fill_rule) = self.fill_rule {
+            native.fill_rule = fill_rule as _;
+            mask |= xlib_sys::GCFillRule;
+        }
+
+        if let Some(arc_mode) = self.arc_mode {
+            native.arc_mode = arc_mode as _;
+            mask |= xlib_sys::GCArcMode;
+        }
+
+        if let Some(tile) = self.tile {
+            native.tile = tile.handle();
+            mask |= xlib_sys::GCTile;
+        }
+
+        if let Some(stipple) = self.stipple {
+            native.stipple = stipple.handle();
+            mask |= xlib_sys::GCStipple;
+        }
+
+        if let Some((x, y)) = self.tile_stipple_origin {
+            native.ts_x_origin = x;
+            native.ts_y_origin = y;
+            mask |= xlib_sys::GCTileStipXOrigin | xlib_sys::GCTileStipYOrigin;
+        }
+
+        if let Some(font) = self.font {
+            native.font = font.id().0;
+            mask |= xlib_sys::GCFont;
+        }
+
+        if let Some(mode) = self.subwindow_mode {
+            native.subwindow_mode = mode as _;
+            mask |= xlib_sys::GCSubwindowMode;
+        }
+
+        if let Some(graphics_exposures) = self.graphics_exposures {
+            native.graphics_exposures = graphics_exposures as _;
+            mask |= xlib_sys::GCGraphicsExposures;
+        }
+
+        if let Some((x, y)) = self.clip_origin {
+            native.clip_x_origin = x;
+            native.clip_y_origin = y;
+            mask |= xlib_sys::GCClipXOrigin | xlib_sys::GCClipYOrigin;
+        }
+
+        if let Some(clip_mask) = self.clip_mask {
+            native.clip_mask = clip_mask.map(|m| m.handle()).unwrap_or(0);
+            mask |= xlib_sys::GCClipMask;
+        }
+
+        if let Some((offset, length)) = self.dashes {
+            native.dash_offset = offset;
+            native.dashes = length as _;
+            mask |= xlib_sys::GCDashOffset | xlib_sys::GCDashList;
+        }
+
+        (mask as _, native)
+    }
+}
+
 /// A graphics context bound to a drawable.
 #[derive(Debug)]
 pub struct XGC<'a, T>
@@ -39,8 +525,10 @@ where
     ///
     /// # Arguments
     ///
-    /// * `foreground` - The foreground color in ARGB format
-    pub fn set_foreground(&self, foreground: u64) {
+    /// * `foreground` - The pixel value of the foreground color, see [`Pixel`]
+    pub fn set_foreground(&self, foreground: impl Into<Pixel>) {
+        let foreground = foreground.into().value();
+
         unsafe { xlib_sys::XSetForeground(self.display.handle(), self.handle, foreground) };
     }
 
@@ -48,8 +536,10 @@ where
     ///
     /// # Arguments
     ///
-    /// * `background` - The background color in ARGB format
-    pub fn set_background(&self, background: u64) {
+    /// * `background` - The pixel value of the background color, see [`Pixel`]
+    pub fn set_background(&self, background: impl Into<Pixel>) {
+        let background = background.into().value();
+
         unsafe { xlib_sys::XSetBackground(self.display.handle(), self.handle, background) };
     }
 
@@ -62,6 +552,97 @@ where
         unsafe { xlib_sys::XSetFont(self.display.handle(), self.handle, font.id().0) };
     }
 
+    /// Sets the clip region of the graphics context.
+    ///
+    /// # Arguments
+    ///
+    /// * `x_origin` - The x coordinate of the clip origin
+    /// * `y_origin` - The y coordinate of the clip origin
+    /// * `region` - The region to clip drawing to, or [`None`] to disable clipping
+    pub fn set_clip_region(&self, x_origin: i32, y_origin: i32, region: Option<&XServerRegion>) {
+        unsafe {
+            xfixes_sys::XFixesSetGCClipRegion(
+                self.display.handle(),
+                self.handle,
+                x_origin,
+                y_origin,
+                region.map(|r| r.handle()).unwrap_or(0),
+            )
+        };
+    }
+
+    /// Changes multiple values of the graphics context at once.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The values to change
+    pub fn change_values(&self, values: GCValues) {
+        let (mask, mut values) = values.into_native();
+
+        unsafe { xlib_sys::XChangeGC(self.display.handle(), self.handle, mask, &mut values) };
+    }
+
+    /// Restricts drawing to a set of rectangles.
+    ///
+    /// # Arguments
+    ///
+    /// * `x_origin` - The x coordinate of the clip origin
+    /// * `y_origin` - The y coordinate of the clip origin
+    /// * `rectangles` - The rectangles to clip drawing to
+    /// * `ordering` - The order the rectangles are sorted in
+    pub fn set_clip_rectangles(
+        &self,
+        x_origin: i32,
+        y_origin: i32,
+        rectangles: &[XRectangle],
+        ordering: ClipOrdering,
+    ) {
+        let mut rectangles = rectangles
+            .iter()
+            .map(|&r| xlib_sys::XRectangle::from(r))
+            .collect::<Vec<_>>();
+
+        unsafe {
+            xlib_sys::XSetClipRectangles(
+                self.display.handle(),
+                self.handle,
+                x_origin,
+                y_origin,
+                rectangles.as_mut_ptr(),
+                rectangles.len() as _,
+                ordering as _,
+            )
+        };
+    }
+
+    /// Sets the dash pattern used for dashed lines.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The offset into the pattern at which lines start
+    /// * `dashes` - The lengths of the alternating on and off dashes, must not contain zeros
+    ///
+    /// # Panics
+    ///
+    /// If `dashes` is empty or contains zeros.
+    pub fn set_dashes(&self, offset: i32, dashes: &[u8]) {
+        assert!(!dashes.is_empty());
+        assert!(
+            dashes.iter().all(|&dash| dash != 0),
+            "Dashes must not have a length of zero"
+        );
+
+        unsafe {
+            xlib_sys::XSetDashes(
+                self.display.handle(),
+                self.handle,
+                offset,
+                dashes.as_ptr() as _,
+                dashes.len() as _,
+            )
+        };
+    }
+
     /// Fills a rectangle.
     ///
     /// # Arguments
@@ -84,6 +665,294 @@ where
         }
     }
 
+    /// Draws a single point.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x coordinate of the point
+    /// * `y` - The y coordinate of the point
+    pub fn draw_point(&self, x: i32, y: i32) {
+        unsafe {
+            xlib_sys::XDrawPoint(
+                self.display.handle(),
+                self.drawable.drawable_handle(),
+                self.handle,
+                x,
+                y,
+            )
+        };
+    }
+
+    /// Draws multiple points.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The points to draw
+    /// * `mode` - How the coordinates of the points are interpreted
+    pub fn draw_points(&self, points: &[XPoint], mode: CoordinateMode) {
+        let mut points = points
+            .iter()
+            .map(|&p| xlib_sys::XPoint::from(p))
+            .collect::<Vec<_>>();
+
+        unsafe {
+            xlib_sys::XDrawPoints(
+                self.display.handle(),
+                self.drawable.drawable_handle(),
+                self.handle,
+                points.as_mut_ptr(),
+                points.len() as _,
+                mode as _,
+            )
+        };
+    }
+
+    /// Draws a line between two points.
+    ///
+    /// # Arguments
+    ///
+    /// * `x1` - The x coordinate of the start point
+    /// * `y1` - The y coordinate of the start point
+    /// * `x2` - The x coordinate of the end point
+    /// * `y2` - The y coordinate of the end point
+    pub fn draw_line(&self, x1: i32, y1: i32, x2: i32, y2: i32) {
+        unsafe {
+            xlib_sys::XDrawLine(
+                self.display.handle(),
+                self.drawable.drawable_handle(),
+                self.handle,
+                x1,
+                y1,
+                x2,
+                y2,
+            )
+        };
+    }
+
+    /// Draws connected lines through multiple points.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The points to connect
+    /// * `mode` - How the coordinates of the points are interpreted
+    pub fn draw_lines(&self, points: &[XPoint], mode: CoordinateMode) {
+        let mut points = points
+            .iter()
+            .map(|&p| xlib_sys::XPoint::from(p))
+            .collect::<Vec<_>>();
+
+        unsafe {
+            xlib_sys::XDrawLines(
+                self.display.handle(),
+                self.drawable.drawable_handle(),
+                self.handle,
+                points.as_mut_ptr(),
+                points.len() as _,
+                mode as _,
+            )
+        };
+    }
+
+    /// Draws multiple unconnected lines.
+    ///
+    /// # Arguments
+    ///
+    /// * `segments` - The lines to draw
+    pub fn draw_segments(&self, segments: &[XSegment]) {
+        let mut segments = segments
+            .iter()
+            .map(|&s| xlib_sys::XSegment::from(s))
+            .collect::<Vec<_>>();
+
+        unsafe {
+            xlib_sys::XDrawSegments(
+                self.display.handle(),
+                self.drawable.drawable_handle(),
+                self.handle,
+                segments.as_mut_ptr(),
+                segments.len() as _,
+            )
+        };
+    }
+
+    /// Draws the outline of a rectangle.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x coordinate to start drawing at
+    /// * `y` - The y coordinate to start drawing at
+    /// * `width` - The width to draw starting from `x`
+    /// * `height` - The height to draw starting from `y`
+    pub fn draw_rect(&self, x: i32, y: i32, width: u32, height: u32) {
+        unsafe {
+            xlib_sys::XDrawRectangle(
+                self.display.handle(),
+                self.drawable.drawable_handle(),
+                self.handle,
+                x,
+                y,
+                width,
+                height,
+            )
+        };
+    }
+
+    /// Draws the outlines of multiple rectangles.
+    ///
+    /// # Arguments
+    ///
+    /// * `rectangles` - The rectangles to draw
+    pub fn draw_rects(&self, rectangles: &[XRectangle]) {
+        let mut rectangles = rectangles
+            .iter()
+            .map(|&r| xlib_sys::XRectangle::from(r))
+            .collect::<Vec<_>>();
+
+        unsafe {
+            xlib_sys::XDrawRectangles(
+                self.display.handle(),
+                self.drawable.drawable_handle(),
+                self.handle,
+                rectangles.as_mut_ptr(),
+                rectangles.len() as _,
+            )
+        };
+    }
+
+    /// Fills multiple rectangles.
+    ///
+    /// # Arguments
+    ///
+    /// * `rectangles` - The rectangles to fill
+    pub fn fill_rects(&self, rectangles: &[XRectangle]) {
+        let mut rectangles = rectangles
+            .iter()
+            .map(|&r| xlib_sys::XRectangle::from(r))
+            .collect::<Vec<_>>();
+
+        unsafe {
+            xlib_sys::XFillRectangles(
+                self.display.handle(),
+                self.drawable.drawable_handle(),
+                self.handle,
+                rectangles.as_mut_ptr(),
+                rectangles.len() as _,
+            )
+        };
+    }
+
+    /// Draws the outline of an arc.
+    ///
+    /// # Arguments
+    ///
+    /// * `arc` - The arc to draw
+    pub fn draw_arc(&self, arc: XArc) {
+        unsafe {
+            xlib_sys::XDrawArc(
+                self.display.handle(),
+                self.drawable.drawable_handle(),
+                self.handle,
+                arc.x as _,
+                arc.y as _,
+                arc.width as _,
+                arc.height as _,
+                arc.angle1 as _,
+                arc.angle2 as _,
+            )
+        };
+    }
+
+    /// Draws the outlines of multiple arcs.
+    ///
+    /// # Arguments
+    ///
+    /// * `arcs` - The arcs to draw
+    pub fn draw_arcs(&self, arcs: &[XArc]) {
+        let mut arcs = arcs
+            .iter()
+            .map(|&a| xlib_sys::XArc::from(a))
+            .collect::<Vec<_>>();
+
+        unsafe {
+            xlib_sys::XDrawArcs(
+                self.display.handle(),
+                self.drawable.drawable_handle(),
+                self.handle,
+                arcs.as_mut_ptr(),
+                arcs.len() as _,
+            )
+        };
+    }
+
+    /// Fills an arc, closing it as configured by the arc mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `arc` - The arc to fill
+    pub fn fill_arc(&self, arc: XArc) {
+        unsafe {
+            xlib_sys::XFillArc(
+                self.display.handle(),
+                self.drawable.drawable_handle(),
+                self.handle,
+                arc.x as _,
+                arc.y as _,
+                arc.width as _,
+                arc.height as _,
+                arc.angle1 as _,
+                arc.angle2 as _,
+            )
+        };
+    }
+
+    /// Fills multiple arcs, closing them as configured by the arc mode.
+    ///
+    /// # Arguments
+    ///
+    /// * `arcs` - The arcs to fill
+    pub fn fill_arcs(&self, arcs: &[XArc]) {
+        let mut arcs = arcs
+            .iter()
+            .map(|&a| xlib_sys::XArc::from(a))
+            .collect::<Vec<_>>();
+
+        unsafe {
+            xlib_sys::XFillArcs(
+                self.display.handle(),
+                self.drawable.drawable_handle(),
+                self.handle,
+                arcs.as_mut_ptr(),
+                arcs.len() as _,
+            )
+        };
+    }
+
+    /// Fills a polygon, closing the path automatically.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The points of the polygon
+    /// * `shape` - The shape of the polygon
+    /// * `mode` - How the coordinates of the points are interpreted
+    pub fn fill_polygon(&self, points: &[XPoint], shape: PolygonShape, mode: CoordinateMode) {
+        let mut points = points
+            .iter()
+            .map(|&p| xlib_sys::XPoint::from(p))
+            .collect::<Vec<_>>();
+
+        unsafe {
+            xlib_sys::XFillPolygon(
+                self.display.handle(),
+                self.drawable.drawable_handle(),
+                self.handle,
+                points.as_mut_ptr(),
+                points.len() as _,
+                shape as _,
+                mode as _,
+            )
+        };
+    }
+
     /// Draws a string.
     ///
     /// # Arguments
@@ -107,6 +976,80 @@ where
         }
     }
 
+    /// Draws a string, filling its bounding box with the background color first.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x coordinate to start drawing at
+    /// * `y` - The y coordinate to start drawing at
+    /// * `s` - The string to draw
+    pub fn draw_image_string(&self, x: i32, y: i32, s: impl AsRef<str>) {
+        let text_bytes = s.as_ref().as_bytes();
+
+        unsafe {
+            xlib_sys::XDrawImageString(
+                self.display.handle(),
+                self.drawable.drawable_handle(),
+                self.handle,
+                x,
+                y,
+                text_bytes.as_ptr() as _,
+                text_bytes.len() as _,
+            );
+        }
+    }
+
+    /// Draws a UTF-8 string using a font set.
+    ///
+    /// # Arguments
+    ///
+    /// * `font_set` - The font set to draw the string with
+    /// * `x` - The x coordinate to start drawing at
+    /// * `y` - The y coordinate to start drawing at
+    /// * `s` - The string to draw
+    pub fn draw_utf8_string(&self, font_set: &XFontSet, x: i32, y: i32, s: impl AsRef<str>) {
+        let text_bytes = s.as_ref().as_bytes();
+
+        unsafe {
+            xlib_sys::Xutf8DrawString(
+                self.display.handle(),
+                self.drawable.drawable_handle(),
+                font_set.handle(),
+                self.handle,
+                x,
+                y,
+                text_bytes.as_ptr() as _,
+                text_bytes.len() as _,
+            );
+        }
+    }
+
+    /// Draws a UTF-8 string using a font set, filling its bounding box with the background
+    /// color first.
+    ///
+    /// # Arguments
+    ///
+    /// * `font_set` - The font set to draw the string with
+    /// * `x` - The x coordinate to start drawing at
+    /// * `y` - The y coordinate to start drawing at
+    /// * `s` - The string to draw
+    pub fn draw_utf8_image_string(&self, font_set: &XFontSet, x: i32, y: i32, s: impl AsRef<str>) {
+        let text_bytes = s.as_ref().as_bytes();
+
+        unsafe {
+            xlib_sys::Xutf8DrawImageString(
+                self.display.handle(),
+                self.drawable.drawable_handle(),
+                font_set.handle(),
+                self.handle,
+                x,
+                y,
+                text_bytes.as_ptr() as _,
+                text_bytes.len() as _,
+            );
+        }
+    }
+
     /// Copies an image onto the target.
     ///
     /// # Arguments
@@ -145,6 +1088,79 @@ where
         };
     }
 
+    /// Copies a shared memory image onto the target.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The image to copy
+    /// * `src_x` - The x offset in the image to start copying from
+    /// * `src_y` - The y offset in the image to start copying from
+    /// * `dest_x` - The x offset in the drawable to start copying to
+    /// * `dest_y` - The y offset in the drawable to start copying to
+    /// * `width` - The width of the image to copy
+    /// * `height` - The height of the image to copy
+    /// * `send_event` - Whether a completion event should be sent once the copy is done
+    #[allow(clippy::too_many_arguments)]
+    pub fn put_shm_image(
+        &self,
+        image: &XShmImage,
+        src_x: i32,
+        src_y: i32,
+        dest_x: i32,
+        dest_y: i32,
+        width: u32,
+        height: u32,
+        send_event: bool,
+    ) {
+        unsafe {
+            xshm_sys::XShmPutImage(
+                self.display.handle(),
+                self.drawable.drawable_handle(),
+                self.handle,
+                image.handle(),
+                src_x,
+                src_y,
+                dest_x,
+                dest_y,
+                width,
+                height,
+                send_event.into(),
+            )
+        };
+    }
+
+    /// Copies a transfer image onto the target using the fastest available path.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The image to copy
+    /// * `src_x` - The x offset in the image to start copying from
+    /// * `src_y` - The y offset in the image to start copying from
+    /// * `dest_x` - The x offset in the drawable to start copying to
+    /// * `dest_y` - The y offset in the drawable to start copying to
+    /// * `width` - The width of the image to copy
+    /// * `height` - The height of the image to copy
+    #[allow(clippy::too_many_arguments)]
+    pub fn put_transfer_image(
+        &self,
+        image: &XTransferImage,
+        src_x: i32,
+        src_y: i32,
+        dest_x: i32,
+        dest_y: i32,
+        width: u32,
+        height: u32,
+    ) {
+        match image {
+            XTransferImage::Shared(image) => {
+                self.put_shm_image(image, src_x, src_y, dest_x, dest_y, width, height, false)
+            }
+            XTransferImage::Plain(image) => {
+                self.put_image(image, src_x, src_y, dest_x, dest_y, width, height)
+            }
+        }
+    }
+
     /// Copies another drawable onto the target.
     ///
     /// # Arguments
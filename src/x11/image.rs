@@ -10,6 +10,35 @@ pub enum XBitmapPadding {
     Bit32 = 32,
 }
 
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum XByteOrder {
+    /// Least significant byte first
+    LsbFirst = xlib_sys::LSBFirst,
+
+    /// Most significant byte first
+    MsbFirst = xlib_sys::MSBFirst,
+}
+
+impl XByteOrder {
+    /// Wraps a native X11 byte order.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` - The X11 byte order to wrap
+    ///
+    /// # Panics
+    ///
+    /// If the byte order is not a valid X11 byte order.
+    pub fn new(raw: i32) -> Self {
+        match raw {
+            xlib_sys::LSBFirst => Self::LsbFirst,
+            xlib_sys::MSBFirst => Self::MsbFirst,
+            _ => unreachable!("Invalid byte order {}", raw),
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 #[repr(i32)]
 pub enum XImageFormat {
@@ -23,12 +52,106 @@ pub enum XImageFormat {
     ZPixmap = xlib_sys::ZPixmap,
 }
 
+impl XImageFormat {
+    /// Wraps a native X11 image format.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` - The X11 image format to wrap
+    ///
+    /// # Panics
+    ///
+    /// If the format is not a valid X11 image format.
+    pub fn new(raw: i32) -> Self {
+        match raw {
+            xlib_sys::XYBitmap => Self::XYBitmap,
+            xlib_sys::XYPixmap => Self::XYPixmap,
+            xlib_sys::ZPixmap => Self::ZPixmap,
+            _ => unreachable!("Invalid image format {}", raw),
+        }
+    }
+}
+
+/// Describes where a color channel is located within a pixel value.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+struct ChannelMask {
+    shift: u32,
+    max: u64,
+}
+
+impl ChannelMask {
+    fn new(mask: u64) -> Self {
+        if mask == 0 {
+            return Self { shift: 0, max: 0 };
+        }
+
+        let shift = mask.trailing_zeros();
+
+        Self {
+            shift,
+            max: mask >> shift,
+        }
+    }
+
+    fn extract(&self, pixel: u64) -> Option<u8> {
+        if self.max == 0 {
+            return None;
+        }
+
+        let value = (pixel >> self.shift) & self.max;
+        Some(((value * 255 + self.max / 2) / self.max) as u8)
+    }
+
+    fn insert(&self, value: u8) -> u64 {
+        if self.max == 0 {
+            return 0;
+        }
+
+        ((value as u64 * self.max + 127) / 255) << self.shift
+    }
+}
+
+/// Describes pixel data which can be accessed directly without going through Xlib.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+struct PackedLayout {
+    bytes_per_pixel: usize,
+    bytes_per_line: usize,
+    byte_order: XByteOrder,
+}
+
+impl PackedLayout {
+    fn read(&self, data: &[u8], x: u32, y: u32) -> u64 {
+        let offset = y as usize * self.bytes_per_line + x as usize * self.bytes_per_pixel;
+        let bytes = &data[offset..offset + self.bytes_per_pixel];
+
+        match self.byte_order {
+            XByteOrder::LsbFirst => bytes.iter().rev().fold(0, |v, &b| (v << 8) | b as u64),
+            XByteOrder::MsbFirst => bytes.iter().fold(0, |v, &b| (v << 8) | b as u64),
+        }
+    }
+
+    fn write(&self, data: &mut [u8], x: u32, y: u32, pixel: u64) {
+        let offset = y as usize * self.bytes_per_line + x as usize * self.bytes_per_pixel;
+        let bytes = &mut data[offset..offset + self.bytes_per_pixel];
+
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            let shift = match self.byte_order {
+                XByteOrder::LsbFirst => i * 8,
+                XByteOrder::MsbFirst => (self.bytes_per_pixel - i - 1) * 8,
+            };
+
+            *byte = (pixel >> shift) as u8;
+        }
+    }
+}
+
 /// X11 image.
 ///
 /// An X11 image is a client side image buffer which can be uploaded to the server.
+#[derive(Debug)]
 pub struct XImage<'a> {
     handle: *mut xlib_sys::XImage,
-    _display: &'a XDisplay,
+    display: &'a XDisplay,
 }
 
 impl<'a> XImage<'a> {
@@ -43,16 +166,279 @@ impl<'a> XImage<'a> {
     ///
     /// It is up to the caller to ensure all arguments are valid.
     pub unsafe fn new(handle: *mut xlib_sys::XImage, display: &'a XDisplay) -> Self {
-        Self {
-            handle,
-            _display: display,
-        }
+        Self { handle, display }
     }
 
     /// Retrieves the underlying native X11 image handle.
     pub fn handle(&self) -> *mut xlib_sys::XImage {
         self.handle
     }
+
+    /// Retrieves the width of the image.
+    pub fn width(&self) -> u32 {
+        unsafe { &*self.handle }.width as _
+    }
+
+    /// Retrieves the height of the image.
+    pub fn height(&self) -> u32 {
+        unsafe { &*self.handle }.height as _
+    }
+
+    /// Retrieves the depth of the image.
+    pub fn depth(&self) -> u32 {
+        unsafe { &*self.handle }.depth as _
+    }
+
+    /// Retrieves the format of the image.
+    pub fn format(&self) -> XImageFormat {
+        XImageFormat::new(unsafe { &*self.handle }.format)
+    }
+
+    /// Retrieves the amount of bytes a single line of the image occupies.
+    pub fn bytes_per_line(&self) -> usize {
+        unsafe { &*self.handle }.bytes_per_line as _
+    }
+
+    /// Retrieves the byte order of the pixel data.
+    pub fn byte_order(&self) -> XByteOrder {
+        XByteOrder::new(unsafe { &*self.handle }.byte_order)
+    }
+
+    /// Retrieves the amount of bits a single pixel occupies.
+    pub fn bits_per_pixel(&self) -> u32 {
+        unsafe { &*self.handle }.bits_per_pixel as _
+    }
+
+    /// Retrieves the red, green and blue masks of the pixel values.
+    ///
+    /// Images read from pixmaps do not carry masks, in that case the usual masks for 24 and 32
+    /// bit true color are assumed.
+    pub fn color_masks(&self) -> (u64, u64, u64) {
+        let image = unsafe { &*self.handle };
+
+        match (
+            image.red_mask,
+            image.green_mask,
+            image.blue_mask,
+            image.depth,
+        ) {
+            (0, 0, 0, 30) => (0x3FF00000, 0x000FFC00, 0x000003FF),
+            (0, 0, 0, 24 | 32) => (0xFF0000, 0x00FF00, 0x0000FF),
+            (red, green, blue, _) => (red as _, green as _, blue as _),
+        }
+    }
+
+    /// Overrides the red, green and blue masks of the pixel values.
+    ///
+    /// This is required for decoding images read from pixmaps using a non-standard visual.
+    ///
+    /// # Arguments
+    ///
+    /// * `red` - The mask of the red channel
+    /// * `green` - The mask of the green channel
+    /// * `blue` - The mask of the blue channel
+    pub fn set_color_masks(&mut self, red: u64, green: u64, blue: u64) {
+        let image = unsafe { &mut *self.handle };
+
+        image.red_mask = red as _;
+        image.green_mask = green as _;
+        image.blue_mask = blue as _;
+    }
+
+    /// Retrieves the pixel data of the image.
+    pub fn data(&self) -> &[u8] {
+        let image = unsafe { &*self.handle };
+
+        if image.data.is_null() {
+            return &[];
+        }
+
+        unsafe { std::slice::from_raw_parts(image.data as *const u8, self.data_len()) }
+    }
+
+    /// Retrieves the pixel data of the image mutably.
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        let image = unsafe { &*self.handle };
+
+        if image.data.is_null() {
+            return &mut [];
+        }
+
+        unsafe { std::slice::from_raw_parts_mut(image.data as *mut u8, self.data_len()) }
+    }
+
+    /// Calculates the length of the pixel data in bytes.
+    fn data_len(&self) -> usize {
+        let planes = match self.format() {
+            XImageFormat::XYPixmap => self.depth() as usize,
+            XImageFormat::XYBitmap | XImageFormat::ZPixmap => 1,
+        };
+
+        self.bytes_per_line() * self.height() as usize * planes
+    }
+
+    /// Retrieves the value of a single pixel.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x coordinate of the pixel
+    /// * `y` - The y coordinate of the pixel
+    ///
+    /// # Panics
+    ///
+    /// If the coordinates lie outside of the image.
+    pub fn get_pixel(&self, x: u32, y: u32) -> u64 {
+        assert!(x < self.width() && y < self.height());
+
+        unsafe { xlib_sys::XGetPixel(self.handle, x as _, y as _) as _ }
+    }
+
+    /// Sets the value of a single pixel.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x coordinate of the pixel
+    /// * `y` - The y coordinate of the pixel
+    /// * `pixel` - The new value of the pixel
+    ///
+    /// # Panics
+    ///
+    /// If the coordinates lie outside of the image.
+    pub fn put_pixel(&mut self, x: u32, y: u32, pixel: u64) {
+        assert!(x < self.width() && y < self.height());
+
+        unsafe { xlib_sys::XPutPixel(self.handle, x as _, y as _, pixel as _) };
+    }
+
+    /// Copies a part of this image into a new image.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x coordinate of the part to copy
+    /// * `y` - The y coordinate of the part to copy
+    /// * `width` - The width of the part to copy
+    /// * `height` - The height of the part to copy
+    ///
+    /// # Panics
+    ///
+    /// If the part does not lie completely within the image.
+    pub fn sub_image(&self, x: u32, y: u32, width: u32, height: u32) -> XImage<'a> {
+        assert!(
+            x.checked_add(width)
+                .is_some_and(|right| right <= self.width())
+                && y.checked_add(height)
+                    .is_some_and(|bottom| bottom <= self.height())
+        );
+
+        unsafe {
+            let image = xlib_sys::XSubImage(self.handle, x as _, y as _, width, height);
+
+            XImage::new(image, self.display)
+        }
+    }
+
+    /// Retrieves the layout of the pixel data if it can be accessed directly.
+    ///
+    /// This is only the case for [`XImageFormat::ZPixmap`] images with a byte aligned amount
+    /// of bits per pixel.
+    fn packed_layout(&self) -> Option<PackedLayout> {
+        match (self.format(), self.bits_per_pixel()) {
+            (XImageFormat::ZPixmap, bits @ (8 | 16 | 24 | 32)) => Some(PackedLayout {
+                bytes_per_pixel: bits as usize / 8,
+                bytes_per_line: self.bytes_per_line(),
+                byte_order: self.byte_order(),
+            }),
+            _ => None,
+        }
+    }
+
+    /// Retrieves the masks for the red, green, blue and alpha channel.
+    fn channel_masks(&self) -> [ChannelMask; 4] {
+        let (red, green, blue) = self.color_masks();
+
+        // 32 bit images store alpha in the bits not covered by any color, 30 bit images use the
+        // remaining 2 bits as padding
+        let alpha = if self.depth() == 32 {
+            !(red | green | blue) & 0xFFFFFFFF
+        } else {
+            0
+        };
+
+        [red, green, blue, alpha].map(ChannelMask::new)
+    }
+
+    /// Converts the image into tightly packed RGBA data with 8 bits per channel.
+    ///
+    /// Colors are decoded using the red, green and blue masks of the image. If the image has no
+    /// alpha channel, all pixels are opaque.
+    pub fn to_rgba8(&self) -> RgbaImage {
+        let masks = self.channel_masks();
+        let layout = self.packed_layout();
+        let data = self.data();
+
+        let mut rgba = Vec::with_capacity(self.width() as usize * self.height() as usize * 4);
+
+        for y in 0..self.height() {
+            for x in 0..self.width() {
+                let pixel = match layout {
+                    Some(layout) => layout.read(data, x, y),
+                    None => self.get_pixel(x, y),
+                };
+
+                rgba.extend_from_slice(&[
+                    masks[0].extract(pixel).unwrap_or(0),
+                    masks[1].extract(pixel).unwrap_or(0),
+                    masks[2].extract(pixel).unwrap_or(0),
+                    masks[3].extract(pixel).unwrap_or(255),
+                ]);
+            }
+        }
+
+        RgbaImage::new(self.width(), self.height(), rgba)
+    }
+
+    /// Overwrites the image with tightly packed RGBA data with 8 bits per channel.
+    ///
+    /// Colors are encoded using the red, green and blue masks of the image. The alpha channel is
+    /// discarded if the image has none.
+    ///
+    /// # Arguments
+    ///
+    /// * `rgba` - The pixel data to copy into the image
+    ///
+    /// # Panics
+    ///
+    /// If the length of the data does not match the size of the image.
+    pub fn copy_from_rgba8(&mut self, rgba: &[u8]) {
+        assert_eq!(
+            rgba.len(),
+            self.width() as usize * self.height() as usize * 4
+        );
+
+        let masks = self.channel_masks();
+        let width = self.width();
+        let pixels = rgba.chunks_exact(4).enumerate().map(|(i, c)| {
+            let pixel = (0..4).fold(0, |pixel, channel| {
+                pixel | masks[channel].insert(c[channel])
+            });
+            (i as u32 % width, i as u32 / width, pixel)
+        });
+
+        match self.packed_layout() {
+            Some(layout) => {
+                let data = self.data_mut();
+
+                for (x, y, pixel) in pixels {
+                    layout.write(data, x, y, pixel);
+                }
+            }
+            None => {
+                for (x, y, pixel) in pixels {
+                    self.put_pixel(x, y, pixel);
+                }
+            }
+        }
+    }
 }
 
 impl<'a> Drop for XImage<'a> {
@@ -60,3 +446,112 @@ impl<'a> Drop for XImage<'a> {
         unsafe { xlib_sys::XDestroyImage(self.handle) };
     }
 }
+
+/// Image with tightly packed RGBA pixel data using 8 bits per channel.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct RgbaImage {
+    width: u32,
+    height: u32,
+    data: Vec<u8>,
+}
+
+impl RgbaImage {
+    /// Creates a new RGBA image.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the image
+    /// * `height` - The height of the image
+    /// * `data` - The pixel data of the image, row by row
+    ///
+    /// # Panics
+    ///
+    /// If the length of the data does not match the size of the image.
+    pub fn new(width: u32, height: u32, data: Vec<u8>) -> Self {
+        assert_eq!(data.len(), width as usize * height as usize * 4);
+
+        Self {
+            width,
+            height,
+            data,
+        }
+    }
+
+    /// Retrieves the width of the image.
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Retrieves the height of the image.
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Retrieves the pixel data of the image.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Retrieves the pixel data of the image mutably.
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+
+    /// Turns this image into its pixel data.
+    pub fn into_data(self) -> Vec<u8> {
+        self.data
+    }
+
+    /// Retrieves a single pixel of the image.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x coordinate of the pixel
+    /// * `y` - The y coordinate of the pixel
+    ///
+    /// # Panics
+    ///
+    /// If the coordinates lie outside of the image.
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        assert!(x < self.width && y < self.height);
+
+        let offset = (y as usize * self.width as usize + x as usize) * 4;
+        [
+            self.data[offset],
+            self.data[offset + 1],
+            self.data[offset + 2],
+            self.data[offset + 3],
+        ]
+    }
+
+    /// Copies a part of this image into a new image.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x coordinate of the part to copy
+    /// * `y` - The y coordinate of the part to copy
+    /// * `width` - The width of the part to copy
+    /// * `height` - The height of the part to copy
+    ///
+    /// # Panics
+    ///
+    /// If the part does not lie completely within the image.
+    pub fn sub_image(&self, x: u32, y: u32, width: u32, height: u32) -> RgbaImage {
+        assert!(
+            x.checked_add(width)
+                .is_some_and(|right| right <= self.width)
+                && y.checked_add(height)
+                    .is_some_and(|bottom| bottom <= self.height)
+        );
+
+        let data = (y..y + height)
+            .flat_map(|row| {
+                let start = (row as usize * self.width as usize + x as usize) * 4;
+                &self.data[start..start + width as usize * 4]
+            })
+            .copied()
+            .collect();
+
+        RgbaImage::new(width, height, data)
+    }
+}
@@ -1,7 +1,8 @@
 use crate::{
-    xinput2_sys, xlib_sys, XAtom, XDisplay, XPropertyChangeMode, XPropertyData,
-    XPropertyDataFormat, XPropertyHolder,
+    xinput2_sys, xlib_sys, WindowHandleOwnership, XAtom, XDisplay, XIClassInfo,
+    XPropertyChangeMode, XPropertyData, XPropertyDataFormat, XPropertyHolder, XWindow,
 };
+use std::ffi::CStr;
 use x11::xinput2;
 
 #[derive(Debug, Clone)]
@@ -49,6 +50,257 @@ impl<'a> XInputDevice<'a> {
     pub fn id(&self) -> i32 {
         self.id
     }
+
+    /// Sets the keyboard focus of this device.
+    ///
+    /// # Arguments
+    ///
+    /// * `focus` - The new focus of the device
+    /// * `time` - The time of the focus change, or 0 for the current server time
+    pub fn set_focus(&self, focus: &XIFocus, time: u64) {
+        unsafe {
+            xinput2_sys::XISetFocus(self.display.handle(), self.id, focus.to_native(), time as _)
+        };
+    }
+
+    /// Retrieves the keyboard focus of this device.
+    ///
+    /// Returns [`None`] if the focus could not be queried, for example because the device is
+    /// not a keyboard.
+    pub fn get_focus(&self) -> Option<XIFocus<'a>> {
+        let mut focus = 0;
+
+        let status = unsafe { xinput2_sys::XIGetFocus(self.display.handle(), self.id, &mut focus) };
+
+        if status != 0 {
+            return None;
+        }
+
+        Some(unsafe { XIFocus::from_native(focus, self.display) })
+    }
+}
+
+/// The keyboard focus of an XInput2 device.
+#[derive(Debug)]
+pub enum XIFocus<'a> {
+    /// The device has no focus, keyboard events are discarded
+    None,
+
+    /// The focus follows the root window the pointer is on
+    PointerRoot,
+
+    /// The focus is on a specific window
+    Window(XWindow<'a>),
+}
+
+impl<'a> XIFocus<'a> {
+    /// Converts the focus from its native representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `focus` - The native focus window
+    /// * `display` - The display the focus belongs to
+    ///
+    /// # Safety
+    ///
+    /// It is up to the caller to ensure all arguments are valid.
+    pub unsafe fn from_native(focus: xlib_sys::Window, display: &'a XDisplay) -> Self {
+        match focus {
+            0 => Self::None,
+            1 => Self::PointerRoot,
+            window => Self::Window(XWindow::new(
+                window,
+                display,
+                WindowHandleOwnership::Foreign,
+            )),
+        }
+    }
+
+    /// Converts the focus to its native representation.
+    pub fn to_native(&self) -> xlib_sys::Window {
+        match self {
+            Self::None => 0,
+            Self::PointerRoot => 1,
+            Self::Window(window) => window.handle(),
+        }
+    }
+}
+
+/// What happens to the slave devices of a master device which is removed.
+#[derive(Debug, Clone)]
+pub enum XIReturnMode<'a> {
+    /// The slave devices become floating
+    Float,
+
+    /// The slave devices are attached to other master devices
+    AttachTo {
+        /// The master pointer slave pointers are attached to
+        pointer: XInputDevice<'a>,
+
+        /// The master keyboard slave keyboards are attached to
+        keyboard: XInputDevice<'a>,
+    },
+}
+
+/// A change to the XInput2 device hierarchy.
+#[derive(Debug, Clone)]
+pub enum XIHierarchyChange<'a> {
+    /// Adds a new master pointer and master keyboard pair.
+    ///
+    /// The X server appends " pointer" and " keyboard" to the name of the new devices.
+    AddMaster {
+        /// The name prefix of the new master devices
+        name: String,
+
+        /// Whether the new master devices send core events
+        send_core: bool,
+
+        /// Whether the new master devices are enabled immediately
+        enable: bool,
+    },
+
+    /// Removes a master device together with its paired master device.
+    RemoveMaster {
+        /// The master device to remove
+        device: XInputDevice<'a>,
+
+        /// What happens to the slave devices of the removed master devices
+        return_mode: XIReturnMode<'a>,
+    },
+
+    /// Attaches a slave device to a master device.
+    AttachSlave {
+        /// The slave device to attach
+        device: XInputDevice<'a>,
+
+        /// The master device to attach the slave device to
+        master: XInputDevice<'a>,
+    },
+
+    /// Detaches a slave device from its master device, making it floating.
+    DetachSlave {
+        /// The slave device to detach
+        device: XInputDevice<'a>,
+    },
+}
+
+/// The role of an XInput2 device in the device hierarchy.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(i32)]
+pub enum XIDeviceUse {
+    /// A virtual pointer which slave pointers are attached to
+    MasterPointer = xinput2_sys::XIMasterPointer,
+
+    /// A virtual keyboard which slave keyboards are attached to
+    MasterKeyboard = xinput2_sys::XIMasterKeyboard,
+
+    /// A physical pointer attached to a master pointer
+    SlavePointer = xinput2_sys::XISlavePointer,
+
+    /// A physical keyboard attached to a master keyboard
+    SlaveKeyboard = xinput2_sys::XISlaveKeyboard,
+
+    /// A physical device which is not attached to any master device
+    FloatingSlave = xinput2_sys::XIFloatingSlave,
+}
+
+impl XIDeviceUse {
+    /// Wraps an existing XInput2 device use.
+    ///
+    /// # Arguments
+    ///
+    /// * `usage` - The XInput2 device use
+    ///
+    /// # Panics
+    ///
+    /// If the given device use is not valid.
+    pub fn new(usage: i32) -> Self {
+        match usage {
+            xinput2_sys::XIMasterPointer => Self::MasterPointer,
+            xinput2_sys::XIMasterKeyboard => Self::MasterKeyboard,
+            xinput2_sys::XISlavePointer => Self::SlavePointer,
+            xinput2_sys::XISlaveKeyboard => Self::SlaveKeyboard,
+            xinput2_sys::XIFloatingSlave => Self::FloatingSlave,
+            x => unreachable!("Unknown XI device use {}", x),
+        }
+    }
+
+    /// Determines whether this is a master device.
+    pub fn is_master(&self) -> bool {
+        matches!(self, Self::MasterPointer | Self::MasterKeyboard)
+    }
+}
+
+/// Information about an XInput2 device.
+#[derive(Debug, Clone)]
+pub struct XIDeviceInfo<'a> {
+    device: XInputDevice<'a>,
+    name: String,
+    usage: XIDeviceUse,
+    attachment: XInputDevice<'a>,
+    enabled: bool,
+    classes: Vec<XIClassInfo<'a>>,
+}
+
+impl<'a> XIDeviceInfo<'a> {
+    /// Converts the XInput2 device info from its native representation.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The XInput2 native info
+    /// * `display` - The display the device belongs to
+    ///
+    /// # Safety
+    ///
+    /// It is up to the caller to ensure all arguments are valid.
+    pub unsafe fn new(handle: &xinput2_sys::XIDeviceInfo, display: &'a XDisplay) -> Self {
+        let classes = std::slice::from_raw_parts(handle.classes, handle.num_classes as _);
+
+        Self {
+            device: XInputDevice::from_id(handle.deviceid, display),
+            name: CStr::from_ptr(handle.name).to_string_lossy().into_owned(),
+            usage: XIDeviceUse::new(handle._use),
+            attachment: XInputDevice::from_id(handle.attachment, display),
+            enabled: handle.enabled != 0,
+            classes: classes
+                .iter()
+                .map(|c| XIClassInfo::new(*c, display))
+                .collect(),
+        }
+    }
+
+    /// Retrieves the device this info describes.
+    pub fn device(&self) -> &XInputDevice<'a> {
+        &self.device
+    }
+
+    /// Retrieves the name of the device.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Retrieves the role of the device in the hierarchy.
+    pub fn usage(&self) -> XIDeviceUse {
+        self.usage
+    }
+
+    /// Retrieves the device this device is attached to.
+    ///
+    /// For master devices this is the paired master device, for slave devices the master
+    /// device they are attached to. Floating slaves report an attachment of 0.
+    pub fn attachment(&self) -> &XInputDevice<'a> {
+        &self.attachment
+    }
+
+    /// Determines whether the device is enabled.
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Retrieves the input classes of the device.
+    pub fn classes(&self) -> &[XIClassInfo] {
+        &self.classes
+    }
 }
 
 #[derive(Debug, Clone)]
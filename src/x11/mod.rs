@@ -1,6 +1,8 @@
 mod atom;
 mod colormap;
 mod cursor;
+#[cfg(feature = "damage")]
+mod damage;
 mod display;
 mod drawable;
 mod event;
@@ -10,14 +12,22 @@ mod image;
 mod input;
 mod pixmap;
 mod property;
+#[cfg(feature = "raw-window-handle")]
+mod raw_handle;
 mod region;
+mod render;
 mod screen;
+mod shm;
 mod visual;
 mod window;
+#[cfg(feature = "xft")]
+mod xft;
 
 pub use atom::*;
 pub use colormap::*;
 pub use cursor::*;
+#[cfg(feature = "damage")]
+pub use damage::*;
 pub use display::*;
 pub use drawable::*;
 pub use event::*;
@@ -28,9 +38,13 @@ pub use input::*;
 pub use pixmap::*;
 pub use property::*;
 pub use region::*;
+pub use render::*;
 pub use screen::*;
+pub use shm::*;
 pub use visual::*;
 pub use window::*;
+#[cfg(feature = "xft")]
+pub use xft::*;
 
 use thiserror::Error;
 
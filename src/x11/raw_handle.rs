@@ -0,0 +1,80 @@
+//! Integration with the `raw-window-handle` crate.
+//!
+//! This allows passing X11 windows directly to graphics libraries such as `wgpu` or Vulkan
+//! surface creation helpers.
+
+use std::mem::MaybeUninit;
+use std::ptr::NonNull;
+
+use raw_window_handle::{
+    DisplayHandle, HandleError, HasDisplayHandle, HasWindowHandle, RawDisplayHandle,
+    RawWindowHandle, WindowHandle, XlibDisplayHandle, XlibWindowHandle,
+};
+
+use crate::xlib_sys;
+use crate::{XDisplay, XDrawable, XScreen, XVisual, XWindow};
+
+/// Queries the screen number and visual id of a window.
+///
+/// Windows not owned by this process may have been destroyed already, so the query runs with
+/// X errors trapped and fails with [`HandleError::Unavailable`] instead of aborting.
+fn query_screen_and_visual(window: &XWindow) -> Result<(i32, xlib_sys::VisualID), HandleError> {
+    let display = window.display();
+    let mut raw = MaybeUninit::zeroed();
+
+    let (status, error) = display.trap_errors(|| unsafe {
+        xlib_sys::XGetWindowAttributes(display.handle(), window.handle(), raw.as_mut_ptr())
+    });
+
+    if status == 0 || error.is_some() {
+        return Err(HandleError::Unavailable);
+    }
+
+    let raw = unsafe { raw.assume_init() };
+    let screen = unsafe { XScreen::new(raw.screen, display) };
+    let visual = unsafe { XVisual::new(raw.visual) };
+
+    Ok((screen.number(), visual.id()))
+}
+
+impl HasDisplayHandle for XDisplay {
+    fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        let display = NonNull::new(self.handle() as *mut _).ok_or(HandleError::Unavailable)?;
+        let handle = XlibDisplayHandle::new(Some(display), self.default_screen().number());
+
+        // The display pointer stays valid for as long as the display is borrowed
+        Ok(unsafe { DisplayHandle::borrow_raw(RawDisplayHandle::Xlib(handle)) })
+    }
+}
+
+impl<'a> HasDisplayHandle for XWindow<'a> {
+    fn display_handle(&self) -> Result<DisplayHandle<'_>, HandleError> {
+        let display =
+            NonNull::new(self.display().handle() as *mut _).ok_or(HandleError::Unavailable)?;
+
+        // The window may live on a screen other than the default one
+        let (screen, _) = query_screen_and_visual(self)?;
+        let handle = XlibDisplayHandle::new(Some(display), screen);
+
+        Ok(unsafe { DisplayHandle::borrow_raw(RawDisplayHandle::Xlib(handle)) })
+    }
+}
+
+/// Only owned windows provide a window handle. Foreign windows may be destroyed by their owner
+/// at any time, which would leave the borrowed handle dangling, so they fail with
+/// [`HandleError::Unavailable`].
+impl<'a> HasWindowHandle for XWindow<'a> {
+    fn window_handle(&self) -> Result<WindowHandle<'_>, HandleError> {
+        if self.handle() == 0 || self.is_foreign() {
+            return Err(HandleError::Unavailable);
+        }
+
+        let (_, visual_id) = query_screen_and_visual(self)?;
+
+        let mut handle = XlibWindowHandle::new(self.handle());
+        handle.visual_id = visual_id;
+
+        // Owned windows are only destroyed when dropped, so they outlive the borrow
+        Ok(unsafe { WindowHandle::borrow_raw(RawWindowHandle::Xlib(handle)) })
+    }
+}
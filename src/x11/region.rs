@@ -1,5 +1,6 @@
-use crate::xfixes_sys;
 use crate::XDisplay;
+use crate::{xfixes_sys, xlib_sys};
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Sub, SubAssign};
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct XRectangle {
@@ -9,6 +10,28 @@ pub struct XRectangle {
     pub height: u16,
 }
 
+impl From<xlib_sys::XRectangle> for XRectangle {
+    fn from(value: xlib_sys::XRectangle) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+            width: value.width,
+            height: value.height,
+        }
+    }
+}
+
+impl From<XRectangle> for xlib_sys::XRectangle {
+    fn from(value: XRectangle) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+            width: value.width,
+            height: value.height,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct XServerRegion<'a> {
     handle: xfixes_sys::XserverRegion,
@@ -34,6 +57,212 @@ impl<'a> XServerRegion<'a> {
     pub fn handle(&self) -> xfixes_sys::XserverRegion {
         self.handle
     }
+
+    /// Retrieves the display this region belongs to.
+    pub fn display(&self) -> &'a XDisplay {
+        self.display
+    }
+
+    /// Creates a new, empty region on the same display as this region.
+    fn create_empty(&self) -> XServerRegion<'a> {
+        self.display.create_region(&[])
+    }
+
+    /// Replaces the contents of this region with the given rectangles.
+    ///
+    /// # Arguments
+    ///
+    /// * `rectangles` - The rectangles to compose the region of
+    pub fn set(&mut self, rectangles: &[XRectangle]) {
+        let mut rectangles = rectangles
+            .iter()
+            .map(|&r| xlib_sys::XRectangle::from(r))
+            .collect::<Vec<_>>();
+
+        unsafe {
+            xfixes_sys::XFixesSetRegion(
+                self.display.handle(),
+                self.handle,
+                rectangles.as_mut_ptr(),
+                rectangles.len() as _,
+            )
+        };
+    }
+
+    /// Computes the union of this region and another region.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The region to unite with
+    pub fn union(&self, other: &XServerRegion) -> XServerRegion<'a> {
+        let out = self.create_empty();
+
+        unsafe {
+            xfixes_sys::XFixesUnionRegion(
+                self.display.handle(),
+                out.handle,
+                self.handle,
+                other.handle,
+            )
+        };
+
+        out
+    }
+
+    /// Computes the intersection of this region and another region.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The region to intersect with
+    pub fn intersect(&self, other: &XServerRegion) -> XServerRegion<'a> {
+        let out = self.create_empty();
+
+        unsafe {
+            xfixes_sys::XFixesIntersectRegion(
+                self.display.handle(),
+                out.handle,
+                self.handle,
+                other.handle,
+            )
+        };
+
+        out
+    }
+
+    /// Computes the region covered by this region but not by another region.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The region to subtract from this region
+    pub fn subtract(&self, other: &XServerRegion) -> XServerRegion<'a> {
+        let out = self.create_empty();
+
+        unsafe {
+            xfixes_sys::XFixesSubtractRegion(
+                self.display.handle(),
+                out.handle,
+                self.handle,
+                other.handle,
+            )
+        };
+
+        out
+    }
+
+    /// Computes the part of a rectangle which is not covered by this region.
+    ///
+    /// # Arguments
+    ///
+    /// * `bounds` - The rectangle to invert the region within
+    pub fn invert(&self, bounds: XRectangle) -> XServerRegion<'a> {
+        let out = self.create_empty();
+        let mut bounds = xlib_sys::XRectangle::from(bounds);
+
+        unsafe {
+            xfixes_sys::XFixesInvertRegion(
+                self.display.handle(),
+                out.handle,
+                &mut bounds,
+                self.handle,
+            )
+        };
+
+        out
+    }
+
+    /// Moves this region by the given offset.
+    ///
+    /// # Arguments
+    ///
+    /// * `dx` - The offset to move the region by on the x axis
+    /// * `dy` - The offset to move the region by on the y axis
+    pub fn translate(&mut self, dx: i32, dy: i32) {
+        unsafe { xfixes_sys::XFixesTranslateRegion(self.display.handle(), self.handle, dx, dy) };
+    }
+
+    /// Computes a region consisting of the bounding box of this region.
+    pub fn extents(&self) -> XServerRegion<'a> {
+        let out = self.create_empty();
+
+        unsafe { xfixes_sys::XFixesRegionExtents(self.display.handle(), out.handle, self.handle) };
+
+        out
+    }
+
+    /// Retrieves the rectangles this region is composed of.
+    pub fn fetch_rectangles(&self) -> Vec<XRectangle> {
+        self.fetch_rectangles_and_bounds().0
+    }
+
+    /// Retrieves the rectangles this region is composed of along with the bounding box of the
+    /// region.
+    pub fn fetch_rectangles_and_bounds(&self) -> (Vec<XRectangle>, XRectangle) {
+        let mut count = 0;
+        let mut bounds = xlib_sys::XRectangle {
+            x: 0,
+            y: 0,
+            width: 0,
+            height: 0,
+        };
+
+        let rectangles = unsafe {
+            let rectangles = xfixes_sys::XFixesFetchRegionAndBounds(
+                self.display.handle(),
+                self.handle,
+                &mut count,
+                &mut bounds,
+            );
+
+            if rectangles.is_null() {
+                Vec::new()
+            } else {
+                let out = std::slice::from_raw_parts(rectangles, count as _)
+                    .iter()
+                    .map(|&r| XRectangle::from(r))
+                    .collect();
+
+                xlib_sys::XFree(rectangles as _);
+
+                out
+            }
+        };
+
+        (rectangles, XRectangle::from(bounds))
+    }
+}
+
+impl<'a> Clone for XServerRegion<'a> {
+    fn clone(&self) -> Self {
+        let out = self.create_empty();
+
+        unsafe { xfixes_sys::XFixesCopyRegion(self.display.handle(), out.handle, self.handle) };
+
+        out
+    }
+}
+
+impl<'a, 'b> BitOr<&'b XServerRegion<'_>> for &'b XServerRegion<'a> {
+    type Output = XServerRegion<'a>;
+
+    fn bitor(self, rhs: &'b XServerRegion<'_>) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl<'a, 'b> BitAnd<&'b XServerRegion<'_>> for &'b XServerRegion<'a> {
+    type Output = XServerRegion<'a>;
+
+    fn bitand(self, rhs: &'b XServerRegion<'_>) -> Self::Output {
+        self.intersect(rhs)
+    }
+}
+
+impl<'a, 'b> Sub<&'b XServerRegion<'_>> for &'b XServerRegion<'a> {
+    type Output = XServerRegion<'a>;
+
+    fn sub(self, rhs: &'b XServerRegion<'_>) -> Self::Output {
+        self.subtract(rhs)
+    }
 }
 
 impl<'a> Drop for XServerRegion<'a> {
@@ -41,3 +270,521 @@ impl<'a> Drop for XServerRegion<'a> {
         unsafe { xfixes_sys::XFixesDestroyRegion(self.display.handle(), self.handle) };
     }
 }
+
+/// Converts rectangle edges to an [`XRectangle`], clipping them to the representable range.
+///
+/// Returns [`None`] if nothing of the rectangle is left after clipping.
+///
+/// # Arguments
+///
+/// * `left` - The left edge of the rectangle
+/// * `top` - The top edge of the rectangle
+/// * `right` - The right edge of the rectangle (exclusive)
+/// * `bottom` - The bottom edge of the rectangle (exclusive)
+fn clipped_rectangle(left: i32, top: i32, right: i32, bottom: i32) -> Option<XRectangle> {
+    let clip = |v: i32| v.clamp(i16::MIN as i32, i16::MAX as i32);
+    let (left, top, right, bottom) = (clip(left), clip(top), clip(right), clip(bottom));
+
+    (left < right && top < bottom).then(|| XRectangle {
+        x: left as _,
+        y: top as _,
+        width: (right - left) as _,
+        height: (bottom - top) as _,
+    })
+}
+
+/// A single horizontal band of a [`Region`].
+///
+/// All spans are sorted, non-empty and neither overlap nor touch each other.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct RegionBand {
+    top: i32,
+    bottom: i32,
+    spans: Vec<(i32, i32)>,
+}
+
+/// A client side region composed of rectangles.
+///
+/// Other than [`XServerRegion`] this region lives entirely in client memory and thus does not
+/// require any round trips to the X server. The region is always kept in a normalized, banded
+/// form: it is split into horizontal bands of equal height, each consisting of sorted and
+/// non-overlapping spans, and vertically adjacent bands with equal spans are merged. Two regions
+/// covering the same area are thus always equal.
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Region {
+    bands: Vec<RegionBand>,
+}
+
+impl Region {
+    /// Creates a new, empty region.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new region covering a single rectangle.
+    ///
+    /// # Arguments
+    ///
+    /// * `rectangle` - The rectangle the region should cover
+    pub fn from_rectangle(rectangle: XRectangle) -> Self {
+        Self::from_rectangles(&[rectangle])
+    }
+
+    /// Creates a new region covering the union of all given rectangles.
+    ///
+    /// # Arguments
+    ///
+    /// * `rectangles` - The rectangles the region should cover
+    pub fn from_rectangles(rectangles: &[XRectangle]) -> Self {
+        let rectangles = rectangles
+            .iter()
+            .filter(|r| r.width > 0 && r.height > 0)
+            .map(|r| {
+                let (x, y) = (r.x as i32, r.y as i32);
+                (x, y, x + r.width as i32, y + r.height as i32)
+            })
+            .collect::<Vec<_>>();
+
+        let mut edges = rectangles
+            .iter()
+            .flat_map(|&(_, top, _, bottom)| [top, bottom])
+            .collect::<Vec<_>>();
+        edges.sort_unstable();
+        edges.dedup();
+
+        let mut region = Self::new();
+
+        for window in edges.windows(2) {
+            let (top, bottom) = (window[0], window[1]);
+
+            let mut spans = rectangles
+                .iter()
+                .filter(|&&(_, r_top, _, r_bottom)| r_top <= top && r_bottom >= bottom)
+                .map(|&(left, _, right, _)| (left, right))
+                .collect::<Vec<_>>();
+            spans.sort_unstable();
+
+            let mut merged: Vec<(i32, i32)> = Vec::with_capacity(spans.len());
+            for (left, right) in spans {
+                match merged.last_mut() {
+                    Some(last) if left <= last.1 => last.1 = last.1.max(right),
+                    _ => merged.push((left, right)),
+                }
+            }
+
+            region.push_band(top, bottom, merged);
+        }
+
+        region
+    }
+
+    /// Appends a band to the bottom of this region, merging it with the last band if possible.
+    fn push_band(&mut self, top: i32, bottom: i32, spans: Vec<(i32, i32)>) {
+        if spans.is_empty() || top >= bottom {
+            return;
+        }
+
+        if let Some(last) = self.bands.last_mut() {
+            if last.bottom == top && last.spans == spans {
+                last.bottom = bottom;
+                return;
+            }
+        }
+
+        self.bands.push(RegionBand { top, bottom, spans });
+    }
+
+    /// Retrieves the spans of the band covering the given y coordinate.
+    ///
+    /// # Arguments
+    ///
+    /// * `y` - The y coordinate to look up
+    fn spans_at(&self, y: i32) -> &[(i32, i32)] {
+        let index = self.bands.partition_point(|band| band.bottom <= y);
+
+        match self.bands.get(index) {
+            Some(band) if band.top <= y => &band.spans,
+            _ => &[],
+        }
+    }
+
+    /// Combines two span lists using a boolean operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `a` - The first span list
+    /// * `b` - The second span list
+    /// * `op` - The operation deciding whether a point covered by `a` and/or `b` is kept
+    fn combine_spans(
+        a: &[(i32, i32)],
+        b: &[(i32, i32)],
+        op: fn(bool, bool) -> bool,
+    ) -> Vec<(i32, i32)> {
+        let mut edges = a
+            .iter()
+            .chain(b.iter())
+            .flat_map(|&(left, right)| [left, right])
+            .collect::<Vec<_>>();
+        edges.sort_unstable();
+        edges.dedup();
+
+        let covers = |spans: &[(i32, i32)], x: i32| {
+            let index = spans.partition_point(|&(_, right)| right <= x);
+            spans
+                .get(index)
+                .map(|&(left, _)| left <= x)
+                .unwrap_or(false)
+        };
+
+        let mut out: Vec<(i32, i32)> = Vec::new();
+        for window in edges.windows(2) {
+            let (left, right) = (window[0], window[1]);
+
+            if !op(covers(a, left), covers(b, left)) {
+                continue;
+            }
+
+            match out.last_mut() {
+                Some(last) if last.1 == left => last.1 = right,
+                _ => out.push((left, right)),
+            }
+        }
+
+        out
+    }
+
+    /// Combines two regions using a boolean operation.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The region to combine this region with
+    /// * `op` - The operation deciding whether a point covered by `self` and/or `other` is kept
+    fn combine(&self, other: &Region, op: fn(bool, bool) -> bool) -> Region {
+        let mut edges = self
+            .bands
+            .iter()
+            .chain(other.bands.iter())
+            .flat_map(|band| [band.top, band.bottom])
+            .collect::<Vec<_>>();
+        edges.sort_unstable();
+        edges.dedup();
+
+        let mut out = Region::new();
+        for window in edges.windows(2) {
+            let (top, bottom) = (window[0], window[1]);
+            let spans = Self::combine_spans(self.spans_at(top), other.spans_at(top), op);
+
+            out.push_band(top, bottom, spans);
+        }
+
+        out
+    }
+
+    /// Computes the union of this region and another region.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The region to unite with
+    pub fn union(&self, other: &Region) -> Region {
+        self.combine(other, |a, b| a || b)
+    }
+
+    /// Computes the intersection of this region and another region.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The region to intersect with
+    pub fn intersect(&self, other: &Region) -> Region {
+        self.combine(other, |a, b| a && b)
+    }
+
+    /// Computes the area covered by this region but not by another region.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The region to subtract from this region
+    pub fn subtract(&self, other: &Region) -> Region {
+        self.combine(other, |a, b| a && !b)
+    }
+
+    /// Adds a rectangle to this region.
+    ///
+    /// # Arguments
+    ///
+    /// * `rectangle` - The rectangle to add
+    pub fn add_rectangle(&mut self, rectangle: XRectangle) {
+        *self = self.union(&Region::from_rectangle(rectangle));
+    }
+
+    /// Moves this region by the given offset.
+    ///
+    /// # Arguments
+    ///
+    /// * `dx` - The offset to move the region by on the x axis
+    /// * `dy` - The offset to move the region by on the y axis
+    pub fn translate(&mut self, dx: i32, dy: i32) {
+        for band in &mut self.bands {
+            band.top += dy;
+            band.bottom += dy;
+
+            for span in &mut band.spans {
+                span.0 += dx;
+                span.1 += dx;
+            }
+        }
+    }
+
+    /// Determines whether this region covers no area at all.
+    pub fn is_empty(&self) -> bool {
+        self.bands.is_empty()
+    }
+
+    /// Determines whether a point lies within this region.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x coordinate of the point
+    /// * `y` - The y coordinate of the point
+    pub fn contains_point(&self, x: i32, y: i32) -> bool {
+        self.spans_at(y)
+            .iter()
+            .any(|&(left, right)| left <= x && x < right)
+    }
+
+    /// Retrieves the bounding box of this region, or [`None`] if the region is empty.
+    ///
+    /// The bounding box is clipped to the coordinate range of [`XRectangle`], [`None`] is also
+    /// returned if the region lies completely outside of it.
+    pub fn extents(&self) -> Option<XRectangle> {
+        let top = self.bands.first()?.top;
+        let bottom = self.bands.last()?.bottom;
+
+        let left = self.bands.iter().map(|band| band.spans[0].0).min()?;
+        let right = self
+            .bands
+            .iter()
+            .map(|band| band.spans[band.spans.len() - 1].1)
+            .max()?;
+
+        clipped_rectangle(left, top, right, bottom)
+    }
+
+    /// Retrieves the rectangles this region is composed of.
+    ///
+    /// The rectangles are returned in banded order, sorted by y first and x second. Rectangles
+    /// are clipped to the coordinate range of [`XRectangle`], parts of the region outside of it
+    /// are left out.
+    pub fn rectangles(&self) -> Vec<XRectangle> {
+        self.bands
+            .iter()
+            .flat_map(|band| {
+                band.spans.iter().filter_map(move |&(left, right)| {
+                    clipped_rectangle(left, band.top, right, band.bottom)
+                })
+            })
+            .collect()
+    }
+
+    /// Uploads this region to the X server.
+    ///
+    /// # Arguments
+    ///
+    /// * `display` - The display to create the server region on
+    pub fn to_server_region<'a>(&self, display: &'a XDisplay) -> XServerRegion<'a> {
+        display.create_region(&self.rectangles())
+    }
+
+    /// Converts this region into a native Xlib region.
+    ///
+    /// The returned region is owned by the caller and must be freed using
+    /// [`xlib_sys::XDestroyRegion`].
+    pub fn to_xlib_region(&self) -> xlib_sys::Region {
+        unsafe {
+            let region = xlib_sys::XCreateRegion();
+
+            for rectangle in self.rectangles() {
+                let mut rectangle = xlib_sys::XRectangle::from(rectangle);
+                xlib_sys::XUnionRectWithRegion(&mut rectangle, region, region);
+            }
+
+            region
+        }
+    }
+}
+
+impl<'a> From<&XServerRegion<'a>> for Region {
+    fn from(value: &XServerRegion<'a>) -> Self {
+        Region::from_rectangles(&value.fetch_rectangles())
+    }
+}
+
+impl From<XRectangle> for Region {
+    fn from(value: XRectangle) -> Self {
+        Region::from_rectangle(value)
+    }
+}
+
+impl<'a> BitOr<&'a Region> for &'a Region {
+    type Output = Region;
+
+    fn bitor(self, rhs: &'a Region) -> Self::Output {
+        self.union(rhs)
+    }
+}
+
+impl<'a> BitAnd<&'a Region> for &'a Region {
+    type Output = Region;
+
+    fn bitand(self, rhs: &'a Region) -> Self::Output {
+        self.intersect(rhs)
+    }
+}
+
+impl<'a> Sub<&'a Region> for &'a Region {
+    type Output = Region;
+
+    fn sub(self, rhs: &'a Region) -> Self::Output {
+        self.subtract(rhs)
+    }
+}
+
+impl<'a> BitOrAssign<&'a Region> for Region {
+    fn bitor_assign(&mut self, rhs: &'a Region) {
+        *self = self.union(rhs);
+    }
+}
+
+impl<'a> BitAndAssign<&'a Region> for Region {
+    fn bitand_assign(&mut self, rhs: &'a Region) {
+        *self = self.intersect(rhs);
+    }
+}
+
+impl<'a> SubAssign<&'a Region> for Region {
+    fn sub_assign(&mut self, rhs: &'a Region) {
+        *self = self.subtract(rhs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: i16, y: i16, width: u16, height: u16) -> XRectangle {
+        XRectangle {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn union_of_overlapping_rectangles_is_banded() {
+        let a = Region::from_rectangle(rect(0, 0, 10, 10));
+        let b = Region::from_rectangle(rect(5, 5, 10, 10));
+
+        assert_eq!(
+            (&a | &b).rectangles(),
+            vec![rect(0, 0, 10, 5), rect(0, 5, 15, 5), rect(5, 10, 10, 5)]
+        );
+    }
+
+    #[test]
+    fn union_merges_touching_spans() {
+        let region = Region::from_rectangles(&[rect(0, 0, 5, 5), rect(5, 0, 5, 5)]);
+
+        assert_eq!(region.rectangles(), vec![rect(0, 0, 10, 5)]);
+    }
+
+    #[test]
+    fn intersect_keeps_common_area() {
+        let a = Region::from_rectangle(rect(0, 0, 10, 10));
+        let b = Region::from_rectangle(rect(5, 5, 10, 10));
+
+        assert_eq!((&a & &b).rectangles(), vec![rect(5, 5, 5, 5)]);
+        assert!((&a & &Region::from_rectangle(rect(20, 20, 5, 5))).is_empty());
+    }
+
+    #[test]
+    fn subtract_punches_holes() {
+        let a = Region::from_rectangle(rect(0, 0, 9, 9));
+        let b = Region::from_rectangle(rect(3, 3, 3, 3));
+
+        assert_eq!(
+            (&a - &b).rectangles(),
+            vec![
+                rect(0, 0, 9, 3),
+                rect(0, 3, 3, 3),
+                rect(6, 3, 3, 3),
+                rect(0, 6, 9, 3)
+            ]
+        );
+        assert!((&b - &a).is_empty());
+    }
+
+    #[test]
+    fn equal_areas_normalize_to_equal_regions() {
+        let vertical = Region::from_rectangles(&[rect(0, 0, 10, 5), rect(0, 5, 10, 5)]);
+        let horizontal = Region::from_rectangles(&[rect(0, 0, 5, 10), rect(5, 0, 5, 10)]);
+
+        assert_eq!(vertical, horizontal);
+        assert_eq!(vertical, Region::from_rectangle(rect(0, 0, 10, 10)));
+    }
+
+    #[test]
+    fn empty_rectangles_are_ignored() {
+        let region = Region::from_rectangles(&[rect(0, 0, 0, 10), rect(0, 0, 10, 0)]);
+
+        assert!(region.is_empty());
+        assert_eq!(region, Region::new());
+    }
+
+    #[test]
+    fn contains_point_excludes_right_and_bottom_edges() {
+        let region = Region::from_rectangles(&[rect(0, 0, 10, 10), rect(20, 0, 10, 10)]);
+
+        assert!(region.contains_point(0, 0));
+        assert!(region.contains_point(9, 9));
+        assert!(region.contains_point(25, 5));
+        assert!(!region.contains_point(10, 5));
+        assert!(!region.contains_point(5, 10));
+        assert!(!region.contains_point(15, 5));
+        assert!(!region.contains_point(-1, 0));
+    }
+
+    #[test]
+    fn extents_cover_all_bands() {
+        let region = Region::from_rectangles(&[rect(5, 0, 5, 5), rect(0, 10, 5, 5)]);
+
+        assert_eq!(region.extents(), Some(rect(0, 0, 10, 15)));
+        assert_eq!(Region::new().extents(), None);
+    }
+
+    #[test]
+    fn translate_moves_region() {
+        let mut region = Region::from_rectangle(rect(0, 0, 10, 10));
+        region.translate(-5, 7);
+
+        assert_eq!(region.rectangles(), vec![rect(-5, 7, 10, 10)]);
+    }
+
+    #[test]
+    fn out_of_range_coordinates_are_clipped() {
+        let mut region = Region::from_rectangle(rect(32760, -10, 100, 20));
+
+        assert_eq!(region.rectangles(), vec![rect(32760, -10, 7, 20)]);
+        assert_eq!(region.extents(), Some(rect(32760, -10, 7, 20)));
+
+        region.translate(100, 0);
+
+        assert!(!region.is_empty());
+        assert!(region.rectangles().is_empty());
+        assert_eq!(region.extents(), None);
+
+        let mut region = Region::from_rectangle(rect(-32768, 0, 10, 10));
+        region.translate(-5, 0);
+
+        assert_eq!(region.rectangles(), vec![rect(-32768, 0, 5, 10)]);
+    }
+}
@@ -0,0 +1,940 @@
+use crate::{
+    xfixes_sys, xlib_sys, xrender_sys, SubwindowMode, XDisplay, XPixmap, XRectangle, XServerRegion,
+};
+use std::ffi::CString;
+use std::fmt::{Debug, Formatter};
+use std::marker::PhantomData;
+
+/// The standard picture formats every X server supporting XRender provides.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum PictStandardFormat {
+    /// 32 bit color with 8 bits per channel including alpha
+    Argb32 = 0,
+
+    /// 24 bit color with 8 bits per channel without alpha
+    Rgb24 = 1,
+
+    /// 8 bit alpha only
+    A8 = 2,
+
+    /// 4 bit alpha only
+    A4 = 3,
+
+    /// 1 bit alpha only
+    A1 = 4,
+}
+
+/// Compositing operators used to combine a source with a destination.
+///
+/// The Porter-Duff operators assume premultiplied alpha, the blend modes follow the PDF
+/// specification.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum PictOp {
+    Clear = xrender_sys::PictOpClear,
+    Src = xrender_sys::PictOpSrc,
+    Dst = xrender_sys::PictOpDst,
+    Over = xrender_sys::PictOpOver,
+    OverReverse = xrender_sys::PictOpOverReverse,
+    In = xrender_sys::PictOpIn,
+    InReverse = xrender_sys::PictOpInReverse,
+    Out = xrender_sys::PictOpOut,
+    OutReverse = xrender_sys::PictOpOutReverse,
+    Atop = xrender_sys::PictOpAtop,
+    AtopReverse = xrender_sys::PictOpAtopReverse,
+    Xor = xrender_sys::PictOpXor,
+    Add = xrender_sys::PictOpAdd,
+    Saturate = xrender_sys::PictOpSaturate,
+    Multiply = xrender_sys::PictOpMultiply,
+    Screen = xrender_sys::PictOpScreen,
+    Overlay = xrender_sys::PictOpOverlay,
+    Darken = xrender_sys::PictOpDarken,
+    Lighten = xrender_sys::PictOpLighten,
+    ColorDodge = xrender_sys::PictOpColorDodge,
+    ColorBurn = xrender_sys::PictOpColorBurn,
+    HardLight = xrender_sys::PictOpHardLight,
+    SoftLight = xrender_sys::PictOpSoftLight,
+    Difference = xrender_sys::PictOpDifference,
+    Exclusion = xrender_sys::PictOpExclusion,
+    HslHue = xrender_sys::PictOpHSLHue,
+    HslSaturation = xrender_sys::PictOpHSLSaturation,
+    HslColor = xrender_sys::PictOpHSLColor,
+    HslLuminosity = xrender_sys::PictOpHSLLuminosity,
+}
+
+/// Determines how a picture is sampled outside of its bounds.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum PictRepeat {
+    /// Pixels outside of the picture are transparent
+    None = xrender_sys::RepeatNone,
+
+    /// The picture is tiled
+    Normal = xrender_sys::RepeatNormal,
+
+    /// The edge pixels of the picture are extended
+    Pad = xrender_sys::RepeatPad,
+
+    /// The picture is tiled and mirrored at every edge
+    Reflect = xrender_sys::RepeatReflect,
+}
+
+/// Filters used when sampling transformed pictures.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum PictFilter {
+    /// Nearest neighbour sampling
+    Nearest,
+
+    /// Bilinear interpolation
+    Bilinear,
+
+    /// The fastest filter available, usually nearest neighbour
+    Fast,
+
+    /// A filter with good quality and reasonable performance, usually bilinear
+    Good,
+
+    /// The best quality filter available
+    Best,
+}
+
+impl PictFilter {
+    /// Retrieves the name of the filter as understood by the X server.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Nearest => xrender_sys::FilterNearest,
+            Self::Bilinear => xrender_sys::FilterBilinear,
+            Self::Fast => xrender_sys::FilterFast,
+            Self::Good => xrender_sys::FilterGood,
+            Self::Best => xrender_sys::FilterBest,
+        }
+    }
+}
+
+/// Determines how edges of trapezoids and triangles are rasterized.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum PolyEdge {
+    /// Edges are aliased
+    Sharp = xrender_sys::PolyEdgeSharp,
+
+    /// Edges are anti-aliased
+    Smooth = xrender_sys::PolyEdgeSmooth,
+}
+
+/// Determines how precise trapezoids and triangles are rasterized.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[repr(i32)]
+pub enum PolyMode {
+    /// Geometry is rasterized exactly as specified by the protocol
+    Precise = xrender_sys::PolyModePrecise,
+
+    /// The X server may use a faster, less precise rasterization
+    Imprecise = xrender_sys::PolyModeImprecise,
+}
+
+/// A color as used by XRender, with 16 bits per channel and premultiplied alpha.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct XRenderColor {
+    /// The red channel of the color, premultiplied by alpha
+    pub red: u16,
+
+    /// The green channel of the color, premultiplied by alpha
+    pub green: u16,
+
+    /// The blue channel of the color, premultiplied by alpha
+    pub blue: u16,
+
+    /// The alpha channel of the color
+    pub alpha: u16,
+}
+
+impl XRenderColor {
+    /// Creates a color from 8 bit channels which are not premultiplied.
+    ///
+    /// # Arguments
+    ///
+    /// * `red` - The red channel of the color
+    /// * `green` - The green channel of the color
+    /// * `blue` - The blue channel of the color
+    /// * `alpha` - The alpha channel of the color
+    pub fn from_rgba8(red: u8, green: u8, blue: u8, alpha: u8) -> Self {
+        let premultiply = |channel: u8| (channel as u32 * alpha as u32 * 257 / 255) as u16;
+
+        Self {
+            red: premultiply(red),
+            green: premultiply(green),
+            blue: premultiply(blue),
+            alpha: alpha as u16 * 257,
+        }
+    }
+
+    /// Converts this color into its native representation.
+    pub fn to_native(self) -> xrender_sys::XRenderColor {
+        xrender_sys::XRenderColor {
+            red: self.red,
+            green: self.green,
+            blue: self.blue,
+            alpha: self.alpha,
+        }
+    }
+}
+
+/// A point with sub-pixel precision.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct PictPoint {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl PictPoint {
+    /// Creates a new point.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The x coordinate of the point
+    /// * `y` - The y coordinate of the point
+    pub fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+
+    /// Converts this point into its native fixed point representation.
+    pub fn to_native(self) -> xrender_sys::XPointFixed {
+        xrender_sys::XPointFixed {
+            x: to_fixed(self.x),
+            y: to_fixed(self.y),
+        }
+    }
+}
+
+/// A trapezoid with horizontal top and bottom edges.
+///
+/// The left and right edges are given as lines through two points each, the trapezoid spans
+/// between them from `top` to `bottom`.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct PictTrapezoid {
+    pub top: f64,
+    pub bottom: f64,
+    pub left: (PictPoint, PictPoint),
+    pub right: (PictPoint, PictPoint),
+}
+
+impl PictTrapezoid {
+    /// Converts this trapezoid into its native fixed point representation.
+    pub fn to_native(self) -> xrender_sys::XTrapezoid {
+        xrender_sys::XTrapezoid {
+            top: to_fixed(self.top),
+            bottom: to_fixed(self.bottom),
+            left: xrender_sys::XLineFixed {
+                p1: self.left.0.to_native(),
+                p2: self.left.1.to_native(),
+            },
+            right: xrender_sys::XLineFixed {
+                p1: self.right.0.to_native(),
+                p2: self.right.1.to_native(),
+            },
+        }
+    }
+}
+
+/// A triangle spanned by three points.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct PictTriangle {
+    pub p1: PictPoint,
+    pub p2: PictPoint,
+    pub p3: PictPoint,
+}
+
+impl PictTriangle {
+    /// Converts this triangle into its native fixed point representation.
+    pub fn to_native(self) -> xrender_sys::XTriangle {
+        xrender_sys::XTriangle {
+            p1: self.p1.to_native(),
+            p2: self.p2.to_native(),
+            p3: self.p3.to_native(),
+        }
+    }
+}
+
+/// A color stop of a gradient.
+#[derive(Debug, Copy, Clone, Default, PartialEq)]
+pub struct PictGradientStop {
+    /// The position of the stop along the gradient between 0.0 and 1.0
+    pub offset: f64,
+
+    /// The color at the stop
+    pub color: XRenderColor,
+}
+
+/// A projective transformation applied when sampling a picture.
+///
+/// Note that the transformation maps destination coordinates to source coordinates, so in
+/// order to draw a source at twice its size it has to be scaled by 0.5.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PictTransform {
+    pub matrix: [[f64; 3]; 3],
+}
+
+impl PictTransform {
+    /// Creates the identity transformation.
+    pub fn identity() -> Self {
+        Self {
+            matrix: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        }
+    }
+
+    /// Creates a scaling transformation.
+    ///
+    /// # Arguments
+    ///
+    /// * `sx` - The factor to scale by on the x axis
+    /// * `sy` - The factor to scale by on the y axis
+    pub fn scale(sx: f64, sy: f64) -> Self {
+        Self {
+            matrix: [[sx, 0.0, 0.0], [0.0, sy, 0.0], [0.0, 0.0, 1.0]],
+        }
+    }
+
+    /// Creates a translating transformation.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx` - The offset to translate by on the x axis
+    /// * `ty` - The offset to translate by on the y axis
+    pub fn translate(tx: f64, ty: f64) -> Self {
+        Self {
+            matrix: [[1.0, 0.0, tx], [0.0, 1.0, ty], [0.0, 0.0, 1.0]],
+        }
+    }
+
+    /// Combines this transformation with another one, applying `other` first.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The transformation to apply before this one
+    pub fn multiply(&self, other: &PictTransform) -> Self {
+        let mut matrix = [[0.0; 3]; 3];
+
+        for (row, out) in matrix.iter_mut().enumerate() {
+            for (column, value) in out.iter_mut().enumerate() {
+                *value = (0..3)
+                    .map(|i| self.matrix[row][i] * other.matrix[i][column])
+                    .sum();
+            }
+        }
+
+        Self { matrix }
+    }
+
+    /// Converts this transformation into its native fixed point representation.
+    pub fn to_native(self) -> xrender_sys::XTransform {
+        xrender_sys::XTransform {
+            matrix: self.matrix.map(|row| row.map(to_fixed)),
+        }
+    }
+}
+
+impl Default for PictTransform {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// Converts a floating point value to the 16.16 fixed point format used by XRender.
+pub(crate) fn to_fixed(value: f64) -> xrender_sys::XFixed {
+    (value * 65536.0).round() as _
+}
+
+/// Converts gradient stops into the native offsets and colors.
+pub(crate) fn gradient_stops_to_native(
+    stops: &[PictGradientStop],
+) -> (Vec<xrender_sys::XFixed>, Vec<xrender_sys::XRenderColor>) {
+    stops
+        .iter()
+        .map(|stop| (to_fixed(stop.offset), stop.color.to_native()))
+        .unzip()
+}
+
+/// X11 render picture format.
+///
+/// Picture formats describe how the pixels of a picture are stored. They are owned by Xlib and
+/// stay valid as long as the display is open.
+#[derive(Copy, Clone)]
+pub struct XPictFormat<'a> {
+    handle: *mut xrender_sys::XRenderPictFormat,
+    _data: PhantomData<&'a ()>,
+}
+
+impl<'a> XPictFormat<'a> {
+    /// Wraps an existing native X11 picture format.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The underlying native pointer
+    ///
+    /// # Safety
+    ///
+    /// It is up to the caller to ensure all arguments are valid.
+    pub unsafe fn new(handle: *mut xrender_sys::XRenderPictFormat) -> Self {
+        Self {
+            handle,
+            _data: PhantomData,
+        }
+    }
+
+    /// Retrieves the underlying native X11 picture format handle.
+    pub fn handle(&self) -> *mut xrender_sys::XRenderPictFormat {
+        self.handle
+    }
+
+    /// Retrieves the X11 id of the picture format.
+    pub fn id(&self) -> xrender_sys::PictFormat {
+        unsafe { &*self.handle }.id
+    }
+
+    /// Retrieves the bit-depth of the picture format.
+    pub fn depth(&self) -> i32 {
+        unsafe { &*self.handle }.depth
+    }
+
+    /// Determines whether pixels are stored directly instead of as colormap indices.
+    pub fn is_direct(&self) -> bool {
+        unsafe { &*self.handle }.type_ == xrender_sys::PictTypeDirect
+    }
+
+    /// Retrieves the mask of the red channel within a pixel.
+    pub fn red_mask(&self) -> u32 {
+        let direct = unsafe { &*self.handle }.direct;
+        (direct.redMask as u32 & 0xFFFF) << direct.red
+    }
+
+    /// Retrieves the mask of the green channel within a pixel.
+    pub fn green_mask(&self) -> u32 {
+        let direct = unsafe { &*self.handle }.direct;
+        (direct.greenMask as u32 & 0xFFFF) << direct.green
+    }
+
+    /// Retrieves the mask of the blue channel within a pixel.
+    pub fn blue_mask(&self) -> u32 {
+        let direct = unsafe { &*self.handle }.direct;
+        (direct.blueMask as u32 & 0xFFFF) << direct.blue
+    }
+
+    /// Retrieves the mask of the alpha channel within a pixel.
+    pub fn alpha_mask(&self) -> u32 {
+        let direct = unsafe { &*self.handle }.direct;
+        (direct.alphaMask as u32 & 0xFFFF) << direct.alpha
+    }
+
+    /// Determines whether the picture format has an alpha channel.
+    pub fn has_alpha(&self) -> bool {
+        self.alpha_mask() != 0
+    }
+}
+
+impl<'a> Debug for XPictFormat<'a> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Note that this check is just to aid debugging - the safety contract of this type
+        // guarantees that the handle is never null!
+        let handle = if self.handle.is_null() {
+            None
+        } else {
+            Some(unsafe { &*self.handle })
+        };
+
+        f.debug_struct("XPictFormat")
+            .field("handle", &handle)
+            .field("_data", &self._data)
+            .finish()
+    }
+}
+
+impl<'a> PartialEq for XPictFormat<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl<'a> Eq for XPictFormat<'a> {}
+
+/// Attributes of a picture, used when creating or changing a picture.
+#[derive(Debug, Default, Clone)]
+pub struct PictureAttributes<'creation, 'a> {
+    repeat: Option<PictRepeat>,
+    alpha_map: Option<(&'creation XPicture<'a>, i32, i32)>,
+    clip_origin: Option<(i32, i32)>,
+    clip_mask: Option<Option<&'creation XPixmap<'a>>>,
+    graphics_exposures: Option<bool>,
+    subwindow_mode: Option<SubwindowMode>,
+    poly_edge: Option<PolyEdge>,
+    poly_mode: Option<PolyMode>,
+    component_alpha: Option<bool>,
+}
+
+impl<'creation, 'a> PictureAttributes<'creation, 'a> {
+    /// Creates a new set of picture attributes without any set.
+    pub fn new() -> Self {
+        PictureAttributes::default()
+    }
+
+    /// Sets how the picture is sampled outside of its bounds.
+    pub fn repeat(&mut self, repeat: PictRepeat) -> &mut Self {
+        self.repeat = Some(repeat);
+        self
+    }
+
+    /// Sets a picture whose alpha channel replaces the alpha channel of the picture.
+    pub fn alpha_map(&mut self, alpha_map: &'creation XPicture<'a>, x: i32, y: i32) -> &mut Self {
+        self.alpha_map = Some((alpha_map, x, y));
+        self
+    }
+
+    /// Sets the origin of the clip mask.
+    pub fn clip_origin(&mut self, x: i32, y: i32) -> &mut Self {
+        self.clip_origin = Some((x, y));
+        self
+    }
+
+    /// Sets the bitmap used for clipping, or [`None`] to disable clipping.
+    pub fn clip_mask(&mut self, clip_mask: Option<&'creation XPixmap<'a>>) -> &mut Self {
+        self.clip_mask = Some(clip_mask);
+        self
+    }
+
+    /// Sets whether graphics exposure events are generated.
+    pub fn graphics_exposures(&mut self, graphics_exposures: bool) -> &mut Self {
+        self.graphics_exposures = Some(graphics_exposures);
+        self
+    }
+
+    /// Sets whether child windows clip drawing operations.
+    pub fn subwindow_mode(&mut self, mode: SubwindowMode) -> &mut Self {
+        self.subwindow_mode = Some(mode);
+        self
+    }
+
+    /// Sets how edges of trapezoids and triangles are rasterized.
+    pub fn poly_edge(&mut self, edge: PolyEdge) -> &mut Self {
+        self.poly_edge = Some(edge);
+        self
+    }
+
+    /// Sets how precise trapezoids and triangles are rasterized.
+    pub fn poly_mode(&mut self, mode: PolyMode) -> &mut Self {
+        self.poly_mode = Some(mode);
+        self
+    }
+
+    /// Sets whether each color channel of a mask is applied separately.
+    pub fn component_alpha(&mut self, component_alpha: bool) -> &mut Self {
+        self.component_alpha = Some(component_alpha);
+        self
+    }
+
+    /// Turns this struct into its native representation along with the associated value mask.
+    pub fn into_native(self) -> (u64, xrender_sys::XRenderPictureAttributes) {
+        let mut mask = 0;
+        let mut native = unsafe { std::mem::zeroed::<xrender_sys::XRenderPictureAttributes>() };
+
+        if let Some(repeat) = self.repeat {
+            native.repeat = repeat as _;
+            mask |= xrender_sys::CPRepeat;
+        }
+
+        if let Some((alpha_map, x, y)) = self.alpha_map {
+            native.alpha_map = alpha_map.handle();
+            native.alpha_x_origin = x;
+            native.alpha_y_origin = y;
+            mask |= xrender_sys::CPAlphaMap | xrender_sys::CPAlphaXOrigin;
+            mask |= xrender_sys::CPAlphaYOrigin;
+        }
+
+        if let Some((x, y)) = self.clip_origin {
+            native.clip_x_origin = x;
+            native.clip_y_origin = y;
+            mask |= xrender_sys::CPClipXOrigin | xrender_sys::CPClipYOrigin;
+        }
+
+        if let Some(clip_mask) = self.clip_mask {
+            native.clip_mask = clip_mask.map(|m| m.handle()).unwrap_or(0);
+            mask |= xrender_sys::CPClipMask;
+        }
+
+        if let Some(graphics_exposures) = self.graphics_exposures {
+            native.graphics_exposures = graphics_exposures as _;
+            mask |= xrender_sys::CPGraphicsExposure;
+        }
+
+        if let Some(mode) = self.subwindow_mode {
+            native.subwindow_mode = mode as _;
+            mask |= xrender_sys::CPSubwindowMode;
+        }
+
+        if let Some(edge) = self.poly_edge {
+            native.poly_edge = edge as _;
+            mask |= xrender_sys::CPPolyEdge;
+        }
+
+        if let Some(mode) = self.poly_mode {
+            native.poly_mode = mode as _;
+            mask |= xrender_sys::CPPolyMode;
+        }
+
+        if let Some(component_alpha) = self.component_alpha {
+            native.component_alpha = component_alpha as _;
+            mask |= xrender_sys::CPComponentAlpha;
+        }
+
+        (mask as _, native)
+    }
+}
+
+/// X11 render picture.
+///
+/// A picture is either bound to a drawable or describes a solid fill or gradient which can be
+/// used as the source of compositing operations.
+#[derive(Debug)]
+pub struct XPicture<'a> {
+    handle: xrender_sys::Picture,
+    display: &'a XDisplay,
+}
+
+impl<'a> XPicture<'a> {
+    /// Wraps an existing X11 picture.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The X11 picture to wrap
+    /// * `display` - The display the picture belongs to
+    ///
+    /// # Safety
+    ///
+    /// It is up to the caller to ensure all arguments are valid.
+    pub unsafe fn new(handle: xrender_sys::Picture, display: &'a XDisplay) -> Self {
+        Self { handle, display }
+    }
+
+    /// Retrieves the underlying native X11 picture id.
+    pub fn handle(&self) -> xrender_sys::Picture {
+        self.handle
+    }
+
+    /// Changes attributes of the picture.
+    ///
+    /// # Arguments
+    ///
+    /// * `attributes` - The attributes to change
+    pub fn change_attributes(&self, attributes: PictureAttributes) {
+        let (mask, attributes) = attributes.into_native();
+
+        unsafe {
+            xrender_sys::XRenderChangePicture(self.display.handle(), self.handle, mask, &attributes)
+        };
+    }
+
+    /// Composites a source picture onto this picture.
+    ///
+    /// # Arguments
+    ///
+    /// * `op` - The compositing operator
+    /// * `src` - The picture to composite
+    /// * `mask` - An optional picture whose alpha channel is multiplied with the source
+    /// * `src_x` - The x offset in the source to start compositing from
+    /// * `src_y` - The y offset in the source to start compositing from
+    /// * `mask_x` - The x offset in the mask to start compositing from
+    /// * `mask_y` - The y offset in the mask to start compositing from
+    /// * `dest_x` - The x offset in this picture to composite to
+    /// * `dest_y` - The y offset in this picture to composite to
+    /// * `width` - The width of the area to composite
+    /// * `height` - The height of the area to composite
+    #[allow(clippy::too_many_arguments)]
+    pub fn composite(
+        &self,
+        op: PictOp,
+        src: &XPicture,
+        mask: Option<&XPicture>,
+        src_x: i32,
+        src_y: i32,
+        mask_x: i32,
+        mask_y: i32,
+        dest_x: i32,
+        dest_y: i32,
+        width: u32,
+        height: u32,
+    ) {
+        unsafe {
+            xrender_sys::XRenderComposite(
+                self.display.handle(),
+                op as _,
+                src.handle,
+                mask.map(|m| m.handle).unwrap_or(0),
+                self.handle,
+                src_x,
+                src_y,
+                mask_x,
+                mask_y,
+                dest_x,
+                dest_y,
+                width,
+                height,
+            )
+        };
+    }
+
+    /// Fills a rectangle of this picture with a color.
+    ///
+    /// # Arguments
+    ///
+    /// * `op` - The compositing operator
+    /// * `color` - The color to fill with
+    /// * `x` - The x coordinate to start filling at
+    /// * `y` - The y coordinate to start filling at
+    /// * `width` - The width of the area to fill
+    /// * `height` - The height of the area to fill
+    pub fn fill_rectangle(
+        &self,
+        op: PictOp,
+        color: XRenderColor,
+        x: i32,
+        y: i32,
+        width: u32,
+        height: u32,
+    ) {
+        let color = color.to_native();
+
+        unsafe {
+            xrender_sys::XRenderFillRectangle(
+                self.display.handle(),
+                op as _,
+                self.handle,
+                &color,
+                x,
+                y,
+                width,
+                height,
+            )
+        };
+    }
+
+    /// Fills multiple rectangles of this picture with a color.
+    ///
+    /// # Arguments
+    ///
+    /// * `op` - The compositing operator
+    /// * `color` - The color to fill with
+    /// * `rectangles` - The rectangles to fill
+    pub fn fill_rectangles(&self, op: PictOp, color: XRenderColor, rectangles: &[XRectangle]) {
+        let color = color.to_native();
+        let rectangles = rectangles
+            .iter()
+            .map(|&r| xlib_sys::XRectangle::from(r))
+            .collect::<Vec<_>>();
+
+        unsafe {
+            xrender_sys::XRenderFillRectangles(
+                self.display.handle(),
+                op as _,
+                self.handle,
+                &color,
+                rectangles.as_ptr(),
+                rectangles.len() as _,
+            )
+        };
+    }
+
+    /// Composites trapezoids filled with a source picture onto this picture.
+    ///
+    /// # Arguments
+    ///
+    /// * `op` - The compositing operator
+    /// * `src` - The picture to fill the trapezoids with
+    /// * `mask_format` - The format of the intermediate mask, usually [`PictStandardFormat::A8`]
+    ///   for anti-aliasing, or [`None`] to composite each trapezoid individually
+    /// * `src_x` - The x offset in the source
+    /// * `src_y` - The y offset in the source
+    /// * `trapezoids` - The trapezoids to render
+    pub fn composite_trapezoids(
+        &self,
+        op: PictOp,
+        src: &XPicture,
+        mask_format: Option<&XPictFormat>,
+        src_x: i32,
+        src_y: i32,
+        trapezoids: &[PictTrapezoid],
+    ) {
+        let trapezoids = trapezoids.iter().map(|t| t.to_native()).collect::<Vec<_>>();
+
+        unsafe {
+            xrender_sys::XRenderCompositeTrapezoids(
+                self.display.handle(),
+                op as _,
+                src.handle,
+                self.handle,
+                mask_format
+                    .map(|f| f.handle() as *const _)
+                    .unwrap_or(std::ptr::null()),
+                src_x,
+                src_y,
+                trapezoids.as_ptr(),
+                trapezoids.len() as _,
+            )
+        };
+    }
+
+    /// Composites triangles filled with a source picture onto this picture.
+    ///
+    /// # Arguments
+    ///
+    /// * `op` - The compositing operator
+    /// * `src` - The picture to fill the triangles with
+    /// * `mask_format` - The format of the intermediate mask, usually [`PictStandardFormat::A8`]
+    ///   for anti-aliasing, or [`None`] to composite each triangle individually
+    /// * `src_x` - The x offset in the source
+    /// * `src_y` - The y offset in the source
+    /// * `triangles` - The triangles to render
+    pub fn composite_triangles(
+        &self,
+        op: PictOp,
+        src: &XPicture,
+        mask_format: Option<&XPictFormat>,
+        src_x: i32,
+        src_y: i32,
+        triangles: &[PictTriangle],
+    ) {
+        let triangles = triangles.iter().map(|t| t.to_native()).collect::<Vec<_>>();
+
+        unsafe {
+            xrender_sys::XRenderCompositeTriangles(
+                self.display.handle(),
+                op as _,
+                src.handle,
+                self.handle,
+                mask_format
+                    .map(|f| f.handle() as *const _)
+                    .unwrap_or(std::ptr::null()),
+                src_x,
+                src_y,
+                triangles.as_ptr(),
+                triangles.len() as _,
+            )
+        };
+    }
+
+    /// Sets the transformation applied when sampling this picture.
+    ///
+    /// # Arguments
+    ///
+    /// * `transform` - The transformation to apply
+    pub fn set_transform(&self, transform: &PictTransform) {
+        let mut transform = transform.to_native();
+
+        unsafe {
+            xrender_sys::XRenderSetPictureTransform(
+                self.display.handle(),
+                self.handle,
+                &mut transform,
+            )
+        };
+    }
+
+    /// Sets the filter used when sampling this picture.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - The filter to use
+    pub fn set_filter(&self, filter: PictFilter) {
+        let name = CString::new(filter.name()).unwrap();
+
+        unsafe {
+            xrender_sys::XRenderSetPictureFilter(
+                self.display.handle(),
+                self.handle,
+                name.as_ptr(),
+                std::ptr::null_mut(),
+                0,
+            )
+        };
+    }
+
+    /// Sets a convolution filter used when sampling this picture.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - The width of the kernel
+    /// * `height` - The height of the kernel
+    /// * `kernel` - The values of the kernel, row by row
+    ///
+    /// # Panics
+    ///
+    /// If the size of the kernel does not match its width and height.
+    pub fn set_convolution_filter(&self, width: u16, height: u16, kernel: &[f64]) {
+        assert_eq!(kernel.len(), width as usize * height as usize);
+
+        let name = CString::new(xrender_sys::FilterConvolution).unwrap();
+        let mut params = [to_fixed(width as _), to_fixed(height as _)]
+            .into_iter()
+            .chain(kernel.iter().map(|&v| to_fixed(v)))
+            .collect::<Vec<_>>();
+
+        unsafe {
+            xrender_sys::XRenderSetPictureFilter(
+                self.display.handle(),
+                self.handle,
+                name.as_ptr(),
+                params.as_mut_ptr(),
+                params.len() as _,
+            )
+        };
+    }
+
+    /// Restricts drawing to this picture to a set of rectangles.
+    ///
+    /// # Arguments
+    ///
+    /// * `x_origin` - The x coordinate of the clip origin
+    /// * `y_origin` - The y coordinate of the clip origin
+    /// * `rectangles` - The rectangles to clip drawing to
+    pub fn set_clip_rectangles(&self, x_origin: i32, y_origin: i32, rectangles: &[XRectangle]) {
+        let rectangles = rectangles
+            .iter()
+            .map(|&r| xlib_sys::XRectangle::from(r))
+            .collect::<Vec<_>>();
+
+        unsafe {
+            xrender_sys::XRenderSetPictureClipRectangles(
+                self.display.handle(),
+                self.handle,
+                x_origin,
+                y_origin,
+                rectangles.as_ptr(),
+                rectangles.len() as _,
+            )
+        };
+    }
+
+    /// Restricts drawing to this picture to a region.
+    ///
+    /// # Arguments
+    ///
+    /// * `x_origin` - The x coordinate of the clip origin
+    /// * `y_origin` - The y coordinate of the clip origin
+    /// * `region` - The region to clip drawing to, or [`None`] to disable clipping
+    pub fn set_clip_region(&self, x_origin: i32, y_origin: i32, region: Option<&XServerRegion>) {
+        unsafe {
+            xfixes_sys::XFixesSetPictureClipRegion(
+                self.display.handle(),
+                self.handle,
+                x_origin,
+                y_origin,
+                region.map(|r| r.handle()).unwrap_or(0),
+            )
+        };
+    }
+}
+
+impl<'a> Drop for XPicture<'a> {
+    fn drop(&mut self) {
+        unsafe { xrender_sys::XRenderFreePicture(self.display.handle(), self.handle) };
+    }
+}
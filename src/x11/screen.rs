@@ -4,7 +4,7 @@ use crate::{
     SetWindowAttributes, WindowClass, WindowHandleOwnership, XAtom, XColormap, XVisual,
     XVisualInfo,
 };
-use crate::{XDisplay, XWindow};
+use crate::{RgbaImage, XDisplay, XDrawable, XRectangle, XWindow};
 use std::io::Cursor;
 use std::mem::MaybeUninit;
 use std::slice;
@@ -120,6 +120,8 @@ impl<'a> XScreen<'a> {
     ///
     /// The composite window is a window, which lies on top of all other windows
     /// but receives no input events.
+    ///
+    /// The overlay window is released again when the returned window is dropped.
     pub fn composite_window(&self) -> XWindow<'a> {
         unsafe {
             let window = xcomposite_sys::XCompositeGetOverlayWindow(
@@ -168,6 +170,37 @@ impl<'a> XScreen<'a> {
         }
     }
 
+    /// Looks up a visual of the screen by its id.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The X11 id of the visual to look up
+    pub fn find_visual(&self, id: xlib_sys::VisualID) -> Option<XVisualInfo<'a>> {
+        let mut template = unsafe { std::mem::zeroed::<xlib_sys::XVisualInfo>() };
+        template.visualid = id;
+        template.screen = self.number();
+
+        let mut count = 0;
+
+        unsafe {
+            let infos = xlib_sys::XGetVisualInfo(
+                self.display.handle(),
+                xlib_sys::VisualIDMask | xlib_sys::VisualScreenMask,
+                &mut template,
+                &mut count,
+            );
+
+            if infos.is_null() {
+                return None;
+            }
+
+            let info = *infos;
+            xlib_sys::XFree(infos as _);
+
+            (count > 0).then(|| XVisualInfo::new(info, XVisual::new(info.visual)))
+        }
+    }
+
     /// Creates a new colormap.
     ///
     /// # Arguments
@@ -360,4 +393,39 @@ impl<'a> XScreen<'a> {
 
         out
     }
+
+    /// Captures the contents of each monitor connected to this screen as RGBA pixels.
+    ///
+    /// Monitors which do not lie completely within the root window are skipped.
+    pub fn capture_monitors(&self) -> Vec<(XRandRMonitorInfo<'a>, RgbaImage)> {
+        let root = self.root_window();
+        let (screen_width, screen_height) = unsafe {
+            (
+                xlib_sys::XWidthOfScreen(self.handle),
+                xlib_sys::XHeightOfScreen(self.handle),
+            )
+        };
+
+        self.get_monitors()
+            .into_iter()
+            .filter(|monitor| {
+                monitor.x >= 0
+                    && monitor.y >= 0
+                    && monitor.width > 0
+                    && monitor.height > 0
+                    && monitor.x + monitor.width <= screen_width
+                    && monitor.y + monitor.height <= screen_height
+            })
+            .filter_map(|monitor| {
+                let rectangle = XRectangle {
+                    x: monitor.x as _,
+                    y: monitor.y as _,
+                    width: monitor.width as _,
+                    height: monitor.height as _,
+                };
+
+                root.capture(rectangle).map(|image| (monitor, image))
+            })
+            .collect()
+    }
 }
@@ -0,0 +1,204 @@
+use crate::{xlib_sys, xshm_sys, XDisplay, XDrawable, XImage, XPixmap};
+
+/// X11 image backed by a shared memory segment.
+///
+/// Shared memory images are transferred to and from the X server without copying the pixel
+/// data through the connection, which makes them suitable for frequent uploads and captures of
+/// large images. The segment is attached to the X server for as long as the image is alive.
+#[derive(Debug)]
+pub struct XShmImage<'a> {
+    handle: *mut xlib_sys::XImage,
+    // Boxed because Xlib keeps a pointer to the segment info in the image
+    segment: Box<xshm_sys::XShmSegmentInfo>,
+    display: &'a XDisplay,
+}
+
+impl<'a> XShmImage<'a> {
+    /// Wraps an existing X11 shared memory image.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The X11 image to wrap
+    /// * `segment` - The attached shared memory segment backing the image
+    /// * `display` - The display the image belongs to
+    ///
+    /// # Safety
+    ///
+    /// It is up to the caller to ensure all arguments are valid.
+    pub unsafe fn new(
+        handle: *mut xlib_sys::XImage,
+        segment: Box<xshm_sys::XShmSegmentInfo>,
+        display: &'a XDisplay,
+    ) -> Self {
+        Self {
+            handle,
+            segment,
+            display,
+        }
+    }
+
+    /// Retrieves the underlying native X11 image handle.
+    pub fn handle(&self) -> *mut xlib_sys::XImage {
+        self.handle
+    }
+
+    /// Retrieves the underlying native segment info.
+    pub fn segment(&self) -> &xshm_sys::XShmSegmentInfo {
+        &self.segment
+    }
+
+    /// Retrieves the width of the image.
+    pub fn width(&self) -> u32 {
+        unsafe { &*self.handle }.width as _
+    }
+
+    /// Retrieves the height of the image.
+    pub fn height(&self) -> u32 {
+        unsafe { &*self.handle }.height as _
+    }
+
+    /// Retrieves the depth of the image.
+    pub fn depth(&self) -> u32 {
+        unsafe { &*self.handle }.depth as _
+    }
+
+    /// Retrieves the amount of bytes a single line of the image occupies.
+    pub fn bytes_per_line(&self) -> usize {
+        unsafe { &*self.handle }.bytes_per_line as _
+    }
+
+    /// Retrieves the pixel data of the image.
+    pub fn data(&self) -> &[u8] {
+        unsafe {
+            std::slice::from_raw_parts(
+                self.segment.shmaddr as *const u8,
+                self.bytes_per_line() * self.height() as usize,
+            )
+        }
+    }
+
+    /// Retrieves the pixel data of the image mutably.
+    ///
+    /// Note that the X server may still be reading from the image if it has been put without
+    /// waiting for completion.
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                self.segment.shmaddr as *mut u8,
+                self.bytes_per_line() * self.height() as usize,
+            )
+        }
+    }
+
+    /// Creates a pixmap sharing its pixel data with this image.
+    ///
+    /// Returns [`None`] if the X server does not support shared pixmaps. The pixmap must not be
+    /// used after the image has been dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `drawable` - The drawable determining the screen of the pixmap
+    pub fn create_pixmap<'b>(&self, drawable: &impl XDrawable<'b>) -> Option<XPixmap<'a>> {
+        if !self.display.shm_pixmaps_available() {
+            return None;
+        }
+
+        let mut segment = *self.segment;
+
+        let pixmap = unsafe {
+            xshm_sys::XShmCreatePixmap(
+                self.display.handle(),
+                drawable.drawable_handle(),
+                self.segment.shmaddr,
+                &mut segment,
+                self.width(),
+                self.height(),
+                self.depth(),
+            )
+        };
+
+        Some(unsafe { XPixmap::new(pixmap, self.display) })
+    }
+}
+
+impl<'a> Drop for XShmImage<'a> {
+    fn drop(&mut self) {
+        unsafe {
+            xshm_sys::XShmDetach(self.display.handle(), self.segment.as_mut());
+
+            // Make sure the X server has detached before the memory goes away
+            xlib_sys::XSync(self.display.handle(), xlib_sys::False);
+
+            (*self.handle).data = std::ptr::null_mut();
+            xlib_sys::XDestroyImage(self.handle);
+            libc::shmdt(self.segment.shmaddr as _);
+        }
+    }
+}
+
+/// X11 image used to transfer pixel data to and from the X server.
+///
+/// The image is backed by shared memory if the MIT-SHM extension is available and falls back to
+/// a regular client side image otherwise.
+#[derive(Debug)]
+pub enum XTransferImage<'a> {
+    /// Image backed by shared memory
+    Shared(XShmImage<'a>),
+
+    /// Regular image transferred through the connection
+    Plain(XImage<'a>),
+}
+
+impl<'a> XTransferImage<'a> {
+    /// Retrieves the underlying native X11 image handle.
+    pub fn handle(&self) -> *mut xlib_sys::XImage {
+        match self {
+            Self::Shared(image) => image.handle(),
+            Self::Plain(image) => image.handle(),
+        }
+    }
+
+    /// Determines whether the image is backed by shared memory.
+    pub fn is_shared(&self) -> bool {
+        matches!(self, Self::Shared(_))
+    }
+
+    /// Retrieves the width of the image.
+    pub fn width(&self) -> u32 {
+        unsafe { &*self.handle() }.width as _
+    }
+
+    /// Retrieves the height of the image.
+    pub fn height(&self) -> u32 {
+        unsafe { &*self.handle() }.height as _
+    }
+
+    /// Retrieves the amount of bytes a single line of the image occupies.
+    pub fn bytes_per_line(&self) -> usize {
+        unsafe { &*self.handle() }.bytes_per_line as _
+    }
+
+    /// Retrieves the pixel data of the image.
+    pub fn data(&self) -> &[u8] {
+        let image = unsafe { &*self.handle() };
+
+        unsafe {
+            std::slice::from_raw_parts(
+                image.data as *const u8,
+                self.bytes_per_line() * self.height() as usize,
+            )
+        }
+    }
+
+    /// Retrieves the pixel data of the image mutably.
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        let image = unsafe { &*self.handle() };
+
+        unsafe {
+            std::slice::from_raw_parts_mut(
+                image.data as *mut u8,
+                self.bytes_per_line() * self.height() as usize,
+            )
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use crate::xlib_sys;
+use crate::{xlib_sys, XDisplay};
 use std::fmt::{Debug, Formatter};
 use std::marker::PhantomData;
 
@@ -34,6 +34,17 @@ impl<'a> XVisual<'a> {
     pub fn id(&self) -> xlib_sys::VisualID {
         unsafe { xlib_sys::XVisualIDFromVisual(self.handle) }
     }
+
+    /// Retrieves the red, green and blue masks of pixel values using this visual.
+    pub fn color_masks(&self) -> (u64, u64, u64) {
+        let visual = unsafe { &*self.handle };
+
+        (
+            visual.red_mask as _,
+            visual.green_mask as _,
+            visual.blue_mask as _,
+        )
+    }
 }
 
 impl<'a> Debug for XVisual<'a> {
@@ -89,6 +100,21 @@ impl<'a> XVisualInfo<'a> {
         &self.visual
     }
 
+    /// Determines whether the visual has an alpha channel.
+    ///
+    /// This checks the XRender picture format of the visual, since the depth of the visual alone
+    /// does not guarantee that the additional bits are used for alpha.
+    ///
+    /// # Arguments
+    ///
+    /// * `display` - The display the visual belongs to
+    pub(crate) fn is_argb(&self, display: &XDisplay) -> bool {
+        self.depth() == 32
+            && display
+                .find_visual_format(self.visual())
+                .is_some_and(|format| format.has_alpha())
+    }
+
     /// Retrieves the native underlying X11 pointer.
     pub fn handle(&self) -> xlib_sys::XVisualInfo {
         self.handle
@@ -1,6 +1,7 @@
 use crate::{
-    xcomposite_sys, xfixes_sys, xinput2_sys, xlib_sys, XAtom, XColormap, XCursor, XDisplay,
-    XDrawable, XPixmap, XPropertyHolder, XScreen, XServerRegion, XVisual,
+    xcomposite_sys, xfixes_sys, xinput2_sys, xlib_sys, RgbaImage, XAtom, XColormap, XCursor,
+    XDisplay, XDrawable, XImageFormat, XPixmap, XPropertyHolder, XRectangle, XScreen,
+    XServerRegion, XVisual,
 };
 use std::ffi::{CStr, CString};
 
@@ -18,6 +19,17 @@ pub enum WindowShapeKind {
     Input = 2,
 }
 
+/// Determines how redirected windows are composited.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[repr(i32)]
+pub enum CompositeRedirectMode {
+    /// The X server keeps compositing the redirected windows onto their parent
+    Automatic = xcomposite_sys::CompositeRedirectAutomatic,
+
+    /// The client is responsible for compositing the redirected windows
+    Manual = xcomposite_sys::CompositeRedirectManual,
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 #[repr(i32)]
 pub enum WindowClass {
@@ -460,6 +472,12 @@ impl<'a> XWindow<'a> {
         self.handle
     }
 
+    /// Determines whether the window handle is owned by someone else.
+    #[cfg(feature = "raw-window-handle")]
+    pub(crate) fn is_foreign(&self) -> bool {
+        matches!(self.ownership, WindowHandleOwnership::Foreign)
+    }
+
     /// Retrieves the attributes of the window.
     pub fn get_attributes(&self) -> XWindowAttributes<'a> {
         let mut raw = MaybeUninit::uninit();
@@ -541,6 +559,19 @@ impl<'a> XWindow<'a> {
         }
     }
 
+    /// Hides the cursor while it is within this window.
+    ///
+    /// Hide requests are reference counted by the X server per client, and the cursor is shown
+    /// again once [`show_cursor`](Self::show_cursor) has been called as many times.
+    pub fn hide_cursor(&self) {
+        unsafe { xfixes_sys::XFixesHideCursor(self.display.handle(), self.handle) };
+    }
+
+    /// Shows the cursor again after it has been hidden using [`hide_cursor`](Self::hide_cursor).
+    pub fn show_cursor(&self) {
+        unsafe { xfixes_sys::XFixesShowCursor(self.display.handle(), self.handle) };
+    }
+
     /// Selects the XInput mask for the window
     pub fn select_xinput_events(&self, mask: Vec<(XInputDevice, XInputEventMask)>) {
         let mut event_mask_bytes = Vec::with_capacity(mask.len());
@@ -774,6 +805,154 @@ impl<'a> XWindow<'a> {
         }
     }
 
+    /// Changes the input shape of this window.
+    ///
+    /// Input events are only delivered to the window in the area covered by the input shape,
+    /// setting an empty region thus makes the window click-through.
+    ///
+    /// # Arguments
+    ///
+    /// * `x_offset` - The x coordinate from where to start the region
+    /// * `y_offset` - The y coordinate from where to start the region
+    /// * `region` - The region to apply, or [`None`] to reset the input shape to the default
+    pub fn set_input_shape_region(
+        &self,
+        x_offset: i32,
+        y_offset: i32,
+        region: Option<&XServerRegion>,
+    ) {
+        unsafe {
+            xfixes_sys::XFixesSetWindowShapeRegion(
+                self.display.handle(),
+                self.handle,
+                WindowShapeKind::Input as _,
+                x_offset,
+                y_offset,
+                region.map(|r| r.handle()).unwrap_or(0),
+            );
+        }
+    }
+
+    /// Redirects the rendering of this window and all of its children into offscreen storage.
+    ///
+    /// Only one client may redirect a window using [`CompositeRedirectMode::Manual`].
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - How the redirected window is composited
+    pub fn redirect(&self, mode: CompositeRedirectMode) {
+        unsafe {
+            xcomposite_sys::XCompositeRedirectWindow(self.display.handle(), self.handle, mode as _)
+        };
+    }
+
+    /// Redirects the rendering of all current and future children of this window into
+    /// offscreen storage.
+    ///
+    /// Only one client may redirect the children of a window using
+    /// [`CompositeRedirectMode::Manual`], usually this is the compositing manager redirecting
+    /// the children of the root window.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - How the redirected windows are composited
+    pub fn redirect_subwindows(&self, mode: CompositeRedirectMode) {
+        unsafe {
+            xcomposite_sys::XCompositeRedirectSubwindows(
+                self.display.handle(),
+                self.handle,
+                mode as _,
+            )
+        };
+    }
+
+    /// Stops redirecting this window previously redirected using [`redirect`](Self::redirect).
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode the window has been redirected with
+    pub fn unredirect(&self, mode: CompositeRedirectMode) {
+        unsafe {
+            xcomposite_sys::XCompositeUnredirectWindow(
+                self.display.handle(),
+                self.handle,
+                mode as _,
+            )
+        };
+    }
+
+    /// Stops redirecting the children of this window previously redirected using
+    /// [`redirect_subwindows`](Self::redirect_subwindows).
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - The mode the children have been redirected with
+    pub fn unredirect_subwindows(&self, mode: CompositeRedirectMode) {
+        unsafe {
+            xcomposite_sys::XCompositeUnredirectSubwindows(
+                self.display.handle(),
+                self.handle,
+                mode as _,
+            )
+        };
+    }
+
+    /// Retrieves a pixmap referring to the offscreen storage of this redirected window.
+    ///
+    /// The pixmap stays valid and keeps its contents even after the window has been resized or
+    /// unmapped, a new pixmap has to be named to observe the new storage in that case.
+    ///
+    /// Returns [`None`] if the window is not redirected or not viewable.
+    pub fn name_window_pixmap(&self) -> Option<XPixmap<'a>> {
+        let (pixmap, error) = self.display.trap_errors(|| unsafe {
+            xcomposite_sys::XCompositeNameWindowPixmap(self.display.handle(), self.handle)
+        });
+
+        // The pixmap id is only allocated by the X server if the request succeeded
+        match (pixmap, error) {
+            (0, _) | (_, Some(_)) => None,
+            (pixmap, None) => Some(unsafe { XPixmap::new(pixmap, self.display) }),
+        }
+    }
+
+    /// Captures the contents of an area of this redirected window as RGBA pixels.
+    ///
+    /// Unlike [`XDrawable::capture`] this reads from the offscreen storage of the window, which
+    /// also contains the parts of the window obscured by other windows. The window has to be
+    /// redirected, either by a compositing manager or using [`XWindow::redirect`].
+    ///
+    /// Returns [`None`] if the window is not redirected or not viewable, or if the area does not
+    /// lie completely within the window.
+    ///
+    /// # Arguments
+    ///
+    /// * `rectangle` - The area to capture
+    pub fn capture_composited(&self, rectangle: XRectangle) -> Option<RgbaImage> {
+        let pixmap = self.name_window_pixmap()?;
+        let mut image = pixmap.get_image(rectangle, !0, XImageFormat::ZPixmap)?;
+
+        // Images read from pixmaps carry no color masks, take them from the window instead
+        let (red, green, blue) = self.get_attributes().visual().color_masks();
+        image.set_color_masks(red, green, blue);
+
+        Some(image.to_rgba8())
+    }
+
+    /// Creates a region covering the border clip of this redirected window.
+    ///
+    /// The border clip is the part of the window, including its border, which would be visible
+    /// on screen if the window was not redirected.
+    pub fn create_border_clip_region(&self) -> XServerRegion<'a> {
+        unsafe {
+            let region = xcomposite_sys::XCompositeCreateRegionFromBorderClip(
+                self.display.handle(),
+                self.handle,
+            );
+
+            XServerRegion::new(region, self.display)
+        }
+    }
+
     /// Changes the parent window of this window.
     ///
     /// # Arguments
@@ -0,0 +1,420 @@
+use crate::{xft_sys, xlib_sys, xrender_sys, XColormap, XDisplay, XDrawable, XRectangle, XVisual};
+
+/// Extents of a glyph or text rendered with Xft.
+#[derive(Debug, Copy, Clone, Default, Eq, PartialEq, Hash)]
+pub struct XGlyphExtents {
+    /// The width of the drawn pixels
+    pub width: u16,
+
+    /// The height of the drawn pixels
+    pub height: u16,
+
+    /// The offset from the drawn pixels to the origin on the x axis
+    pub x: i16,
+
+    /// The offset from the drawn pixels to the origin on the y axis
+    pub y: i16,
+
+    /// The advance to the origin of the next glyph on the x axis
+    pub x_offset: i16,
+
+    /// The advance to the origin of the next glyph on the y axis
+    pub y_offset: i16,
+}
+
+impl From<xrender_sys::XGlyphInfo> for XGlyphExtents {
+    fn from(value: xrender_sys::XGlyphInfo) -> Self {
+        Self {
+            width: value.width,
+            height: value.height,
+            x: value.x,
+            y: value.y,
+            x_offset: value.xOff,
+            y_offset: value.yOff,
+        }
+    }
+}
+
+/// Anti-aliased font loaded using Xft and fontconfig.
+#[derive(Debug)]
+pub struct XftFont<'a> {
+    handle: *mut xft_sys::XftFont,
+    display: &'a XDisplay,
+}
+
+impl<'a> XftFont<'a> {
+    /// Wraps an existing Xft font.
+    ///
+    /// # Arguments
+    ///
+    /// * `handle` - The Xft font to wrap
+    /// * `display` - The display the font belongs to
+    ///
+    /// # Safety
+    ///
+    /// It is up to the caller to ensure all arguments are valid.
+    pub unsafe fn new(handle: *mut xft_sys::XftFont, display: &'a XDisplay) -> Self {
+        Self { handle, display }
+    }
+
+    /// Retrieves the underlying native Xft font handle.
+    pub fn handle(&self) -> *mut xft_sys::XftFont {
+        self.handle
+    }
+
+    /// Retrieves the distance from the baseline to the top of the font.
+    pub fn ascent(&self) -> i32 {
+        unsafe { &*self.handle }.ascent
+    }
+
+    /// Retrieves the distance from the baseline to the bottom of the font.
+    pub fn descent(&self) -> i32 {
+        unsafe { &*self.handle }.descent
+    }
+
+    /// Retrieves the recommended distance between two baselines.
+    pub fn height(&self) -> i32 {
+        unsafe { &*self.handle }.height
+    }
+
+    /// Retrieves the maximum advance of any glyph in the font.
+    pub fn max_advance_width(&self) -> i32 {
+        unsafe { &*self.handle }.max_advance_width
+    }
+
+    /// Determines whether the font contains a glyph for a character.
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - The character to look up
+    pub fn has_char(&self, c: char) -> bool {
+        unsafe { xft_sys::XftCharExists(self.display.handle(), self.handle, c as _) != 0 }
+    }
+
+    /// Calculates the extents of the given text.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to calculate the extents for
+    pub fn text_extents(&self, text: impl AsRef<str>) -> XGlyphExtents {
+        let text_bytes = text.as_ref().as_bytes();
+        let mut extents = unsafe { std::mem::zeroed() };
+
+        unsafe {
+            xft_sys::XftTextExtentsUtf8(
+                self.display.handle(),
+                self.handle,
+                text_bytes.as_ptr(),
+                text_bytes.len() as _,
+                &mut extents,
+            )
+        };
+
+        extents.into()
+    }
+}
+
+impl<'a> Drop for XftFont<'a> {
+    fn drop(&mut self) {
+        unsafe { xft_sys::XftFontClose(self.display.handle(), self.handle) };
+    }
+}
+
+/// A list of Xft fonts, falling back to the next font for characters missing in a font.
+#[derive(Debug)]
+pub struct XftFontList<'a> {
+    fonts: Vec<XftFont<'a>>,
+}
+
+impl<'a> XftFontList<'a> {
+    /// Creates a new font list.
+    ///
+    /// # Arguments
+    ///
+    /// * `fonts` - The fonts in order of preference
+    ///
+    /// # Panics
+    ///
+    /// If `fonts` is empty.
+    pub fn new(fonts: Vec<XftFont<'a>>) -> Self {
+        assert!(!fonts.is_empty());
+
+        Self { fonts }
+    }
+
+    /// Retrieves the fonts in order of preference.
+    pub fn fonts(&self) -> &[XftFont<'a>] {
+        &self.fonts
+    }
+
+    /// Retrieves the primary font, which is used for metrics and missing characters.
+    pub fn primary(&self) -> &XftFont<'a> {
+        &self.fonts[0]
+    }
+
+    /// Selects the first font containing a glyph for a character.
+    ///
+    /// # Arguments
+    ///
+    /// * `c` - The character to select the font for
+    pub fn font_for(&self, c: char) -> &XftFont<'a> {
+        self.fonts
+            .iter()
+            .find(|font| font.has_char(c))
+            .unwrap_or_else(|| self.primary())
+    }
+
+    /// Splits a text into runs which are rendered with the same font.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to split
+    pub fn runs<'t>(&self, text: &'t str) -> Vec<(&XftFont<'a>, &'t str)> {
+        let mut runs = Vec::new();
+        let mut current: Option<(&XftFont<'a>, usize)> = None;
+
+        for (i, c) in text.char_indices() {
+            let font = self.font_for(c);
+
+            match current {
+                Some((current_font, _)) if std::ptr::eq(current_font, font) => {}
+                Some((current_font, start)) => {
+                    runs.push((current_font, &text[start..i]));
+                    current = Some((font, i));
+                }
+                None => current = Some((font, i)),
+            }
+        }
+
+        if let Some((font, start)) = current {
+            runs.push((font, &text[start..]));
+        }
+
+        runs
+    }
+
+    /// Calculates the advance of the given text on the x axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to calculate the advance for
+    pub fn text_width(&self, text: impl AsRef<str>) -> i32 {
+        self.runs(text.as_ref())
+            .into_iter()
+            .map(|(font, run)| font.text_extents(run).x_offset as i32)
+            .sum()
+    }
+}
+
+/// A color allocated for drawing with Xft.
+///
+/// The colormap the color has been allocated from stays borrowed for as long as the color
+/// exists.
+#[derive(Debug)]
+pub struct XftColor<'c, 'a> {
+    native: xft_sys::XftColor,
+    visual: *mut xlib_sys::Visual,
+    colormap: &'c XColormap<'a>,
+    display: &'a XDisplay,
+}
+
+impl<'c, 'a> XftColor<'c, 'a> {
+    /// Wraps an allocated Xft color.
+    ///
+    /// # Arguments
+    ///
+    /// * `native` - The native Xft color
+    /// * `visual` - The visual the color has been allocated for
+    /// * `colormap` - The colormap the color has been allocated from
+    /// * `display` - The display the color has been allocated on
+    ///
+    /// # Safety
+    ///
+    /// The color must have been allocated using the given visual and colormap, it is freed
+    /// when the wrapper is dropped.
+    pub unsafe fn new(
+        native: xft_sys::XftColor,
+        visual: &XVisual,
+        colormap: &'c XColormap<'a>,
+        display: &'a XDisplay,
+    ) -> Self {
+        Self {
+            native,
+            visual: visual.handle(),
+            colormap,
+            display,
+        }
+    }
+
+    /// Retrieves the underlying native Xft color.
+    pub fn native(&self) -> &xft_sys::XftColor {
+        &self.native
+    }
+
+    /// Retrieves the pixel value the color has been allocated as.
+    pub fn pixel(&self) -> u64 {
+        self.native.pixel as _
+    }
+}
+
+impl<'c, 'a> Drop for XftColor<'c, 'a> {
+    fn drop(&mut self) {
+        unsafe {
+            xft_sys::XftColorFree(
+                self.display.handle(),
+                self.visual,
+                self.colormap.handle(),
+                &mut self.native,
+            )
+        };
+    }
+}
+
+/// Xft drawing context bound to a drawable.
+///
+/// The drawable stays borrowed for as long as the drawing context exists.
+#[derive(Debug)]
+pub struct XftDraw<'a> {
+    handle: *mut xft_sys::XftDraw,
+    _display: &'a XDisplay,
+}
+
+impl<'a> XftDraw<'a> {
+    /// Creates a new drawing context for a drawable.
+    ///
+    /// Returns [`None`] if Xft failed to create the drawing context.
+    ///
+    /// # Arguments
+    ///
+    /// * `drawable` - The drawable to draw to
+    /// * `visual` - The visual of the drawable
+    /// * `colormap` - The colormap of the drawable
+    pub fn create<'d: 'a>(
+        drawable: &'a impl XDrawable<'d>,
+        visual: &XVisual,
+        colormap: &XColormap,
+    ) -> Option<Self> {
+        let handle = unsafe {
+            xft_sys::XftDrawCreate(
+                drawable.display().handle(),
+                drawable.drawable_handle(),
+                visual.handle(),
+                colormap.handle(),
+            )
+        };
+
+        if handle.is_null() {
+            return None;
+        }
+
+        Some(Self {
+            handle,
+            _display: drawable.display(),
+        })
+    }
+
+    /// Retrieves the underlying native Xft draw handle.
+    pub fn handle(&self) -> *mut xft_sys::XftDraw {
+        self.handle
+    }
+
+    /// Draws a UTF-8 string.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color to draw the string with
+    /// * `font` - The font to draw the string with
+    /// * `x` - The x coordinate of the origin of the string
+    /// * `y` - The y coordinate of the baseline of the string
+    /// * `text` - The string to draw
+    pub fn draw_string(
+        &self,
+        color: &XftColor,
+        font: &XftFont,
+        x: i32,
+        y: i32,
+        text: impl AsRef<str>,
+    ) {
+        let text_bytes = text.as_ref().as_bytes();
+
+        unsafe {
+            xft_sys::XftDrawStringUtf8(
+                self.handle,
+                color.native(),
+                font.handle(),
+                x,
+                y,
+                text_bytes.as_ptr(),
+                text_bytes.len() as _,
+            )
+        };
+    }
+
+    /// Draws a UTF-8 string, using the first font of the list containing each character.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color to draw the string with
+    /// * `fonts` - The fonts to draw the string with
+    /// * `x` - The x coordinate of the origin of the string
+    /// * `y` - The y coordinate of the baseline of the string
+    /// * `text` - The string to draw
+    pub fn draw_string_with_fallback(
+        &self,
+        color: &XftColor,
+        fonts: &XftFontList,
+        x: i32,
+        y: i32,
+        text: impl AsRef<str>,
+    ) {
+        let mut x = x;
+
+        for (font, run) in fonts.runs(text.as_ref()) {
+            self.draw_string(color, font, x, y, run);
+            x += font.text_extents(run).x_offset as i32;
+        }
+    }
+
+    /// Fills a rectangle.
+    ///
+    /// # Arguments
+    ///
+    /// * `color` - The color to fill the rectangle with
+    /// * `x` - The x coordinate to start drawing at
+    /// * `y` - The y coordinate to start drawing at
+    /// * `width` - The width to draw starting from `x`
+    /// * `height` - The height to draw starting from `y`
+    pub fn fill_rect(&self, color: &XftColor, x: i32, y: i32, width: u32, height: u32) {
+        unsafe { xft_sys::XftDrawRect(self.handle, color.native(), x, y, width, height) };
+    }
+
+    /// Restricts drawing to a set of rectangles.
+    ///
+    /// # Arguments
+    ///
+    /// * `x_origin` - The x coordinate of the clip origin
+    /// * `y_origin` - The y coordinate of the clip origin
+    /// * `rectangles` - The rectangles to clip drawing to
+    pub fn set_clip_rectangles(&self, x_origin: i32, y_origin: i32, rectangles: &[XRectangle]) {
+        let rectangles = rectangles
+            .iter()
+            .map(|&r| xlib_sys::XRectangle::from(r))
+            .collect::<Vec<_>>();
+
+        unsafe {
+            xft_sys::XftDrawSetClipRectangles(
+                self.handle,
+                x_origin,
+                y_origin,
+                rectangles.as_ptr(),
+                rectangles.len() as _,
+            )
+        };
+    }
+}
+
+impl<'a> Drop for XftDraw<'a> {
+    fn drop(&mut self) {
+        unsafe { xft_sys::XftDrawDestroy(self.handle) };
+    }
+}